@@ -25,6 +25,16 @@ pub enum Commands {
         /// Treat <path> as a directory of finding folders and import them all
         #[arg(short, long, default_value_t = false)]
         bulk: bool,
+
+        /// Recursively descend into <path>, deriving assets from the
+        /// directory tree and asset.md files along the way (for messy,
+        /// nested engagement layouts --bulk can't handle in one pass)
+        #[arg(short, long, default_value_t = false)]
+        recursive: bool,
+
+        /// Import adapter to use: auto, native, sarif, csv, or json
+        #[arg(short, long, default_value = "auto")]
+        format: String,
     },
 
     /// Import asset(s) from a Markdown file
@@ -39,7 +49,11 @@ pub enum Commands {
     },
 
     /// View all findings and assets through a TUI
-    View {},
+    View {
+        /// Skip the on-disk artifact cache and recompute graph data fresh
+        #[arg(long, default_value_t = false)]
+        no_cache: bool,
+    },
 
     /// Generate a PDF report from findings
     Report {
@@ -62,6 +76,33 @@ pub enum Commands {
         /// End date for the date range (YYYY/MM/DD)
         #[arg(long)]
         to: String,
+
+        /// Skip the on-disk artifact cache and always regenerate the PDF
+        #[arg(long, default_value_t = false)]
+        no_cache: bool,
+
+        /// Split [from, to] into periodic windows per an RRULE subset
+        /// (FREQ=DAILY|WEEKLY|MONTHLY, INTERVAL=n, COUNT=n, UNTIL=YYYYMMDD)
+        /// and emit one report per occurrence, e.g. report-2024-09-01.pdf
+        #[arg(long)]
+        repeat: Option<String>,
+
+        /// With --repeat, skip occurrences whose window has no findings
+        /// instead of still rendering an empty report for them
+        #[arg(long, default_value_t = false)]
+        skip_empty: bool,
+
+        /// External preprocessor command to run on the parsed block
+        /// stream before LaTeX conversion (mdBook-style); may be passed
+        /// multiple times and commands run in the order given
+        #[arg(long = "preprocessor")]
+        preprocessors: Vec<String>,
+
+        /// Compile the PDF with the old inputenc/fontenc preamble and its
+        /// fixed Unicode-to-LaTeX transliteration table, for pdfLaTeX-style
+        /// output, instead of the default fontspec setup
+        #[arg(long, default_value_t = false)]
+        legacy_latex_escape: bool,
     },
 
     /// Update the status of a finding
@@ -80,7 +121,11 @@ pub enum Commands {
     },
 
     /// Wipe the database and all stored findings
-    Clean {},
+    Clean {
+        /// Also wipe the on-disk artifact cache (graph data, rendered reports)
+        #[arg(long, default_value_t = false)]
+        cache: bool,
+    },
 
     /// Export all findings to CSV
     Export {
@@ -99,6 +144,18 @@ pub enum Commands {
         /// End date for the date range (YYYY/MM/DD)
         #[arg(long)]
         to: Option<String>,
+
+        /// Split [from, to] into periodic windows per an RRULE subset
+        /// (FREQ=DAILY|WEEKLY|MONTHLY, INTERVAL=n, COUNT=n, UNTIL=YYYYMMDD)
+        /// and emit one CSV per occurrence, e.g. findings-2024-09-01.csv.
+        /// Requires --from and --to.
+        #[arg(long)]
+        repeat: Option<String>,
+
+        /// With --repeat, skip occurrences whose window has no findings
+        /// instead of still writing an empty CSV for them
+        #[arg(long, default_value_t = false)]
+        skip_empty: bool,
     },
 
     /// Manage clients (each client gets its own DB and findings)
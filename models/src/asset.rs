@@ -15,6 +15,22 @@ pub struct Asset {
     pub criticality: String,
     /// DNS name or IP address.
     pub dns_or_ip: String,
+    /// Date the asset was first imported, in `YYYY/MM/DD` format. `"-"`
+    /// for an asset not yet persisted.
+    pub created_at: String,
+    /// Date any of this asset's metadata was last changed by a re-import,
+    /// in `YYYY/MM/DD` format. `"-"` for an asset not yet persisted.
+    pub updated_at: String,
+    /// Date this asset was most recently touched by *any* import run
+    /// (even one that changed nothing), in `YYYY/MM/DD` format — distinct
+    /// from `updated_at` so "assets not seen in N days" can tell a stale
+    /// asset apart from one that's still being scanned but unchanged.
+    /// `"-"` for an asset not yet persisted.
+    pub last_seen: String,
+    /// Name of the parent asset, if this asset is nested under a broader
+    /// scope (e.g. host `api.acme.com` under domain `acme.com`). `None` for
+    /// a top-level asset.
+    pub parent: Option<String>,
 }
 
 impl Asset {
@@ -26,9 +42,18 @@ impl Asset {
             contact: "-".into(),
             criticality: "-".into(),
             dns_or_ip: "-".into(),
+            created_at: "-".into(),
+            updated_at: "-".into(),
+            last_seen: "-".into(),
+            parent: None,
         }
     }
 
+    pub fn with_parent(mut self, parent: impl Into<String>) -> Self {
+        self.parent = Some(parent.into());
+        self
+    }
+
     pub fn with_description(mut self, desc: impl Into<String>) -> Self {
         self.description = desc.into();
         self
@@ -49,6 +74,39 @@ impl Asset {
         self
     }
 
+    pub fn with_created_at(mut self, date: impl Into<String>) -> Self {
+        self.created_at = date.into();
+        self
+    }
+
+    pub fn with_updated_at(mut self, date: impl Into<String>) -> Self {
+        self.updated_at = date.into();
+        self
+    }
+
+    pub fn with_last_seen(mut self, date: impl Into<String>) -> Self {
+        self.last_seen = date.into();
+        self
+    }
+
+    /// Days since `last_seen`, per [`crate::dates::day_ordinal`]. `None`
+    /// when `last_seen` isn't a valid `YYYY/MM/DD` date (e.g. `"-"` for an
+    /// asset that predates this field, or one not yet persisted).
+    pub fn days_since_last_seen(&self) -> Option<i32> {
+        let (y, m, d) = crate::dates::parse_ymd(&self.last_seen)?;
+        let (ty, tm, td) = crate::dates::today_ymd();
+        Some(crate::dates::day_ordinal(ty, tm, td) - crate::dates::day_ordinal(y, m, d))
+    }
+
+    /// Days since `created_at`, per [`crate::dates::day_ordinal`]. `None`
+    /// when `created_at` isn't a valid `YYYY/MM/DD` date (e.g. `"-"` for an
+    /// asset that predates this field, or one not yet persisted).
+    pub fn days_since_created(&self) -> Option<i32> {
+        let (y, m, d) = crate::dates::parse_ymd(&self.created_at)?;
+        let (ty, tm, td) = crate::dates::today_ymd();
+        Some(crate::dates::day_ordinal(ty, tm, td) - crate::dates::day_ordinal(y, m, d))
+    }
+
     /// Map the criticality string to a TUI color.
     pub fn criticality_color(&self) -> Color {
         match self.criticality.to_lowercase().as_str() {
@@ -70,6 +128,10 @@ impl Asset {
                 contact: "Platform Team <platform@nexus.corp>".into(),
                 criticality: "Critical".into(),
                 dns_or_ip: "portal.nexus.corp".into(),
+                created_at: "2026/01/05".into(),
+                updated_at: "2026/01/05".into(),
+                last_seen: "2026/01/05".into(),
+                parent: None,
             },
             Asset {
                 id: Some(2),
@@ -78,6 +140,10 @@ impl Asset {
                 contact: "Infrastructure Team <infra@orion.corp>".into(),
                 criticality: "Critical".into(),
                 dns_or_ip: "gw.orion.corp".into(),
+                created_at: "2026/01/05".into(),
+                updated_at: "2026/01/05".into(),
+                last_seen: "2026/01/05".into(),
+                parent: None,
             },
             Asset {
                 id: Some(3),
@@ -86,6 +152,10 @@ impl Asset {
                 contact: "Mobile Team <mobile@helix.corp>".into(),
                 criticality: "High".into(),
                 dns_or_ip: "mobile-api.helix.corp".into(),
+                created_at: "2026/01/05".into(),
+                updated_at: "2026/01/05".into(),
+                last_seen: "2026/01/05".into(),
+                parent: None,
             },
         ]
     }
@@ -0,0 +1,153 @@
+//! CVSS v3.1 base score calculation from a vector string such as
+//! `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H`.
+//!
+//! This implements just the base metric group of the CVSS v3.1 spec — no
+//! temporal/environmental metrics — which is what a pentest finding needs
+//! to derive a consistent [`crate::Severity`] instead of hand-picking one.
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// A CVSS vector string that failed to parse or evaluate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CvssError(pub String);
+
+impl fmt::Display for CvssError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid CVSS vector: {}", self.0)
+    }
+}
+
+impl std::error::Error for CvssError {}
+
+/// A fully parsed CVSS v3.1 base vector: the eight base metrics, from which
+/// [`Cvss::score`] derives the base score. Use [`Cvss::parse`] to build one
+/// from a vector string, or [`base_score`] when only the numeric score is
+/// needed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cvss {
+    pub attack_vector: f64,
+    pub attack_complexity: f64,
+    pub privileges_required: f64,
+    pub user_interaction: f64,
+    pub scope_changed: bool,
+    pub confidentiality: f64,
+    pub integrity: f64,
+    pub availability: f64,
+}
+
+impl Cvss {
+    /// Parse a `CVSS:3.1/...` base vector string into its eight metrics.
+    pub fn parse(vector: &str) -> Result<Cvss, CvssError> {
+        let mut segments = vector.split('/');
+        let header = segments.next().filter(|s| !s.is_empty()).ok_or_else(|| CvssError("empty vector".to_string()))?;
+        if header != "CVSS:3.1" {
+            return Err(CvssError(format!("unsupported CVSS version header: {header}")));
+        }
+
+        let mut metrics: HashMap<&str, &str> = HashMap::new();
+        for segment in segments {
+            let (key, value) = segment
+                .split_once(':')
+                .ok_or_else(|| CvssError(format!("malformed metric: {segment}")))?;
+            metrics.insert(key, value);
+        }
+
+        let attack_vector = lookup(&metrics, "AV", &[("N", 0.85), ("A", 0.62), ("L", 0.55), ("P", 0.20)])?;
+        let attack_complexity = lookup(&metrics, "AC", &[("L", 0.77), ("H", 0.44)])?;
+        let user_interaction = lookup(&metrics, "UI", &[("N", 0.85), ("R", 0.62)])?;
+
+        let scope_changed = match metrics.get("S") {
+            Some(&"U") => false,
+            Some(&"C") => true,
+            Some(other) => return Err(CvssError(format!("invalid S value: {other}"))),
+            None => return Err(CvssError("missing S metric".to_string())),
+        };
+
+        let privileges_required = match metrics.get("PR") {
+            Some(&"N") => 0.85,
+            Some(&"L") => if scope_changed { 0.68 } else { 0.62 },
+            Some(&"H") => if scope_changed { 0.50 } else { 0.27 },
+            Some(other) => return Err(CvssError(format!("invalid PR value: {other}"))),
+            None => return Err(CvssError("missing PR metric".to_string())),
+        };
+
+        let confidentiality = cia(&metrics, "C")?;
+        let integrity = cia(&metrics, "I")?;
+        let availability = cia(&metrics, "A")?;
+
+        Ok(Cvss {
+            attack_vector,
+            attack_complexity,
+            privileges_required,
+            user_interaction,
+            scope_changed,
+            confidentiality,
+            integrity,
+            availability,
+        })
+    }
+
+    /// The CVSS v3.1 base score (0.0–10.0) for these metrics.
+    pub fn score(&self) -> f64 {
+        let iss = 1.0 - ((1.0 - self.confidentiality) * (1.0 - self.integrity) * (1.0 - self.availability));
+        let impact = if self.scope_changed {
+            7.52 * (iss - 0.029) - 3.25 * (iss - 0.02).powf(15.0)
+        } else {
+            6.42 * iss
+        };
+
+        if impact <= 0.0 {
+            return 0.0;
+        }
+
+        let exploitability = 8.22 * self.attack_vector * self.attack_complexity * self.privileges_required * self.user_interaction;
+        let raw = if self.scope_changed {
+            1.08 * (impact + exploitability)
+        } else {
+            impact + exploitability
+        };
+        roundup(raw.min(10.0))
+    }
+
+    /// Map this vector's base score onto the existing [`crate::Severity`]
+    /// enum's qualitative rating scale.
+    pub fn severity(&self) -> crate::Severity {
+        crate::Severity::from_cvss_score(self.score())
+    }
+}
+
+/// Compute the CVSS v3.1 base score (0.0–10.0) from a vector string.
+pub fn base_score(vector: &str) -> Result<f64, CvssError> {
+    Cvss::parse(vector).map(|cvss| cvss.score())
+}
+
+fn lookup(metrics: &HashMap<&str, &str>, key: &str, table: &[(&str, f64)]) -> Result<f64, CvssError> {
+    let value = *metrics.get(key).ok_or_else(|| CvssError(format!("missing {key} metric")))?;
+    table
+        .iter()
+        .find(|(k, _)| *k == value)
+        .map(|&(_, w)| w)
+        .ok_or_else(|| CvssError(format!("invalid {key} value: {value}")))
+}
+
+fn cia(metrics: &HashMap<&str, &str>, key: &str) -> Result<f64, CvssError> {
+    match metrics.get(key) {
+        Some(&"H") => Ok(0.56),
+        Some(&"L") => Ok(0.22),
+        Some(&"N") => Ok(0.0),
+        Some(other) => Err(CvssError(format!("invalid {key} value: {other}"))),
+        None => Err(CvssError(format!("missing {key} metric"))),
+    }
+}
+
+/// CVSS v3.1's `Roundup`: round up to one decimal place, working in
+/// integer space to sidestep floating-point rounding quirks.
+fn roundup(x: f64) -> f64 {
+    let int_input = (x * 100_000.0).round() as i64;
+    if int_input % 10_000 == 0 {
+        int_input as f64 / 100_000.0
+    } else {
+        (int_input / 10_000 + 1) as f64 / 10.0
+    }
+}
@@ -0,0 +1,115 @@
+//! Date helpers shared across crates: the TUI's Graph/Calendar tabs and
+//! storage's recurrence-rule windowing both need to parse, compare, and
+//! step `"YYYY/MM/DD"` dates without pulling in a date-arithmetic crate.
+//!
+//! [`parse_ymd`] turns a date string into a `(year, month, day)` triple and
+//! [`day_ordinal`] turns that into a day count (the Julian day number)
+//! that's cheap to compare, subtract, and bucket by.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Parse "YYYY/MM/DD" → (year, month, day).
+pub fn parse_ymd(date: &str) -> Option<(i32, u32, u32)> {
+    let parts: Vec<&str> = date.split('/').collect();
+    if parts.len() < 3 { return None; }
+    Some((parts[0].parse().ok()?, parts[1].parse().ok()?, parts[2].parse().ok()?))
+}
+
+/// Format (year, month, day) → "YYYY/MM/DD", the inverse of [`parse_ymd`].
+pub fn format_ymd(y: i32, m: u32, d: u32) -> String {
+    format!("{y:04}/{m:02}/{d:02}")
+}
+
+/// Convert (year, month, day) → ordinal day count since an arbitrary epoch
+/// (good enough for grouping into day/week/month buckets).
+pub fn day_ordinal(y: i32, m: u32, d: u32) -> i32 {
+    let m = m as i32;
+    let d = d as i32;
+    // Gregorian Julian Day Number; exact, so `civil_from_ordinal` inverts it
+    // and `UNIX_EPOCH_ORDINAL` lines up with it
+    let a = (14 - m) / 12;
+    let yy = y + 4800 - a;
+    let mm = m + 12 * a - 3;
+    d + (153 * mm + 2) / 5 + 365 * yy + yy / 4 - yy / 100 + yy / 400 - 32045
+}
+
+/// Inverse of [`day_ordinal`]: recover (year, month, day) from an ordinal.
+pub fn civil_from_ordinal(ord: i32) -> (i32, u32, u32) {
+    let a = ord + 32044;
+    let b = (4 * a + 3) / 146097;
+    let c = a - (146097 * b) / 4;
+    let d = (4 * c + 3) / 1461;
+    let e = c - (1461 * d) / 4;
+    let m = (5 * e + 2) / 153;
+    let day = e - (153 * m + 2) / 5 + 1;
+    let month = m + 3 - 12 * (m / 10);
+    let year = 100 * b + d - 4800 + (m / 10);
+    (year, month as u32, day as u32)
+}
+
+/// Ordinal of the Unix epoch (1970/01/01) in [`day_ordinal`]'s scale, used
+/// to convert `SystemTime` into the same day-count space.
+const UNIX_EPOCH_ORDINAL: i32 = 2_440_588;
+
+/// Today's date, derived from the system clock.
+pub fn today_ymd() -> (i32, u32, u32) {
+    let days_since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() / 86_400)
+        .unwrap_or(0) as i32;
+    civil_from_ordinal(UNIX_EPOCH_ORDINAL + days_since_epoch)
+}
+
+/// Monday-based weekday index (0 = Monday .. 6 = Sunday) for (y, m, d).
+pub fn weekday_index(y: i32, m: u32, d: u32) -> u32 {
+    day_ordinal(y, m, d).rem_euclid(7) as u32
+}
+
+/// Number of days in calendar month `m` of year `y`.
+pub fn days_in_month(m: u32, y: i32) -> u32 {
+    match m {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => if y % 4 == 0 && (y % 100 != 0 || y % 400 == 0) { 29 } else { 28 },
+        _ => 30,
+    }
+}
+
+pub fn month_abbrev(m: u32) -> &'static str {
+    match m {
+        1 => "Jan", 2 => "Feb", 3 => "Mar", 4 => "Apr",
+        5 => "May", 6 => "Jun", 7 => "Jul", 8 => "Aug",
+        9 => "Sep", 10 => "Oct", 11 => "Nov", 12 => "Dec",
+        _ => "???",
+    }
+}
+
+/// Approximate month/day after adding `delta` days to (y, m, d).
+pub fn approx_month_day(y: i32, m: u32, d: u32, delta: i32) -> (u32, u32) {
+    let mut yy = y;
+    let mut mm = m;
+    let mut dd = d as i32 + delta;
+
+    while dd > days_in_month(mm, yy) as i32 {
+        dd -= days_in_month(mm, yy) as i32;
+        mm += 1;
+        if mm > 12 { mm = 1; yy += 1; }
+    }
+    while dd < 1 {
+        mm = if mm == 1 { 12 } else { mm - 1 };
+        if mm == 12 { yy -= 1; }
+        dd += days_in_month(mm, yy) as i32;
+    }
+
+    (mm, dd as u32)
+}
+
+/// Add `n` calendar months to (y, m, d), clamping the day to the last day
+/// of the resulting month (e.g. Jan 31 + 1 month → Feb 28/29).
+pub fn add_months(y: i32, m: u32, d: u32, n: u32) -> (i32, u32, u32) {
+    let total = (m - 1) as i64 + n as i64;
+    let yy = y + (total / 12) as i32;
+    let mm = (total % 12) as u32 + 1;
+    let dd = d.min(days_in_month(mm, yy));
+    (yy, mm, dd)
+}
@@ -1,7 +1,11 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
 use crate::{Severity, Status};
 
 /// A single security finding/vulnerability.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Finding {
     /// Database row id (`None` for findings not yet persisted).
     pub id: Option<i64>,
@@ -20,6 +24,20 @@ pub struct Finding {
     pub status: Status,
     /// Relative paths to images inside the POGDIR finding directory.
     pub images: Vec<String>,
+    /// CVSS v3.1 vector string (e.g. `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H`),
+    /// if the finding was scored. See [`Finding::base_score`].
+    pub cvss_vector: Option<String>,
+    /// An optional source-code excerpt (e.g. the vulnerable query or script),
+    /// rendered with basic syntax highlighting. See [`Finding::with_snippet`].
+    pub snippet: Option<crate::CodeSnippet>,
+    /// Free-form labels, e.g. `["auth", "idor"]`.
+    pub tags: Vec<String>,
+    /// Supporting URLs (advisories, writeups, CVE pages).
+    pub references: Vec<String>,
+    /// CWE identifier, e.g. `"CWE-89"`.
+    pub cwe: Option<String>,
+    /// Arbitrary front-matter keys this repo doesn't model explicitly yet.
+    pub extra: HashMap<String, String>,
 }
 
 impl Finding {
@@ -46,6 +64,12 @@ impl Finding {
             description: description.into(),
             status,
             images: Vec::new(),
+            cvss_vector: None,
+            snippet: None,
+            tags: Vec::new(),
+            references: Vec::new(),
+            cwe: None,
+            extra: HashMap::new(),
         }
     }
 
@@ -55,6 +79,59 @@ impl Finding {
         self
     }
 
+    /// Convenience builder to attach a CVSS v3.1 vector string.
+    pub fn with_cvss_vector(mut self, vector: impl Into<String>) -> Self {
+        self.cvss_vector = Some(vector.into());
+        self
+    }
+
+    /// Convenience builder to attach a highlighted code excerpt.
+    pub fn with_snippet(mut self, snippet: crate::CodeSnippet) -> Self {
+        self.snippet = Some(snippet);
+        self
+    }
+
+    /// Convenience builder to attach front-matter metadata: tags,
+    /// references, a CWE id, and any unmodeled `extra` keys.
+    pub fn with_metadata(mut self, tags: Vec<String>, references: Vec<String>, cwe: Option<String>, extra: HashMap<String, String>) -> Self {
+        self.tags = tags;
+        self.references = references;
+        self.cwe = cwe;
+        self.extra = extra;
+        self
+    }
+
+    /// Render [`Finding::description`] as styled lines, handling inline
+    /// code, bold/italic, bullet lists, and links. A description with none
+    /// of these renders exactly as plain text.
+    pub fn description_lines(&self) -> Vec<ratatui::text::Line<'static>> {
+        crate::markdown::to_lines(&self.description)
+    }
+
+    /// Compute the CVSS v3.1 base score from [`Finding::cvss_vector`], if
+    /// one is set and parses successfully.
+    pub fn base_score(&self) -> Option<f64> {
+        self.cvss_vector.as_deref().and_then(|v| crate::cvss::base_score(v).ok())
+    }
+
+    /// Build a finding whose [`Severity`] is derived from a CVSS v3.1 base
+    /// vector rather than hand-picked, e.g. when importing a scan result
+    /// that only carries a vector string.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_cvss(
+        title: impl Into<String>,
+        cvss_vector: impl Into<String>,
+        asset: impl Into<String>,
+        date: impl Into<String>,
+        location: impl Into<String>,
+        description: impl Into<String>,
+        status: Status,
+    ) -> Result<Self, crate::cvss::CvssError> {
+        let cvss_vector = cvss_vector.into();
+        let severity = crate::cvss::Cvss::parse(&cvss_vector)?.severity();
+        Ok(Self::new(title, severity, asset, date, location, description, status).with_cvss_vector(cvss_vector))
+    }
+
     /// Sample findings for demonstration / testing purposes.
     pub fn sample_findings() -> Vec<Finding> {
         vec![
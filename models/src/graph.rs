@@ -1,6 +1,6 @@
 use ratatui::style::Color;
 
-use crate::Severity;
+use crate::{Finding, Severity};
 
 /// A single bar in a severity distribution graph.
 #[derive(Clone, Debug)]
@@ -43,6 +43,21 @@ impl GraphData {
         self
     }
 
+    /// Build a severity-distribution graph from the counts in `findings`,
+    /// skipping severities with no findings. Used to derive real bars
+    /// instead of the hardcoded [`GraphData::sample_severity`] counts,
+    /// e.g. after loading findings from a saved report.
+    pub fn from_findings(title: impl Into<String>, findings: &[Finding]) -> Self {
+        let mut data = Self::new(title);
+        for &sev in Severity::ALL {
+            let count = findings.iter().filter(|f| f.severity == sev).count() as u64;
+            if count > 0 {
+                data = data.with_bar(SeverityBar::from_severity(sev, count));
+            }
+        }
+        data
+    }
+
     /// Sample graph data for demonstration / testing purposes.
     pub fn sample_severity() -> Self {
         Self::new("Severity Distribution")
@@ -3,9 +3,16 @@ mod status;
 mod finding;
 mod asset;
 mod graph;
+mod markdown;
+mod snippet;
+mod stats;
+pub mod cvss;
+pub mod dates;
 
 pub use severity::Severity;
 pub use status::Status;
 pub use finding::Finding;
 pub use asset::Asset;
 pub use graph::{GraphData, SeverityBar};
+pub use snippet::CodeSnippet;
+pub use stats::{sort_by_severity_desc, FindingStats};
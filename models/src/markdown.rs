@@ -0,0 +1,110 @@
+//! A tiny markdown-to-ratatui-`Line` renderer for [`crate::Finding::description_lines`].
+//!
+//! This is not a general markdown parser — it handles just the subset a
+//! finding's free-form description realistically needs: inline `` `code` ``,
+//! `**bold**`/`*italic*`, bullet list lines (`- `/`* `), and `[text](url)`
+//! links. A description with none of these renders exactly as plain text.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+/// Render `text` line by line, parsing inline markup within each line.
+pub fn to_lines(text: &str) -> Vec<Line<'static>> {
+    text.lines().map(render_line).collect()
+}
+
+fn render_line(line: &str) -> Line<'static> {
+    let trimmed = line.trim_start();
+    if let Some(rest) = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")) {
+        let mut spans = vec![Span::raw("• ")];
+        spans.extend(render_inline(rest));
+        return Line::from(spans);
+    }
+    Line::from(render_inline(line))
+}
+
+fn render_inline(text: &str) -> Vec<Span<'static>> {
+    let code_style = Style::default().bg(Color::DarkGray).fg(Color::White);
+    let bold_style = Style::default().add_modifier(Modifier::BOLD);
+    let italic_style = Style::default().add_modifier(Modifier::ITALIC);
+    let link_style = Style::default().fg(Color::Cyan).add_modifier(Modifier::UNDERLINED);
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] == '`' {
+            if let Some(end) = find_char(&chars, i + 1, '`') {
+                flush(&mut plain, &mut spans);
+                spans.push(Span::styled(chars[i + 1..end].iter().collect::<String>(), code_style));
+                i = end + 1;
+                continue;
+            }
+        }
+
+        if chars[i] == '*' && chars.get(i + 1) == Some(&'*') {
+            if let Some(end) = find_run(&chars, i + 2, &['*', '*']) {
+                flush(&mut plain, &mut spans);
+                spans.push(Span::styled(chars[i + 2..end].iter().collect::<String>(), bold_style));
+                i = end + 2;
+                continue;
+            }
+        }
+
+        if chars[i] == '*' {
+            if let Some(end) = find_char(&chars, i + 1, '*') {
+                flush(&mut plain, &mut spans);
+                spans.push(Span::styled(chars[i + 1..end].iter().collect::<String>(), italic_style));
+                i = end + 1;
+                continue;
+            }
+        }
+
+        if chars[i] == '[' {
+            if let Some((display, url, end)) = try_parse_link(&chars, i) {
+                flush(&mut plain, &mut spans);
+                spans.push(Span::styled(format!("{display} ({url})"), link_style));
+                i = end;
+                continue;
+            }
+        }
+
+        plain.push(chars[i]);
+        i += 1;
+    }
+
+    flush(&mut plain, &mut spans);
+    if spans.is_empty() {
+        spans.push(Span::raw(String::new()));
+    }
+    spans
+}
+
+fn flush(plain: &mut String, spans: &mut Vec<Span<'static>>) {
+    if !plain.is_empty() {
+        spans.push(Span::raw(std::mem::take(plain)));
+    }
+}
+
+fn find_char(chars: &[char], start: usize, delim: char) -> Option<usize> {
+    (start..chars.len()).find(|&j| chars[j] == delim)
+}
+
+fn find_run(chars: &[char], start: usize, delim: &[char]) -> Option<usize> {
+    (start..=chars.len().saturating_sub(delim.len())).find(|&j| chars[j..j + delim.len()] == *delim)
+}
+
+/// Parse a `[display](url)` link starting at `chars[start] == '['`,
+/// returning the display text, url, and the index just past the closing `)`.
+fn try_parse_link(chars: &[char], start: usize) -> Option<(String, String, usize)> {
+    let close_bracket = find_char(chars, start + 1, ']')?;
+    if chars.get(close_bracket + 1) != Some(&'(') {
+        return None;
+    }
+    let close_paren = find_char(chars, close_bracket + 2, ')')?;
+    let display: String = chars[start + 1..close_bracket].iter().collect();
+    let url: String = chars[close_bracket + 2..close_paren].iter().collect();
+    Some((display, url, close_paren + 1))
+}
@@ -1,7 +1,8 @@
 use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
 
 /// Severity level for a security finding.
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Serialize, Deserialize)]
 pub enum Severity {
     Critical,
     High,
@@ -10,6 +11,32 @@ pub enum Severity {
     Info,
 }
 
+impl Severity {
+    /// Sort rank: higher is more severe, so `Severity::Critical` is the
+    /// maximum and `Severity::Info` the minimum under [`Ord`].
+    fn rank(&self) -> u8 {
+        match self {
+            Severity::Info => 0,
+            Severity::Low => 1,
+            Severity::Medium => 2,
+            Severity::High => 3,
+            Severity::Critical => 4,
+        }
+    }
+}
+
+impl PartialOrd for Severity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Severity {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
 impl Severity {
     /// All severity variants in descending order.
     pub const ALL: &[Severity] = &[
@@ -39,6 +66,22 @@ impl Severity {
             Severity::Info => Color::Blue,
         }
     }
+
+    /// Map a CVSS v3.1 base score (0.0–10.0) to a severity rating, per the
+    /// qualitative severity rating scale in the CVSS v3.1 spec.
+    pub fn from_cvss_score(score: f64) -> Severity {
+        if score <= 0.0 {
+            Severity::Info
+        } else if score < 4.0 {
+            Severity::Low
+        } else if score < 7.0 {
+            Severity::Medium
+        } else if score < 9.0 {
+            Severity::High
+        } else {
+            Severity::Critical
+        }
+    }
 }
 
 impl std::fmt::Display for Severity {
@@ -56,7 +99,7 @@ impl std::str::FromStr for Severity {
             "high" => Ok(Severity::High),
             "medium" => Ok(Severity::Medium),
             "low" => Ok(Severity::Low),
-            "info" | "informational" => Ok(Severity::Info),
+            "info" | "informational" | "none" => Ok(Severity::Info),
             other => Err(format!("unknown severity: {other}")),
         }
     }
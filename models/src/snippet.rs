@@ -0,0 +1,148 @@
+//! An optional source-code excerpt attached to a [`crate::Finding`], with a
+//! lightweight keyword/string/number/comment tokenizer (in the spirit of
+//! rustdoc's `html/highlight` classifier) covering a handful of languages
+//! common in pentest evidence: SQL, JavaScript, and shell. Any other `lang`
+//! falls back to plain, uncolored monospaced text.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use serde::{Deserialize, Serialize};
+
+/// A code excerpt plus the language it should be highlighted as (e.g.
+/// `"sql"`, `"javascript"`, `"shell"`).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CodeSnippet {
+    pub code: String,
+    pub lang: String,
+}
+
+impl CodeSnippet {
+    pub fn new(code: impl Into<String>, lang: impl Into<String>) -> Self {
+        Self { code: code.into(), lang: lang.into() }
+    }
+
+    /// Tokenize and render this snippet as styled lines.
+    pub fn highlight(&self) -> Vec<Line<'static>> {
+        let keywords = keywords_for(&self.lang);
+        let line_comment = line_comment_for(&self.lang);
+        self.code.lines().map(|line| highlight_line(line, keywords, line_comment)).collect()
+    }
+}
+
+fn keywords_for(lang: &str) -> &'static [&'static str] {
+    match lang.to_lowercase().as_str() {
+        "sql" => &[
+            "select", "from", "where", "insert", "into", "values", "update", "set", "delete",
+            "join", "inner", "outer", "left", "right", "on", "and", "or", "not", "null",
+            "order", "by", "group", "having", "limit", "union", "create", "table", "drop", "alter",
+        ],
+        "javascript" | "js" => &[
+            "function", "const", "let", "var", "return", "if", "else", "for", "while",
+            "class", "import", "export", "from", "async", "await", "new", "this",
+            "null", "undefined", "true", "false",
+        ],
+        "shell" | "sh" | "bash" => &[
+            "if", "then", "else", "fi", "for", "do", "done", "while", "echo", "export",
+            "function", "case", "esac", "local", "return",
+        ],
+        _ => &[],
+    }
+}
+
+fn line_comment_for(lang: &str) -> Option<&'static str> {
+    match lang.to_lowercase().as_str() {
+        "sql" => Some("--"),
+        "javascript" | "js" => Some("//"),
+        "shell" | "sh" | "bash" => Some("#"),
+        _ => None,
+    }
+}
+
+fn highlight_line(line: &str, keywords: &[&str], line_comment: Option<&str>) -> Line<'static> {
+    if keywords.is_empty() && line_comment.is_none() {
+        return Line::raw(line.to_string());
+    }
+
+    if let Some(prefix) = line_comment
+        && let Some(idx) = line.find(prefix)
+    {
+        let (code, comment) = line.split_at(idx);
+        let mut spans = tokenize(code, keywords);
+        spans.push(Span::styled(comment.to_string(), Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC)));
+        return Line::from(spans);
+    }
+
+    Line::from(tokenize(line, keywords))
+}
+
+/// Classify `text` into keyword/string/number/punctuation spans, in the
+/// spirit of rustdoc's `html/highlight` classifier but producing ratatui
+/// spans directly instead of HTML.
+fn tokenize(text: &str, keywords: &[&str]) -> Vec<Span<'static>> {
+    let keyword_style = Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD);
+    let string_style = Style::default().fg(Color::Green);
+    let number_style = Style::default().fg(Color::Yellow);
+    let punct_style = Style::default().fg(Color::DarkGray);
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut spans = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '\'' || c == '"' || c == '`' {
+            let start = i;
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                i += 1;
+            }
+            if i < chars.len() {
+                i += 1;
+            }
+            spans.push(Span::styled(chars[start..i].iter().collect::<String>(), string_style));
+            continue;
+        }
+
+        if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            spans.push(Span::styled(chars[start..i].iter().collect::<String>(), number_style));
+            continue;
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            let style = if keywords.iter().any(|k| k.eq_ignore_ascii_case(&word)) { keyword_style } else { Style::default() };
+            spans.push(Span::styled(word, style));
+            continue;
+        }
+
+        if c.is_whitespace() {
+            let start = i;
+            while i < chars.len() && chars[i].is_whitespace() {
+                i += 1;
+            }
+            spans.push(Span::raw(chars[start..i].iter().collect::<String>()));
+            continue;
+        }
+
+        let start = i;
+        while i < chars.len()
+            && !chars[i].is_whitespace()
+            && !chars[i].is_alphanumeric()
+            && !matches!(chars[i], '_' | '\'' | '"' | '`')
+        {
+            i += 1;
+        }
+        spans.push(Span::styled(chars[start..i].iter().collect::<String>(), punct_style));
+    }
+
+    spans
+}
@@ -0,0 +1,49 @@
+//! Aggregated counts over a finding corpus — the headline "X Critical, Y
+//! High…" numbers a pentest deliverable's executive summary needs, derived
+//! once so the TUI dashboard and PDF report agree on them.
+
+use std::collections::BTreeMap;
+
+use crate::{Finding, Severity, Status};
+
+/// Per-severity and per-status counts over a slice of findings.
+#[derive(Clone, Debug, Default)]
+pub struct FindingStats {
+    pub by_severity: BTreeMap<Severity, usize>,
+    pub by_status: BTreeMap<Status, usize>,
+    /// Findings that are both `Severity::Critical` and `Status::Open`.
+    pub open_critical_count: usize,
+    /// The highest severity among still-open findings, if any are open.
+    pub highest_open_severity: Option<Severity>,
+}
+
+impl FindingStats {
+    /// Aggregate `findings` into severity/status counts in a single pass.
+    pub fn from_findings(findings: &[Finding]) -> Self {
+        let mut stats = FindingStats::default();
+
+        for f in findings {
+            *stats.by_severity.entry(f.severity).or_insert(0) += 1;
+            *stats.by_status.entry(f.status).or_insert(0) += 1;
+
+            if f.status == Status::Open {
+                if f.severity == Severity::Critical {
+                    stats.open_critical_count += 1;
+                }
+                stats.highest_open_severity = Some(match stats.highest_open_severity {
+                    Some(current) => current.max(f.severity),
+                    None => f.severity,
+                });
+            }
+        }
+
+        stats
+    }
+}
+
+/// Stable-sort `findings` by descending severity (`Critical` first), so
+/// lists in the TUI and report render in a deterministic, risk-ordered way.
+/// Ties (same severity) keep their relative order.
+pub fn sort_by_severity_desc(findings: &mut [Finding]) {
+    findings.sort_by_key(|f| std::cmp::Reverse(f.severity));
+}
@@ -1,7 +1,8 @@
 use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
 
 /// Workflow status for a security finding.
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Serialize, Deserialize)]
 pub enum Status {
     Open,
     InProgress,
@@ -10,6 +11,14 @@ pub enum Status {
 }
 
 impl Status {
+    /// All status variants, in workflow order.
+    pub const ALL: &[Status] = &[
+        Status::Open,
+        Status::InProgress,
+        Status::Resolved,
+        Status::FalsePositive,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Status::Open => "Open",
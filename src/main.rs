@@ -5,7 +5,7 @@ use std::process;
 mod log;
 
 use cli::{parse_args, ClientAction, Commands};
-use models::{GraphData, Severity, SeverityBar};
+use models::GraphData;
 use storage::PogDir;
 
 fn main() {
@@ -31,17 +31,38 @@ fn run() -> std::result::Result<(), Box<dyn std::error::Error>> {
         // (Client was already handled above.)
         Commands::Client { .. } => unreachable!(),
 
-        Commands::ImportFindings { path, bulk } => {
-            let folder = Path::new(&path);
-            if bulk {
-                let findings = storage::import_bulk(folder, &pog)?;
+        Commands::ImportFindings { path, bulk, recursive, format } => {
+            let target = Path::new(&path);
+            let adapter_format: storage::ImportFormat = format.parse().map_err(|e: String| e)?;
+
+            if recursive {
+                let findings = storage::import_recursive(target, &pog)?;
+                success!("Imported {} finding(s)", findings.len());
+                for f in &findings {
+                    info!("{} [{}] ({})", f.title, f.severity, f.asset);
+                }
+            } else if bulk {
+                let findings = storage::import_bulk_format(target, &pog, adapter_format)?;
+                success!("Imported {} finding(s)", findings.len());
+                for f in &findings {
+                    info!("{} [{}] ({})", f.title, f.severity, f.asset);
+                }
+            } else if target.is_file() {
+                // A single scanner-export file (SARIF/CSV/JSON) can hold
+                // many findings even outside --bulk.
+                let findings = storage::import_file(target, &pog, adapter_format)?;
                 success!("Imported {} finding(s)", findings.len());
                 for f in &findings {
                     info!("{} [{}] ({})", f.title, f.severity, f.asset);
                 }
             } else {
-                let finding = storage::import_finding(folder, &pog)?;
-                success!("Imported: {} [{}] ({})", finding.title, finding.severity, finding.asset);
+                let outcome = storage::import_finding(target, &pog)?;
+                let finding = outcome.finding();
+                if outcome.is_unchanged() {
+                    success!("Unchanged: {} [{}] ({})", finding.title, finding.severity, finding.asset);
+                } else {
+                    success!("Imported: {} [{}] ({})", finding.title, finding.severity, finding.asset);
+                }
             }
         }
 
@@ -59,44 +80,86 @@ fn run() -> std::result::Result<(), Box<dyn std::error::Error>> {
             }
         }
 
-        Commands::View {} => {
+        Commands::View { no_cache } => {
             let db = pog.open_db()?;
             let findings = db.all_findings()?;
             let assets = db.all_assets()?;
-            let graph_data = build_graph_data(&findings);
+            let graph_data = if no_cache {
+                build_graph_data(&findings)
+            } else {
+                let cache = pog.cache()?;
+                let key = graph_cache_key(&findings);
+                match cache.get_graph_data(&key) {
+                    Some(data) => data,
+                    None => {
+                        let data = build_graph_data(&findings);
+                        cache.put_graph_data(&key, &data)?;
+                        data
+                    }
+                }
+            };
 
-            tui::run_with_data(graph_data, findings, assets)?;
+            tui::run_with_data(pog, graph_data, findings, assets)?;
         }
 
-        Commands::Report { output, template, asset, from, to } => {
+        Commands::Report {
+            output, template, asset, from, to, no_cache, repeat, skip_empty, preprocessors,
+            legacy_latex_escape,
+        } => {
             let db = pog.open_db()?;
-            let findings = db.findings_filtered(
-                Some(asset.as_str()),
-                Some(from.as_str()),
-                Some(to.as_str()),
-            )?;
-
-            if findings.is_empty() {
-                error!("No findings match the given filters");
-                process::exit(1);
-            }
+            let cache = if no_cache { None } else { Some(pog.cache()?) };
+            let preprocessors: Vec<Box<dyn storage::Preprocessor>> = preprocessors
+                .iter()
+                .map(|cmd| Box::new(storage::CmdPreprocessor::new(cmd.clone(), cmd)) as Box<dyn storage::Preprocessor>)
+                .collect();
+
+            match repeat {
+                None => {
+                    let findings = db.findings_filtered(
+                        Some(asset.as_str()),
+                        Some(from.as_str()),
+                        Some(to.as_str()),
+                    )?;
+
+                    if findings.is_empty() {
+                        error!("No findings match the given filters");
+                        process::exit(1);
+                    }
+
+                    info!("Generating report for {} finding(s)…", findings.len());
+                    storage::generate_report(
+                        &findings, &template, &output, &asset, &from, &to, &pog, cache.as_ref(),
+                        &preprocessors, legacy_latex_escape,
+                    )?;
+                    success!("Report written to {}", output);
+                }
+                Some(rrule) => {
+                    let rule = storage::Rrule::parse(&rrule)?;
+                    let windows = rule.windows(&from, &to)?;
+                    info!("Generating {} report(s) for {}..{}…", windows.len(), from, to);
+
+                    for (window_from, window_to) in windows {
+                        let findings = db.findings_filtered(
+                            Some(asset.as_str()),
+                            Some(window_from.as_str()),
+                            Some(window_to.as_str()),
+                        )?;
 
-            info!(
-                "Generating report for {} finding(s)…",
-                findings.len()
-            );
-
-            storage::generate_report(
-                &findings,
-                &template,
-                &output,
-                &asset,
-                &from,
-                &to,
-                &pog,
-            )?;
-
-            success!("Report written to {}", output);
+                        if findings.is_empty() && skip_empty {
+                            info!("Skipping {window_from}..{window_to} (no findings)");
+                            continue;
+                        }
+
+                        let windowed_output = with_date_suffix(&output, &window_from);
+                        storage::generate_report(
+                            &findings, &template, &windowed_output, &asset,
+                            &window_from, &window_to, &pog, cache.as_ref(),
+                            &preprocessors, legacy_latex_escape,
+                        )?;
+                        success!("Report written to {}", windowed_output);
+                    }
+                }
+            }
         }
 
         Commands::UpdateStatus { asset, id, status } => {
@@ -107,16 +170,57 @@ fn run() -> std::result::Result<(), Box<dyn std::error::Error>> {
             success!("{} [{}] ({}) → {}", title, id, asset, parsed);
         }
 
-        Commands::Clean {} => {
+        Commands::Clean { cache } => {
             pog.clean()?;
-            success!("Database and findings directory wiped clean");
+            if cache {
+                pog.cache()?.wipe()?;
+                success!("Database, findings directory, and cache wiped clean");
+            } else {
+                success!("Database and findings directory wiped clean");
+            }
         }
 
-        Commands::Export { output, asset, from, to } => {
+        Commands::Export { output, asset, from, to, repeat, skip_empty } => {
             let db = pog.open_db()?;
-            let csv = db.export_csv(asset.as_deref(), from.as_deref(), to.as_deref())?;
-            std::fs::write(&output, &csv)?;
-            success!("Exported findings to {}", output);
+
+            match repeat {
+                None => {
+                    let csv = db.export_csv(asset.as_deref(), from.as_deref(), to.as_deref())?;
+                    std::fs::write(&output, &csv)?;
+                    success!("Exported findings to {}", output);
+                }
+                Some(rrule) => {
+                    let (from, to) = match (from.as_deref(), to.as_deref()) {
+                        (Some(from), Some(to)) => (from, to),
+                        _ => {
+                            error!("--repeat requires both --from and --to");
+                            process::exit(1);
+                        }
+                    };
+
+                    let rule = storage::Rrule::parse(&rrule)?;
+                    let windows = rule.windows(from, to)?;
+                    info!("Generating {} export(s) for {}..{}…", windows.len(), from, to);
+
+                    for (window_from, window_to) in windows {
+                        let findings = db.findings_filtered(
+                            asset.as_deref(),
+                            Some(window_from.as_str()),
+                            Some(window_to.as_str()),
+                        )?;
+
+                        if findings.is_empty() && skip_empty {
+                            info!("Skipping {window_from}..{window_to} (no findings)");
+                            continue;
+                        }
+
+                        let csv = db.export_csv(asset.as_deref(), Some(&window_from), Some(&window_to))?;
+                        let windowed_output = with_date_suffix(&output, &window_from);
+                        std::fs::write(&windowed_output, &csv)?;
+                        success!("Exported findings to {}", windowed_output);
+                    }
+                }
+            }
         }
     }
 
@@ -164,14 +268,39 @@ fn handle_client_action(action: ClientAction) -> std::result::Result<(), Box<dyn
     Ok(())
 }
 
+/// Cache key for the severity-distribution graph: a hash of each finding's
+/// hex id and severity, so an import or a severity change invalidates the
+/// cached bars without needing to re-derive them to compare. Status and
+/// other field edits leave the key (and the bars) unchanged.
+fn graph_cache_key(findings: &[models::Finding]) -> String {
+    let parts: Vec<String> = findings
+        .iter()
+        .map(|f| format!("{}:{}", f.hex_id, f.severity))
+        .collect();
+    storage::Cache::key(&parts.iter().map(String::as_str).collect::<Vec<_>>())
+}
+
 /// Build a `GraphData` from the severity distribution of real findings.
 fn build_graph_data(findings: &[models::Finding]) -> GraphData {
-    let mut data = GraphData::new("Severity Distribution");
-    for &sev in Severity::ALL {
-        let count = findings.iter().filter(|f| f.severity == sev).count() as u64;
-        if count > 0 {
-            data = data.with_bar(SeverityBar::from_severity(sev, count));
-        }
+    GraphData::from_findings("Severity Distribution", findings)
+}
+
+/// Insert `-{date}` before a `--repeat` output path's extension, turning
+/// the `date` window start (a "YYYY/MM/DD" string) into a filename-safe
+/// `YYYY-MM-DD` suffix, e.g. `report.pdf` + `2024/09/01` →
+/// `report-2024-09-01.pdf`.
+fn with_date_suffix(path: &str, date: &str) -> String {
+    let suffix = date.replace('/', "-");
+    let p = Path::new(path);
+    match (p.parent(), p.file_stem(), p.extension()) {
+        (Some(parent), Some(stem), Some(ext)) => parent
+            .join(format!("{}-{}.{}", stem.to_string_lossy(), suffix, ext.to_string_lossy()))
+            .to_string_lossy()
+            .into_owned(),
+        (Some(parent), Some(stem), None) => parent
+            .join(format!("{}-{}", stem.to_string_lossy(), suffix))
+            .to_string_lossy()
+            .into_owned(),
+        _ => format!("{path}-{suffix}"),
     }
-    data
 }
\ No newline at end of file
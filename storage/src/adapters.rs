@@ -0,0 +1,444 @@
+//! Pluggable import adapters for non-native finding formats.
+//!
+//! pog's own layout (a folder per finding, `.md` + YAML front-matter) is
+//! handled by [`NativeAdapter`], but users also want to ingest output from
+//! other scanners. An [`ImportAdapter`] owns both detection (`detect`) and
+//! parsing (`parse`) for one format; [`resolve`] lets a caller either force
+//! a specific format or fall back to [`ImportFormat::Auto`], which offers
+//! the file to each built-in adapter in turn and uses the first match.
+//!
+//! Adapters only produce unpersisted [`Finding`]s — `crate::import` is
+//! still responsible for assigning hex IDs, writing to the DB, and copying
+//! source files into the POGDIR.
+
+use std::path::Path;
+use std::fs;
+
+use models::{Finding, Severity, Status};
+
+use crate::error::{Result, StorageError};
+use crate::import::normalise_asset;
+
+/// One importable format: recognizes its own files and turns them into
+/// unpersisted [`Finding`]s.
+pub trait ImportAdapter {
+    /// Short, lowercase name used in error messages and `--format` parsing.
+    fn name(&self) -> &'static str;
+
+    /// Whether this adapter can handle `path`, used by [`ImportFormat::Auto`].
+    fn detect(&self, path: &Path) -> bool;
+
+    /// Parse `path` into zero or more findings.
+    fn parse(&self, path: &Path) -> Result<Vec<Finding>>;
+}
+
+/// Which adapter should handle an import. `Auto` offers the file to every
+/// built-in adapter and uses the first whose `detect` matches; the other
+/// variants force a specific adapter regardless of `detect`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImportFormat {
+    Auto,
+    Native,
+    Sarif,
+    Csv,
+    Json,
+}
+
+impl std::str::FromStr for ImportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "auto" => Ok(ImportFormat::Auto),
+            "native" => Ok(ImportFormat::Native),
+            "sarif" => Ok(ImportFormat::Sarif),
+            "csv" => Ok(ImportFormat::Csv),
+            "json" => Ok(ImportFormat::Json),
+            other => Err(format!("unknown import format: {other}")),
+        }
+    }
+}
+
+/// Resolve the adapter that should handle `path` under `format`. Explicit
+/// formats always win; `Auto` consults the built-in registry in order.
+pub fn resolve(path: &Path, format: ImportFormat) -> Result<Box<dyn ImportAdapter>> {
+    match format {
+        ImportFormat::Native => Ok(Box::new(NativeAdapter)),
+        ImportFormat::Sarif => Ok(Box::new(SarifAdapter)),
+        ImportFormat::Csv => Ok(Box::new(CsvAdapter)),
+        ImportFormat::Json => Ok(Box::new(JsonAdapter)),
+        ImportFormat::Auto => registry()
+            .into_iter()
+            .find(|adapter| adapter.detect(path))
+            .ok_or_else(|| StorageError::UnsupportedFormat(path.display().to_string())),
+    }
+}
+
+/// Built-in adapters in detection priority order. SARIF is checked before
+/// the generic JSON adapter so a `.json`-extensioned SARIF log doesn't get
+/// swallowed by the looser JSON adapter.
+fn registry() -> Vec<Box<dyn ImportAdapter>> {
+    vec![
+        Box::new(SarifAdapter),
+        Box::new(JsonAdapter),
+        Box::new(CsvAdapter),
+        Box::new(NativeAdapter),
+    ]
+}
+
+// ---------------------------------------------------------------------------
+// Native (.md + YAML front-matter)
+// ---------------------------------------------------------------------------
+
+/// pog's own finding format: a `.md` file with YAML front-matter, as
+/// parsed by [`crate::import::parse_finding_md`].
+pub struct NativeAdapter;
+
+impl ImportAdapter for NativeAdapter {
+    fn name(&self) -> &'static str {
+        "native"
+    }
+
+    fn detect(&self, path: &Path) -> bool {
+        has_extension(path, "md")
+    }
+
+    fn parse(&self, path: &Path) -> Result<Vec<Finding>> {
+        let raw = fs::read_to_string(path)?;
+        // The folder containing the .md file is the finding's slug in
+        // pog's native layout (see `import_finding`).
+        let slug = path
+            .parent()
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            .unwrap_or("finding");
+        Ok(vec![crate::import::parse_finding_md(&raw, slug)?])
+    }
+}
+
+// ---------------------------------------------------------------------------
+// SARIF 2.1.0
+// ---------------------------------------------------------------------------
+
+/// SARIF (Static Analysis Results Interchange Format) logs, as produced by
+/// most SAST/DAST tools' CI integrations. Only the fields pog has a home
+/// for are read: `runs[].results[]` → one finding each, `ruleId` → title,
+/// `message.text` → description, `level` → severity, and the first
+/// location's artifact URI → location/asset.
+pub struct SarifAdapter;
+
+impl ImportAdapter for SarifAdapter {
+    fn name(&self) -> &'static str {
+        "sarif"
+    }
+
+    fn detect(&self, path: &Path) -> bool {
+        if has_extension(path, "sarif") {
+            return true;
+        }
+        has_extension(path, "json")
+            && fs::read_to_string(path)
+                .map(|raw| raw.contains("\"$schema\"") && raw.contains("sarif"))
+                .unwrap_or(false)
+    }
+
+    fn parse(&self, path: &Path) -> Result<Vec<Finding>> {
+        let raw = fs::read_to_string(path)?;
+        let root: serde_json::Value = serde_json::from_str(&raw)
+            .map_err(|e| StorageError::ParseError(format!("invalid SARIF JSON: {e}")))?;
+
+        let mut findings = Vec::new();
+        for run in root.get("runs").and_then(|v| v.as_array()).into_iter().flatten() {
+            for result in run.get("results").and_then(|v| v.as_array()).into_iter().flatten() {
+                let title = result
+                    .get("ruleId")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("sarif-finding")
+                    .to_string();
+
+                let description = result
+                    .get("message")
+                    .and_then(|m| m.get("text"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string();
+
+                let severity = sarif_level_to_severity(
+                    result.get("level").and_then(|v| v.as_str()).unwrap_or("warning"),
+                );
+
+                let location = result
+                    .get("locations")
+                    .and_then(|v| v.as_array())
+                    .and_then(|a| a.first())
+                    .and_then(|l| l.get("physicalLocation"))
+                    .and_then(|p| p.get("artifactLocation"))
+                    .and_then(|a| a.get("uri"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string();
+
+                let asset = location
+                    .split('/')
+                    .find(|segment| !segment.is_empty())
+                    .map(normalise_asset)
+                    .unwrap_or_else(|| "unknown".to_string());
+
+                findings.push(Finding {
+                    id: None,
+                    hex_id: String::new(),
+                    slug: slugify(&title),
+                    title,
+                    severity,
+                    asset,
+                    date: String::new(),
+                    location,
+                    description,
+                    status: Status::Open,
+                    images: Vec::new(),
+                    cvss_vector: None,
+                    snippet: None,
+                    tags: Vec::new(),
+                    references: Vec::new(),
+                    cwe: None,
+                    extra: std::collections::HashMap::new(),
+                });
+            }
+        }
+        Ok(findings)
+    }
+}
+
+fn sarif_level_to_severity(level: &str) -> Severity {
+    match level {
+        "error" => Severity::High,
+        "warning" => Severity::Medium,
+        "note" => Severity::Low,
+        _ => Severity::Info,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Generic CSV
+// ---------------------------------------------------------------------------
+
+/// A generic CSV export. The header row names the columns; recognized
+/// columns (case-insensitive, with a couple of common aliases) map onto
+/// `Finding` fields and everything else is ignored. Parsing is
+/// intentionally lenient, same as the native markdown parser — it doesn't
+/// handle quoted fields containing commas.
+pub struct CsvAdapter;
+
+impl ImportAdapter for CsvAdapter {
+    fn name(&self) -> &'static str {
+        "csv"
+    }
+
+    fn detect(&self, path: &Path) -> bool {
+        has_extension(path, "csv")
+    }
+
+    fn parse(&self, path: &Path) -> Result<Vec<Finding>> {
+        let raw = fs::read_to_string(path)?;
+        let mut lines = raw.lines();
+        let header = lines
+            .next()
+            .ok_or_else(|| StorageError::ParseError("CSV file is empty".into()))?;
+        let columns: Vec<String> = header.split(',').map(|c| c.trim().to_lowercase()).collect();
+
+        let mut findings = Vec::new();
+        for line in lines {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let cells: Vec<&str> = line.split(',').collect();
+            let get = |names: &[&str]| -> String {
+                names
+                    .iter()
+                    .find_map(|name| columns.iter().position(|c| c == name))
+                    .and_then(|i| cells.get(i))
+                    .map(|v| v.trim().to_string())
+                    .unwrap_or_default()
+            };
+
+            let title = get(&["title", "name"]);
+            if title.is_empty() {
+                continue;
+            }
+
+            findings.push(Finding {
+                id: None,
+                hex_id: String::new(),
+                slug: slugify(&title),
+                severity: get(&["severity", "risk"]).parse().unwrap_or(Severity::Info),
+                asset: normalise_asset(&get(&["asset", "host"])),
+                date: get(&["date"]),
+                location: get(&["location", "url"]),
+                description: get(&["description", "desc"]),
+                status: get(&["status"]).parse().unwrap_or(Status::Open),
+                images: Vec::new(),
+                cvss_vector: None,
+                snippet: None,
+                tags: Vec::new(),
+                references: Vec::new(),
+                cwe: None,
+                extra: std::collections::HashMap::new(),
+                title,
+            });
+        }
+        Ok(findings)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Generic JSON
+// ---------------------------------------------------------------------------
+
+/// A generic JSON export: either a top-level array of finding objects, or a
+/// single finding object. Field names are matched case-sensitively against
+/// a short list of common aliases (e.g. `asset`/`host`/`target`).
+pub struct JsonAdapter;
+
+impl ImportAdapter for JsonAdapter {
+    fn name(&self) -> &'static str {
+        "json"
+    }
+
+    fn detect(&self, path: &Path) -> bool {
+        has_extension(path, "json")
+    }
+
+    fn parse(&self, path: &Path) -> Result<Vec<Finding>> {
+        let raw = fs::read_to_string(path)?;
+        let root: serde_json::Value = serde_json::from_str(&raw)
+            .map_err(|e| StorageError::ParseError(format!("invalid JSON: {e}")))?;
+
+        let items: Vec<&serde_json::Value> = match &root {
+            serde_json::Value::Array(items) => items.iter().collect(),
+            serde_json::Value::Object(_) => vec![&root],
+            _ => Vec::new(),
+        };
+
+        let mut findings = Vec::new();
+        for item in items {
+            let title = json_str(item, &["title", "name"]).unwrap_or_else(|| "untitled".to_string());
+
+            findings.push(Finding {
+                id: None,
+                hex_id: String::new(),
+                slug: slugify(&title),
+                severity: json_str(item, &["severity", "risk"])
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(Severity::Info),
+                asset: normalise_asset(&json_str(item, &["asset", "host", "target"]).unwrap_or_default()),
+                date: json_str(item, &["date"]).unwrap_or_default(),
+                location: json_str(item, &["location", "url"]).unwrap_or_default(),
+                description: json_str(item, &["description", "desc"]).unwrap_or_default(),
+                status: json_str(item, &["status"]).and_then(|s| s.parse().ok()).unwrap_or(Status::Open),
+                images: Vec::new(),
+                cvss_vector: None,
+                snippet: None,
+                tags: Vec::new(),
+                references: Vec::new(),
+                cwe: None,
+                extra: std::collections::HashMap::new(),
+                title,
+            });
+        }
+        Ok(findings)
+    }
+}
+
+fn json_str(value: &serde_json::Value, keys: &[&str]) -> Option<String> {
+    keys.iter().find_map(|k| value.get(k).and_then(|v| v.as_str()).map(String::from))
+}
+
+// ---------------------------------------------------------------------------
+// Shared helpers
+// ---------------------------------------------------------------------------
+
+fn has_extension(path: &Path, ext: &str) -> bool {
+    path.extension().and_then(|e| e.to_str()).map(|e| e.eq_ignore_ascii_case(ext)).unwrap_or(false)
+}
+
+/// Derive a folder-safe slug from a finding title, matching `Finding::new`'s
+/// convention.
+fn slugify(title: &str) -> String {
+    title.to_lowercase().replace(' ', "-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn write_temp(contents: &str, ext: &str) -> NamedTempFile {
+        let mut file = tempfile::Builder::new().suffix(&format!(".{ext}")).tempfile().unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_sarif_detect_by_extension() {
+        let file = write_temp("{}", "sarif");
+        assert!(SarifAdapter.detect(file.path()));
+    }
+
+    #[test]
+    fn test_sarif_parse_maps_severity_and_location() {
+        let sarif = r#"{
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "runs": [{
+                "results": [{
+                    "ruleId": "sql-injection",
+                    "level": "error",
+                    "message": { "text": "User input concatenated into SQL query." },
+                    "locations": [{ "physicalLocation": { "artifactLocation": { "uri": "web_app/api/users" } } }]
+                }]
+            }]
+        }"#;
+        let file = write_temp(sarif, "sarif");
+        let findings = SarifAdapter.parse(file.path()).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].title, "sql-injection");
+        assert_eq!(findings[0].severity, Severity::High);
+        assert_eq!(findings[0].asset, "web_app");
+    }
+
+    #[test]
+    fn test_csv_parse_maps_columns() {
+        let csv = "title,severity,asset,location\nSQL Injection,Critical,Web App,/api/users\n";
+        let file = write_temp(csv, "csv");
+        let findings = CsvAdapter.parse(file.path()).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].title, "SQL Injection");
+        assert_eq!(findings[0].severity, Severity::Critical);
+        assert_eq!(findings[0].asset, "web_app");
+    }
+
+    #[test]
+    fn test_json_parse_array_of_findings() {
+        let json = r#"[{"title": "XSS", "severity": "High", "host": "portal.corp"}]"#;
+        let file = write_temp(json, "json");
+        let findings = JsonAdapter.parse(file.path()).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].title, "XSS");
+        assert_eq!(findings[0].asset, "portal_corp");
+    }
+
+    #[test]
+    fn test_resolve_auto_picks_sarif_over_json() {
+        let sarif = r#"{"$schema": "https://json.schemastore.org/sarif-2.1.0.json", "runs": []}"#;
+        let file = tempfile::Builder::new().suffix(".json").tempfile().unwrap();
+        std::fs::write(file.path(), sarif).unwrap();
+        let adapter = resolve(file.path(), ImportFormat::Auto).unwrap();
+        assert_eq!(adapter.name(), "sarif");
+    }
+
+    #[test]
+    fn test_resolve_unsupported_file_errors() {
+        let file = write_temp("not a recognized format", "txt");
+        assert!(resolve(file.path(), ImportFormat::Auto).is_err());
+    }
+}
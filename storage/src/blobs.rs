@@ -0,0 +1,35 @@
+//! Content-addressed blob storage, used to dedup images reused across
+//! findings: each distinct file is written once under
+//! `<POGDIR>/blobs/<sha256-hex>` instead of being copied into every finding
+//! folder that references it.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+use crate::error::Result;
+
+/// Hex-encoded SHA-256 digest of `bytes`, used both as the blob's filename
+/// and as the content hash folded into a finding's change-detection hash.
+pub fn hash_bytes(bytes: &[u8]) -> String {
+    let digest = Sha256::digest(bytes);
+    digest.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Write `data` under `blobs_dir/<hash>` if it isn't already stored there,
+/// and return the hash.
+pub fn store(blobs_dir: &Path, data: &[u8]) -> Result<String> {
+    fs::create_dir_all(blobs_dir)?;
+    let hash = hash_bytes(data);
+    let path = blobs_dir.join(&hash);
+    if !path.is_file() {
+        fs::write(&path, data)?;
+    }
+    Ok(hash)
+}
+
+/// Path to a previously stored blob, given its hash.
+pub fn blob_path(blobs_dir: &Path, hash: &str) -> PathBuf {
+    blobs_dir.join(hash)
+}
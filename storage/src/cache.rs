@@ -0,0 +1,151 @@
+// storage/src/cache.rs — XDG-compliant cache for expensive derived artifacts.
+//
+// `pog view` recomputes the severity-distribution `GraphData` and `pog
+// report` re-renders its template and recompiles LaTeX from scratch on
+// every run, even when nothing in the underlying findings has changed.
+// `Cache` memoizes those artifacts under `$XDG_CACHE_HOME/pog/<client>/`
+// (falling back to `~/.cache/pog/<client>/`), keyed by a hash of their
+// inputs so edits invalidate the right entries automatically. Entries are
+// stored with `bincode`, the same way `embedding::store` persists vectors
+// as SQLite blobs.
+
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, StorageError};
+use models::{GraphData, Severity, SeverityBar};
+
+/// Directory name used under the resolved cache root.
+const CACHE_DIR_NAME: &str = "pog";
+
+/// On-disk cache for a single client's derived artifacts.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+/// `GraphData` with its bars reduced to `(severity label, count)` pairs,
+/// since `ratatui::style::Color` doesn't derive `Serialize`/`Deserialize`
+/// and every bar's color is fully determined by its severity anyway.
+#[derive(Serialize, Deserialize)]
+struct CachedGraphData {
+    title: String,
+    bars: Vec<(String, u64)>,
+}
+
+impl Cache {
+    /// Open (creating if necessary) the cache directory for `client`.
+    pub fn for_client(client: &str) -> Result<Self> {
+        let dir = Self::resolve_cache_root()?.join(client);
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// Directory this cache is reading from/writing to.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Hash an arbitrary set of string parts into a stable cache key.
+    pub fn key(parts: &[&str]) -> String {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        for part in parts {
+            part.hash(&mut hasher);
+        }
+        format!("{:016x}", hasher.finish())
+    }
+
+    /// Hash the mtimes (falling back to the path alone if `stat` fails) of
+    /// a set of source files, so edits to any of them invalidate the cache
+    /// without needing to read file contents.
+    pub fn mtime_key(paths: &[&Path]) -> String {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        for path in paths {
+            path.hash(&mut hasher);
+            let mtime = fs::metadata(path)
+                .and_then(|m| m.modified())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            mtime.hash(&mut hasher);
+        }
+        format!("{:016x}", hasher.finish())
+    }
+
+    fn entry_path(&self, kind: &str, key: &str) -> PathBuf {
+        self.dir.join(format!("{kind}-{key}.bin"))
+    }
+
+    /// Fetch a cached `GraphData`, if present.
+    pub fn get_graph_data(&self, key: &str) -> Option<GraphData> {
+        let bytes = fs::read(self.entry_path("graph", key)).ok()?;
+        let cached: CachedGraphData = bincode::deserialize(&bytes).ok()?;
+        let bars = cached
+            .bars
+            .into_iter()
+            .filter_map(|(label, value)| {
+                label.parse::<Severity>().ok().map(|sev| SeverityBar::from_severity(sev, value))
+            })
+            .collect();
+        Some(GraphData::new(cached.title).with_bars(bars))
+    }
+
+    /// Store a `GraphData` under `key`.
+    pub fn put_graph_data(&self, key: &str, data: &GraphData) -> Result<()> {
+        let cached = CachedGraphData {
+            title: data.title.clone(),
+            bars: data.bars.iter().map(|b| (b.label.clone(), b.value)).collect(),
+        };
+        let bytes = bincode::serialize(&cached)
+            .map_err(|e| StorageError::CacheError(e.to_string()))?;
+        fs::write(self.entry_path("graph", key), bytes)?;
+        Ok(())
+    }
+
+    /// Fetch the cached bytes of a rendered report, if present.
+    pub fn get_report(&self, key: &str) -> Option<Vec<u8>> {
+        fs::read(self.entry_path("report", key)).ok()
+    }
+
+    /// Store the rendered bytes of a report under `key`.
+    pub fn put_report(&self, key: &str, bytes: &[u8]) -> Result<()> {
+        fs::write(self.entry_path("report", key), bytes)?;
+        Ok(())
+    }
+
+    /// Fetch cached parsed content for a single finding, if present.
+    pub fn get_finding_content(&self, key: &str) -> Option<String> {
+        let bytes = fs::read(self.entry_path("finding", key)).ok()?;
+        bincode::deserialize(&bytes).ok()
+    }
+
+    /// Store parsed content for a single finding under `key`.
+    pub fn put_finding_content(&self, key: &str, content: &str) -> Result<()> {
+        let bytes = bincode::serialize(content)
+            .map_err(|e| StorageError::CacheError(e.to_string()))?;
+        fs::write(self.entry_path("finding", key), bytes)?;
+        Ok(())
+    }
+
+    /// Remove every cached entry for this client.
+    pub fn wipe(&self) -> Result<()> {
+        if self.dir.exists() {
+            fs::remove_dir_all(&self.dir)?;
+        }
+        fs::create_dir_all(&self.dir)?;
+        Ok(())
+    }
+
+    /// Resolve the cache root: `$XDG_CACHE_HOME/pog`, falling back to
+    /// `~/.cache/pog`.
+    fn resolve_cache_root() -> Result<PathBuf> {
+        if let Ok(xdg) = std::env::var("XDG_CACHE_HOME") {
+            return Ok(PathBuf::from(xdg).join(CACHE_DIR_NAME));
+        }
+        let home = std::env::var("HOME")
+            .map(PathBuf::from)
+            .map_err(|_| StorageError::NoPogDir)?;
+        Ok(home.join(".cache").join(CACHE_DIR_NAME))
+    }
+}
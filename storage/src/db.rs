@@ -5,6 +5,12 @@ use rusqlite::{params, Connection};
 
 use crate::error::Result;
 
+/// Today's date in `YYYY/MM/DD` format, per [`models::dates`].
+fn today_ymd() -> String {
+    let (y, m, d) = models::dates::today_ymd();
+    models::dates::format_ymd(y, m, d)
+}
+
 /// Thin wrapper around the SQLite connection.
 pub struct Database {
     conn: Connection,
@@ -44,7 +50,9 @@ impl Database {
                 location    TEXT    NOT NULL DEFAULT '',
                 description TEXT    NOT NULL DEFAULT '',
                 status      TEXT    NOT NULL DEFAULT 'Open',
-                slug        TEXT    NOT NULL UNIQUE
+                slug        TEXT    NOT NULL UNIQUE,
+                cvss_vector TEXT,
+                content_hash TEXT
             );
 
             CREATE TABLE IF NOT EXISTS finding_images (
@@ -59,10 +67,67 @@ impl Database {
                 description TEXT    NOT NULL DEFAULT '-',
                 contact     TEXT    NOT NULL DEFAULT '-',
                 criticality TEXT    NOT NULL DEFAULT '-',
-                dns_or_ip   TEXT    NOT NULL DEFAULT '-'
+                dns_or_ip   TEXT    NOT NULL DEFAULT '-',
+                created_at  TEXT    NOT NULL DEFAULT '-',
+                updated_at  TEXT    NOT NULL DEFAULT '-',
+                last_seen   TEXT    NOT NULL DEFAULT '-',
+                parent      TEXT
             );
             "
         )?;
+        self.add_asset_timestamp_columns()?;
+        self.add_asset_parent_column()?;
+        self.add_finding_content_hash_column()?;
+        crate::embedding::migrate(&self.conn)?;
+        crate::search::migrate(&self.conn)?;
+        Ok(())
+    }
+
+    /// Add the `content_hash` column to a `findings` table created before
+    /// content-addressed re-import dedup existed.
+    fn add_finding_content_hash_column(&self) -> Result<()> {
+        let mut stmt = self.conn.prepare("SELECT name FROM pragma_table_info('findings')")?;
+        let existing: Vec<String> = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<std::result::Result<_, _>>()?;
+
+        if !existing.iter().any(|c| c == "content_hash") {
+            self.conn.execute_batch("ALTER TABLE findings ADD COLUMN content_hash TEXT")?;
+        }
+        Ok(())
+    }
+
+    /// Add the `created_at`/`updated_at`/`last_seen` columns to an `assets`
+    /// table created before they existed. SQLite's `ALTER TABLE ... ADD
+    /// COLUMN` has no `IF NOT EXISTS`, so each column is guarded by checking
+    /// `pragma_table_info` first.
+    fn add_asset_timestamp_columns(&self) -> Result<()> {
+        let mut stmt = self.conn.prepare("SELECT name FROM pragma_table_info('assets')")?;
+        let existing: Vec<String> = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<std::result::Result<_, _>>()?;
+
+        for column in ["created_at", "updated_at", "last_seen"] {
+            if !existing.iter().any(|c| c == column) {
+                self.conn.execute_batch(&format!(
+                    "ALTER TABLE assets ADD COLUMN {column} TEXT NOT NULL DEFAULT '-'"
+                ))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Add the `parent` column to an `assets` table created before
+    /// hierarchical assets existed.
+    fn add_asset_parent_column(&self) -> Result<()> {
+        let mut stmt = self.conn.prepare("SELECT name FROM pragma_table_info('assets')")?;
+        let existing: Vec<String> = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<std::result::Result<_, _>>()?;
+
+        if !existing.iter().any(|c| c == "parent") {
+            self.conn.execute_batch("ALTER TABLE assets ADD COLUMN parent TEXT")?;
+        }
         Ok(())
     }
 
@@ -86,9 +151,15 @@ impl Database {
 
     /// Insert a finding. Returns the new row id.
     pub fn insert_finding(&self, finding: &Finding, slug: &str, hex_id: &str) -> Result<i64> {
+        self.insert_finding_with_hash(finding, slug, hex_id, None)
+    }
+
+    /// Insert a finding, optionally stamping its content hash (see
+    /// [`Database::content_hash_for_slug`]). Returns the new row id.
+    pub fn insert_finding_with_hash(&self, finding: &Finding, slug: &str, hex_id: &str, content_hash: Option<&str>) -> Result<i64> {
         self.conn.execute(
-            "INSERT INTO findings (hex_id, title, severity, asset, date, location, description, status, slug)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            "INSERT INTO findings (hex_id, title, severity, asset, date, location, description, status, slug, cvss_vector, content_hash)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
             params![
                 hex_id,
                 finding.title,
@@ -99,6 +170,8 @@ impl Database {
                 finding.description,
                 finding.status.as_str(),
                 slug,
+                finding.cvss_vector,
+                content_hash,
             ],
         )?;
         let id = self.conn.last_insert_rowid();
@@ -110,12 +183,21 @@ impl Database {
             )?;
         }
 
+        crate::search::index(&self.conn, finding, slug)?;
+
         Ok(id)
     }
 
     /// Update an existing finding by its slug, or insert if new.
     /// Returns `(row_id, hex_id, is_new)`.
     pub fn upsert_finding(&self, finding: &Finding, slug: &str) -> Result<(i64, String, bool)> {
+        self.upsert_finding_with_hash(finding, slug, None)
+    }
+
+    /// Like [`Database::upsert_finding`], but also stamps a content hash
+    /// (the caller's hash of the source markdown + referenced image bytes)
+    /// so a later re-import can cheaply tell whether anything changed.
+    pub fn upsert_finding_with_hash(&self, finding: &Finding, slug: &str, content_hash: Option<&str>) -> Result<(i64, String, bool)> {
         let existing: Option<(i64, String)> = self.conn
             .query_row(
                 "SELECT id, hex_id FROM findings WHERE slug = ?1",
@@ -127,7 +209,7 @@ impl Database {
         if let Some((id, hex_id)) = existing {
             self.conn.execute(
                 "UPDATE findings SET title = ?1, severity = ?2, asset = ?3, date = ?4,
-                 location = ?5, description = ?6, status = ?7 WHERE id = ?8",
+                 location = ?5, description = ?6, status = ?7, cvss_vector = ?8, content_hash = ?9 WHERE id = ?10",
                 params![
                     finding.title,
                     finding.severity.as_str(),
@@ -136,6 +218,8 @@ impl Database {
                     finding.location,
                     finding.description,
                     finding.status.as_str(),
+                    finding.cvss_vector,
+                    content_hash,
                     id,
                 ],
             )?;
@@ -147,14 +231,28 @@ impl Database {
                     params![id, img],
                 )?;
             }
+            crate::search::index(&self.conn, finding, slug)?;
             Ok((id, hex_id, false))
         } else {
             let hex_id = self.next_hex_id(&finding.asset)?;
-            let id = self.insert_finding(finding, slug, &hex_id)?;
+            let id = self.insert_finding_with_hash(finding, slug, &hex_id, content_hash)?;
             Ok((id, hex_id, true))
         }
     }
 
+    /// The content hash stamped for `slug` by a previous
+    /// [`Database::upsert_finding_with_hash`] call, if any.
+    pub fn content_hash_for_slug(&self, slug: &str) -> Result<Option<String>> {
+        let hash: Option<Option<String>> = self.conn
+            .query_row(
+                "SELECT content_hash FROM findings WHERE slug = ?1",
+                params![slug],
+                |row| row.get(0),
+            )
+            .ok();
+        Ok(hash.flatten())
+    }
+
     // ------------------------------------------------------------------
     // Read operations
     // ------------------------------------------------------------------
@@ -164,6 +262,26 @@ impl Database {
         self.findings_filtered(None, None, None)
     }
 
+    /// Load a single finding by its slug, if it exists.
+    pub fn finding_by_slug(&self, slug: &str) -> Result<Option<Finding>> {
+        let row: Option<FindingRow> = self.conn
+            .query_row(
+                "SELECT id, hex_id, title, severity, asset, date, location, description, status, slug, cvss_vector \
+                 FROM findings WHERE slug = ?1",
+                params![slug],
+                |row| Ok(FindingRow::from_row(row)),
+            )
+            .ok();
+
+        match row {
+            Some(r) => {
+                let images = self.images_for(r.id)?;
+                Ok(Some(r.into_finding(images)))
+            }
+            None => Ok(None),
+        }
+    }
+
     /// Load findings filtered by optional asset and date range.
     pub fn findings_filtered(
         &self,
@@ -174,7 +292,7 @@ impl Database {
         let (where_clause, param_values) = build_where_clause(asset, from, to);
 
         let sql = format!(
-            "SELECT id, hex_id, title, severity, asset, date, location, description, status, slug \
+            "SELECT id, hex_id, title, severity, asset, date, location, description, status, slug, cvss_vector \
              FROM findings{} ORDER BY asset, hex_id",
             where_clause
         );
@@ -235,39 +353,96 @@ impl Database {
         Ok(hex_id)
     }
 
+    // ------------------------------------------------------------------
+    // Semantic search
+    // ------------------------------------------------------------------
+
+    /// Store (or replace) the embedding vector for a finding, keyed by its
+    /// hex ID.
+    pub fn store_embedding(&self, hex_id: &str, vector: &[f32]) -> Result<()> {
+        crate::embedding::store(&self.conn, hex_id, vector)
+    }
+
+    /// Rank findings by cosine similarity of their stored embedding against
+    /// `query_vec`. Returns up to `top_k` `(hex_id, score)` pairs sorted by
+    /// descending score.
+    pub fn semantic_search(&self, query_vec: &[f32], top_k: usize) -> Result<Vec<(String, f32)>> {
+        let mut scored: Vec<(String, f32)> = crate::embedding::all(&self.conn)?
+            .into_iter()
+            .map(|(hex_id, vector)| (hex_id, crate::embedding::cosine_similarity(query_vec, &vector)))
+            .collect();
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(top_k);
+        Ok(scored)
+    }
+
+    // ------------------------------------------------------------------
+    // Full-text search
+    // ------------------------------------------------------------------
+
+    /// Search findings by free text, ranked by BM25 relevance. Supports
+    /// `asset:<name>` and `severity:<level>` field filters anywhere in the
+    /// query string; see `crate::search` for the matching/ranking details.
+    pub fn search_findings(&self, query: &str) -> Result<Vec<(Finding, f64)>> {
+        crate::search::search(&self.conn, query)
+    }
+
     // ------------------------------------------------------------------
     // Asset operations
     // ------------------------------------------------------------------
 
-    /// Insert or update an asset by name. Returns the row id.
-    pub fn upsert_asset(&self, asset: &Asset) -> Result<i64> {
-        let existing: Option<i64> = self.conn
+    /// Insert or update an asset by name. Returns `(id, created_at,
+    /// updated_at, last_seen)` so callers can stamp the in-memory `Asset`
+    /// with the values that actually landed in the row.
+    ///
+    /// `last_seen` is refreshed on every call, since it marks "touched by
+    /// any import run". `updated_at` only moves when one of the asset's
+    /// metadata fields actually changed, so it can be used to tell a
+    /// still-being-scanned-but-unchanged asset apart from a stale one.
+    pub fn upsert_asset(&self, asset: &Asset) -> Result<(i64, String, String, String)> {
+        let today = today_ymd();
+
+        let existing: Option<(i64, String, String, String, String, String, String, Option<String>)> = self.conn
             .query_row(
-                "SELECT id FROM assets WHERE name = ?1",
+                "SELECT id, created_at, updated_at, description, contact, criticality, dns_or_ip, parent
+                 FROM assets WHERE name = ?1",
                 params![asset.name],
-                |row| row.get(0),
+                |row| Ok((
+                    row.get(0)?, row.get(1)?, row.get(2)?,
+                    row.get(3)?, row.get(4)?, row.get(5)?, row.get(6)?, row.get(7)?,
+                )),
             )
             .ok();
 
-        if let Some(id) = existing {
+        if let Some((id, created_at, prev_updated_at, prev_desc, prev_contact, prev_crit, prev_dns, prev_parent)) = existing {
+            let changed = prev_desc != asset.description
+                || prev_contact != asset.contact
+                || prev_crit != asset.criticality
+                || prev_dns != asset.dns_or_ip
+                || prev_parent != asset.parent;
+            let updated_at = if changed { today.clone() } else { prev_updated_at };
+
             self.conn.execute(
-                "UPDATE assets SET description = ?1, contact = ?2, criticality = ?3, dns_or_ip = ?4 WHERE id = ?5",
-                params![asset.description, asset.contact, asset.criticality, asset.dns_or_ip, id],
+                "UPDATE assets SET description = ?1, contact = ?2, criticality = ?3, dns_or_ip = ?4,
+                 updated_at = ?5, last_seen = ?6, parent = ?7 WHERE id = ?8",
+                params![asset.description, asset.contact, asset.criticality, asset.dns_or_ip, updated_at, today, asset.parent, id],
             )?;
-            Ok(id)
+            Ok((id, created_at, updated_at, today))
         } else {
             self.conn.execute(
-                "INSERT INTO assets (name, description, contact, criticality, dns_or_ip) VALUES (?1, ?2, ?3, ?4, ?5)",
-                params![asset.name, asset.description, asset.contact, asset.criticality, asset.dns_or_ip],
+                "INSERT INTO assets (name, description, contact, criticality, dns_or_ip, created_at, updated_at, last_seen, parent)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![asset.name, asset.description, asset.contact, asset.criticality, asset.dns_or_ip, today, today, today, asset.parent],
             )?;
-            Ok(self.conn.last_insert_rowid())
+            Ok((self.conn.last_insert_rowid(), today.clone(), today.clone(), today))
         }
     }
 
     /// Load all assets from the database.
     pub fn all_assets(&self) -> Result<Vec<Asset>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, name, description, contact, criticality, dns_or_ip FROM assets ORDER BY name"
+            "SELECT id, name, description, contact, criticality, dns_or_ip, created_at, updated_at, last_seen, parent
+             FROM assets ORDER BY name"
         )?;
 
         let rows = stmt.query_map([], |row| {
@@ -278,6 +453,10 @@ impl Database {
                 contact: row.get(3)?,
                 criticality: row.get(4)?,
                 dns_or_ip: row.get(5)?,
+                created_at: row.get(6)?,
+                updated_at: row.get(7)?,
+                last_seen: row.get(8)?,
+                parent: row.get(9)?,
             })
         })?;
 
@@ -288,6 +467,51 @@ impl Database {
         Ok(assets)
     }
 
+    /// Assets that declare `name` as their direct `parent`.
+    pub fn asset_children(&self, name: &str) -> Result<Vec<Asset>> {
+        Ok(self.all_assets()?.into_iter().filter(|a| a.parent.as_deref() == Some(name)).collect())
+    }
+
+    /// Every asset transitively nested under `name` — children, grandchildren,
+    /// and so on — in breadth-first order.
+    ///
+    /// `asset.md`'s `parent:` field is hand-edited, so a typo can easily
+    /// produce a cycle (`a.parent = b`, `b.parent = a`) without any
+    /// adversarial intent; `visited` guards against that turning into an
+    /// infinite loop.
+    pub fn asset_descendants(&self, name: &str) -> Result<Vec<Asset>> {
+        let all = self.all_assets()?;
+        let mut descendants = Vec::new();
+        let mut visited: std::collections::HashSet<String> = std::collections::HashSet::from([name.to_string()]);
+        let mut frontier: std::collections::VecDeque<String> = std::collections::VecDeque::from([name.to_string()]);
+
+        while let Some(current) = frontier.pop_front() {
+            for asset in &all {
+                if asset.parent.as_deref() == Some(current.as_str()) && visited.insert(asset.name.clone()) {
+                    frontier.push_back(asset.name.clone());
+                    descendants.push(asset.clone());
+                }
+            }
+        }
+
+        Ok(descendants)
+    }
+
+    /// Roll up finding counts/severities for `name` together with every
+    /// asset nested beneath it, so a report can summarize a whole scope
+    /// tree (e.g. a domain and all its hosts) under one heading.
+    pub fn asset_rollup_stats(&self, name: &str) -> Result<models::FindingStats> {
+        let mut names = vec![name.to_string()];
+        names.extend(self.asset_descendants(name)?.into_iter().map(|a| a.name));
+
+        let mut findings = Vec::new();
+        for asset_name in names {
+            findings.extend(self.findings_filtered(Some(&asset_name), None, None)?);
+        }
+
+        Ok(models::FindingStats::from_findings(&findings))
+    }
+
     // ------------------------------------------------------------------
     // Destructive operations
     // ------------------------------------------------------------------
@@ -400,6 +624,7 @@ struct FindingRow {
     description: String,
     status: String,
     slug: String,
+    cvss_vector: Option<String>,
 }
 
 impl FindingRow {
@@ -415,6 +640,7 @@ impl FindingRow {
             description: row.get(7).unwrap_or_default(),
             status: row.get(8).unwrap_or_default(),
             slug: row.get(9).unwrap_or_default(),
+            cvss_vector: row.get(10).unwrap_or_default(),
         }
     }
 
@@ -433,6 +659,12 @@ impl FindingRow {
             description: self.description,
             status,
             images,
+            cvss_vector: self.cvss_vector,
+            snippet: None,
+            tags: Vec::new(),
+            references: Vec::new(),
+            cwe: None,
+            extra: std::collections::HashMap::new(),
         }
     }
 }
@@ -530,4 +762,97 @@ mod tests {
         let res = db.insert_finding(&f, "same-slug", "0x002");
         assert!(res.is_err());
     }
+
+    #[test]
+    fn test_search_findings_ranks_free_text_matches() {
+        let db = Database::open_in_memory().unwrap();
+        db.insert_finding(
+            &Finding::new("Log4Shell RCE", Severity::Critical, "orion_gateway", "2026/01/15", "", "Remote code execution via Log4j JNDI lookup.", Status::Open),
+            "log4shell-rce", "0x001",
+        ).unwrap();
+        db.insert_finding(
+            &Finding::new("Weak Password Policy", Severity::Low, "helix_mobile", "2026/01/16", "", "No minimum password length.", Status::Open),
+            "weak-password-policy", "0x001",
+        ).unwrap();
+
+        let results = db.search_findings("log4j").unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.title, "Log4Shell RCE");
+    }
+
+    #[test]
+    fn test_search_findings_filters_by_asset_and_severity() {
+        let db = Database::open_in_memory().unwrap();
+        db.insert_finding(
+            &Finding::new("SQL Injection", Severity::Critical, "nexus_portal", "2026/01/15", "", "Unsanitized query parameter.", Status::Open),
+            "sql-injection", "0x001",
+        ).unwrap();
+        db.insert_finding(
+            &Finding::new("SQL Injection", Severity::Critical, "orion_gateway", "2026/01/15", "", "Unsanitized query parameter.", Status::Open),
+            "sql-injection-2", "0x001",
+        ).unwrap();
+
+        let results = db.search_findings("injection asset:nexus_portal").unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.asset, "nexus_portal");
+
+        let results = db.search_findings("severity:low").unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_search_findings_rejects_fts_operator_injection() {
+        let db = Database::open_in_memory().unwrap();
+        db.insert_finding(
+            &Finding::new("A AND B", Severity::Info, "web_app", "2026/01/15", "", "unrelated description", Status::Open),
+            "a-and-b", "0x001",
+        ).unwrap();
+
+        // A literal term containing an FTS operator shouldn't act as one —
+        // it's quoted as a phrase, not interpreted as `OR NOT`.
+        let results = db.search_findings("OR NOT").unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_asset_hierarchy_children_descendants_and_rollup() {
+        use models::Asset;
+
+        let db = Database::open_in_memory().unwrap();
+        db.upsert_asset(&Asset::new("acme.com")).unwrap();
+        db.upsert_asset(&Asset::new("api.acme.com").with_parent("acme.com")).unwrap();
+        db.upsert_asset(&Asset::new("v1.api.acme.com").with_parent("api.acme.com")).unwrap();
+
+        let children = db.asset_children("acme.com").unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].name, "api.acme.com");
+
+        let mut descendants: Vec<String> = db.asset_descendants("acme.com").unwrap().into_iter().map(|a| a.name).collect();
+        descendants.sort();
+        assert_eq!(descendants, vec!["api.acme.com", "v1.api.acme.com"]);
+
+        db.insert_finding(
+            &Finding::new("SQLi", Severity::Critical, "v1.api.acme.com", "2026/01/15", "", "desc", Status::Open),
+            "sqli", "0x001",
+        ).unwrap();
+
+        let stats = db.asset_rollup_stats("acme.com").unwrap();
+        assert_eq!(stats.by_severity.get(&Severity::Critical), Some(&1));
+    }
+
+    #[test]
+    fn test_asset_descendants_terminates_on_parent_cycle() {
+        use models::Asset;
+
+        let db = Database::open_in_memory().unwrap();
+        db.upsert_asset(&Asset::new("a").with_parent("b")).unwrap();
+        db.upsert_asset(&Asset::new("b").with_parent("a")).unwrap();
+
+        // Must terminate rather than looping forever on the a <-> b cycle,
+        // and must not report an asset as its own descendant.
+        let descendants: Vec<String> = db.asset_descendants("a").unwrap().into_iter().map(|a| a.name).collect();
+        assert_eq!(descendants, vec!["b"]);
+
+        db.asset_rollup_stats("a").unwrap();
+    }
 }
@@ -0,0 +1,82 @@
+//! JSON-backed persistence for a findings report, independent of the
+//! sqlite-backed [`crate::Database`]. Modeled on the typed-document style of
+//! JSON-LD/ActivityStreams: the report and every finding inside it carry a
+//! `type` discriminator and a stable `id`, so two saved reports can be
+//! diffed finding-by-finding instead of only row-by-row.
+
+use std::fs;
+use std::path::Path;
+
+use models::Finding;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, StorageError};
+
+const DOCUMENT_TYPE: &str = "Report";
+const FINDING_TYPE: &str = "Finding";
+
+/// One finding as it appears inside a [`ReportDocument`]: its own fields,
+/// flattened, plus a `type`/`@id` pair so it round-trips as a self-describing
+/// object rather than a bare row. `@id` is the finding's `slug`, which is
+/// already unique and stable across re-imports; the plain `id` key is the
+/// flattened finding's own database row id.
+#[derive(Serialize, Deserialize)]
+struct FindingDoc {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(rename = "@id")]
+    id: String,
+    #[serde(flatten)]
+    finding: Finding,
+}
+
+impl From<&Finding> for FindingDoc {
+    fn from(finding: &Finding) -> Self {
+        FindingDoc { kind: FINDING_TYPE.to_string(), id: finding.slug.clone(), finding: finding.clone() }
+    }
+}
+
+/// A whole findings report: a title (for the severity-distribution graph)
+/// plus every finding, serialized as self-describing typed objects.
+#[derive(Serialize, Deserialize)]
+pub struct ReportDocument {
+    #[serde(rename = "type")]
+    kind: String,
+    id: String,
+    title: String,
+    findings: Vec<FindingDoc>,
+}
+
+impl ReportDocument {
+    fn from_findings(title: &str, findings: &[Finding]) -> Self {
+        ReportDocument {
+            kind: DOCUMENT_TYPE.to_string(),
+            id: slugify(title),
+            title: title.to_string(),
+            findings: findings.iter().map(FindingDoc::from).collect(),
+        }
+    }
+
+    /// Write `findings` (under `title`) to `path` as a pretty-printed JSON
+    /// report.
+    pub fn save(path: &Path, title: &str, findings: &[Finding]) -> Result<()> {
+        let doc = Self::from_findings(title, findings);
+        let json = serde_json::to_string_pretty(&doc).map_err(|e| StorageError::DocumentError(e.to_string()))?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Read a JSON report from `path`, returning its title and findings.
+    pub fn load(path: &Path) -> Result<(String, Vec<Finding>)> {
+        let json = fs::read_to_string(path)?;
+        let doc: ReportDocument = serde_json::from_str(&json).map_err(|e| StorageError::DocumentError(e.to_string()))?;
+        let findings = doc.findings.into_iter().map(|f| f.finding).collect();
+        Ok((doc.title, findings))
+    }
+}
+
+/// Lowercase `title`, replacing anything that isn't alphanumeric with `-`,
+/// so it's stable across saves and safe to use as a document id.
+fn slugify(title: &str) -> String {
+    title.to_lowercase().chars().map(|c| if c.is_alphanumeric() { c } else { '-' }).collect()
+}
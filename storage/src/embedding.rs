@@ -0,0 +1,151 @@
+use rusqlite::{params, Connection};
+
+use crate::error::{Result, StorageError};
+
+/// Dimensionality of vectors produced by [`HashingEmbedder`].
+const HASHING_DIMS: usize = 128;
+
+/// Computes a vector embedding for a piece of text.
+///
+/// Kept behind a trait so the zero-dependency [`HashingEmbedder`] can later
+/// be swapped for a real model-backed implementation without touching
+/// callers in [`crate::import`] or [`Database::semantic_search`](crate::Database::semantic_search).
+pub trait Embedder {
+    fn embed(&self, text: &str) -> Vec<f32>;
+}
+
+/// Deterministic hashing bag-of-words embedder.
+///
+/// Each token is hashed into one of a fixed number of buckets, which is
+/// incremented; the resulting vector is then L2-normalized. This needs no
+/// external service or model weights, so semantic search works offline —
+/// at the cost of missing true synonyms that hash to different buckets.
+pub struct HashingEmbedder {
+    dims: usize,
+}
+
+impl Default for HashingEmbedder {
+    fn default() -> Self {
+        Self { dims: HASHING_DIMS }
+    }
+}
+
+impl Embedder for HashingEmbedder {
+    fn embed(&self, text: &str) -> Vec<f32> {
+        let mut vector = vec![0f32; self.dims];
+        for token in text.split(|c: char| !c.is_alphanumeric()).filter(|t| !t.is_empty()) {
+            let bucket = (hash_token(&token.to_lowercase()) % self.dims as u64) as usize;
+            vector[bucket] += 1.0;
+        }
+        l2_normalize(&mut vector);
+        vector
+    }
+}
+
+fn hash_token(token: &str) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    token.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn l2_normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for v in vector.iter_mut() {
+            *v /= norm;
+        }
+    }
+}
+
+/// Cosine similarity: the dot product over the product of the L2 norms.
+/// Returns `0.0` when either vector is all zeros.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|v| v * v).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Persistence (called from `Database`)
+// ---------------------------------------------------------------------------
+
+/// Create the `finding_embeddings` table if it doesn't already exist.
+pub(crate) fn migrate(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS finding_embeddings (
+            hex_id TEXT PRIMARY KEY,
+            vector BLOB NOT NULL
+        );"
+    )?;
+    Ok(())
+}
+
+/// Insert or replace the embedding vector for a finding, keyed by `hex_id`.
+pub(crate) fn store(conn: &Connection, hex_id: &str, vector: &[f32]) -> Result<()> {
+    let bytes = bincode::serialize(vector)
+        .map_err(|e| StorageError::Embedding(e.to_string()))?;
+    conn.execute(
+        "INSERT INTO finding_embeddings (hex_id, vector) VALUES (?1, ?2)
+         ON CONFLICT(hex_id) DO UPDATE SET vector = excluded.vector",
+        params![hex_id, bytes],
+    )?;
+    Ok(())
+}
+
+/// Load every stored `(hex_id, vector)` pair.
+pub(crate) fn all(conn: &Connection) -> Result<Vec<(String, Vec<f32>)>> {
+    let mut stmt = conn.prepare("SELECT hex_id, vector FROM finding_embeddings")?;
+    let rows = stmt.query_map([], |row| {
+        let hex_id: String = row.get(0)?;
+        let bytes: Vec<u8> = row.get(1)?;
+        Ok((hex_id, bytes))
+    })?;
+
+    let mut out = Vec::new();
+    for row in rows {
+        let (hex_id, bytes) = row?;
+        let vector: Vec<f32> = bincode::deserialize(&bytes)
+            .map_err(|e| StorageError::Embedding(e.to_string()))?;
+        out.push((hex_id, vector));
+    }
+    Ok(out)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hashing_embedder_is_normalized() {
+        let embedder = HashingEmbedder::default();
+        let vector = embedder.embed("weak TLS configuration");
+        let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_cosine_similarity_identical_vectors() {
+        let embedder = HashingEmbedder::default();
+        let vector = embedder.embed("SQL injection in login form");
+        assert!((cosine_similarity(&vector, &vector) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_cosine_similarity_related_over_unrelated() {
+        let embedder = HashingEmbedder::default();
+        let query = embedder.embed("weak TLS configuration");
+        let related = embedder.embed("Outdated TLS cipher suite configuration in use");
+        let unrelated = embedder.embed("Missing HttpOnly flag on session cookie");
+        assert!(cosine_similarity(&query, &related) > cosine_similarity(&query, &unrelated));
+    }
+}
@@ -21,6 +21,18 @@ pub enum StorageError {
     #[error("PDF compilation error: {0}")]
     PdfError(String),
 
+    #[error("embedding (de)serialization error: {0}")]
+    Embedding(String),
+
+    #[error("failed to watch findings directory: {0}")]
+    WatchError(String),
+
+    #[error("no import adapter recognizes this file: {0}")]
+    UnsupportedFormat(String),
+
+    #[error("cache (de)serialization error: {0}")]
+    CacheError(String),
+
     #[error("POGDIR is not set and could not be determined")]
     NoPogDir,
 
@@ -29,6 +41,15 @@ pub enum StorageError {
 
     #[error("client not found: {0}")]
     ClientNotFound(String),
+
+    #[error("invalid RRULE: {0}")]
+    RruleError(String),
+
+    #[error("SARIF export error: {0}")]
+    SarifError(String),
+
+    #[error("report document error: {0}")]
+    DocumentError(String),
 }
 
 /// Convenience alias used throughout the crate.
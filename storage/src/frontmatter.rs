@@ -0,0 +1,74 @@
+//! Front-matter parsing for finding markdown files.
+//!
+//! Supports the two fence styles Zola uses for its section front-matter:
+//! YAML between `---` fences, and TOML between `+++` fences. Deserializing
+//! into a typed [`FrontMatter`] (rather than hand-scanning `key: value`
+//! lines) lets a finding carry lists and nested fields — `tags:`,
+//! `references:`, `cwe:`, and a free-form `extra:` map — in addition to the
+//! flat fields [`crate::import::parse_finding_md`] has always understood.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::error::{Result, StorageError};
+
+/// Typed finding front-matter. Every field is optional so
+/// [`crate::import::parse_finding_md`] can keep its lenient defaulting
+/// behavior for whichever keys are missing.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct FrontMatter {
+    pub title: Option<String>,
+    pub severity: Option<String>,
+    pub asset: Option<String>,
+    pub date: Option<String>,
+    pub location: Option<String>,
+    pub status: Option<String>,
+    pub cvss: Option<String>,
+    pub tags: Vec<String>,
+    pub references: Vec<String>,
+    pub cwe: Option<String>,
+    pub extra: HashMap<String, String>,
+}
+
+/// Split `raw` into its parsed front-matter (if any) and the remaining
+/// report body.
+///
+/// Returns `Ok((None, raw))` when `raw` doesn't open with a `---` or `+++`
+/// fence, or opens with one but never closes it — in both cases the whole
+/// file is treated as report content, same as before front-matter existed.
+/// A fence that *is* present but fails to parse is a hard error carrying
+/// the underlying YAML/TOML parser's line/column, rather than silently
+/// dumping the file into the report body.
+pub fn parse_front_matter(raw: &str) -> Result<(Option<FrontMatter>, &str)> {
+    let trimmed = raw.trim_start();
+    let marker = if trimmed.starts_with("---") {
+        "---"
+    } else if trimmed.starts_with("+++") {
+        "+++"
+    } else {
+        return Ok((None, raw));
+    };
+
+    let after_open = &trimmed[marker.len()..];
+    let after_open = after_open.trim_start_matches(|c: char| c != '\n');
+    let after_open = after_open.strip_prefix('\n').unwrap_or(after_open);
+
+    let Some(close) = after_open.find(&format!("\n{marker}")) else {
+        return Ok((None, raw));
+    };
+
+    let front = &after_open[..close];
+    let body = &after_open[close + 1 + marker.len()..];
+    let body = body.trim_start_matches(|c: char| c != '\n');
+    let body = body.strip_prefix('\n').unwrap_or(body);
+
+    let parsed = if marker == "---" {
+        serde_yaml::from_str(front).map_err(|e| StorageError::ParseError(format!("invalid YAML front-matter: {e}")))?
+    } else {
+        toml::from_str(front).map_err(|e| StorageError::ParseError(format!("invalid TOML front-matter: {e}")))?
+    };
+
+    Ok((Some(parsed), body))
+}
@@ -1,10 +1,47 @@
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use models::{Asset, Finding, Severity, Status};
+use walkdir::WalkDir;
 
+use crate::adapters::ImportFormat;
+use crate::blobs;
+use crate::embedding::{Embedder, HashingEmbedder};
 use crate::error::{Result, StorageError};
 use crate::pogdir::PogDir;
+use crate::sanitize::{sanitize_html, SanitizeConfig};
+
+/// The result of importing a single finding folder.
+pub enum ImportOutcome {
+    /// The finding was new, or its content hash changed since the last
+    /// import; the DB row and on-disk folder were updated.
+    Imported(Finding),
+    /// The finding's content hash matched what's already persisted, so the
+    /// DB upsert and file copy were skipped.
+    Unchanged(Finding),
+}
+
+impl ImportOutcome {
+    /// Borrow the finding regardless of which variant this is.
+    pub fn finding(&self) -> &Finding {
+        match self {
+            ImportOutcome::Imported(f) | ImportOutcome::Unchanged(f) => f,
+        }
+    }
+
+    /// Take the finding regardless of which variant this is.
+    pub fn into_finding(self) -> Finding {
+        match self {
+            ImportOutcome::Imported(f) | ImportOutcome::Unchanged(f) => f,
+        }
+    }
+
+    /// Whether the DB/files were actually touched by this import.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, ImportOutcome::Unchanged(_))
+    }
+}
 
 /// Import a single finding from a folder.
 ///
@@ -16,70 +53,219 @@ use crate::pogdir::PogDir;
 /// The folder name is used as the finding's *slug* (unique identifier).
 ///
 /// Findings are stored under `<POGDIR>/findings/<asset>/<hex_id>_<slug>/`.
-pub fn import_finding(folder: &Path, pog: &PogDir) -> Result<Finding> {
+/// Images are deduplicated through a content-addressed blob store under
+/// `<POGDIR>/blobs/<hash>`, and re-importing an unchanged finding skips the
+/// DB write and file copy entirely — see [`ImportOutcome`].
+pub fn import_finding(folder: &Path, pog: &PogDir) -> Result<ImportOutcome> {
+    let md_path = find_markdown(folder)?;
+    import_finding_at(folder, &md_path, pog, None)
+}
+
+/// Shared implementation behind [`import_finding`] and [`import_recursive`]:
+/// parse `md_path` (already known to live inside `folder`), falling back to
+/// `default_asset` when the finding's own front-matter omits `asset`.
+fn import_finding_at(folder: &Path, md_path: &Path, pog: &PogDir, default_asset: Option<&str>) -> Result<ImportOutcome> {
     let slug = folder
         .file_name()
         .and_then(|n| n.to_str())
         .ok_or_else(|| StorageError::ParseError("cannot derive slug from folder name".into()))?
         .to_string();
 
-    let md_path = find_markdown(folder)?;
-    let raw = fs::read_to_string(&md_path)?;
+    let raw = fs::read_to_string(md_path)?;
     let mut finding = parse_finding_md(&raw, &slug)?;
+    if finding.asset == "unknown"
+        && let Some(asset) = default_asset
+    {
+        finding.asset = normalise_asset(asset);
+    }
 
-    // Collect images -------------------------------------------------------
+    // Collect images, deduping their bytes through the blob store ----------
     let img_dir = folder.join("img");
+    let mut image_hashes = Vec::new();
     if img_dir.is_dir() {
-        for entry in fs::read_dir(&img_dir)? {
-            let entry = entry?;
+        let mut entries: Vec<_> = fs::read_dir(&img_dir)?.filter_map(|e| e.ok()).collect();
+        entries.sort_by_key(|e| e.file_name());
+        for entry in entries {
             let path = entry.path();
             if path.is_file()
                 && let Some(name) = path.file_name().and_then(|n| n.to_str())
             {
+                let hash = blobs::store(&pog.blobs_dir(), &fs::read(&path)?)?;
+                image_hashes.push(hash);
                 finding.images.push(format!("img/{name}"));
             }
         }
-        finding.images.sort();
     }
 
-    // Persist to database (assigns hex_id) ---------------------------------
+    // A finding is "unchanged" when its source markdown and every
+    // referenced image's bytes hash the same as last time.
+    let content_hash = blobs::hash_bytes(format!("{raw}\0{}", image_hashes.join(",")).as_bytes());
+
     let db = pog.open_db()?;
-    let (id, hex_id, _is_new) = db.upsert_finding(&finding, &slug)?;
+    if db.content_hash_for_slug(&slug)?.as_deref() == Some(content_hash.as_str())
+        && let Some(existing) = db.finding_by_slug(&slug)?
+    {
+        return Ok(ImportOutcome::Unchanged(existing));
+    }
+
+    // Persist to database (assigns hex_id) ---------------------------------
+    let (id, hex_id, _is_new) = db.upsert_finding_with_hash(&finding, &slug, Some(&content_hash))?;
     finding.id = Some(id);
     finding.hex_id = hex_id.clone();
 
-    // Copy files into POGDIR -----------------------------------------------
+    // Embed title + description for semantic search --------------------
+    let embedder = HashingEmbedder::default();
+    let vector = embedder.embed(&format!("{} {}", finding.title, finding.description));
+    db.store_embedding(&hex_id, &vector)?;
+
+    // Reconstruct files into POGDIR from the blob store ---------------------
     let dest = pog.finding_dir(&finding.asset, &hex_id, &slug);
     fs::create_dir_all(&dest)?;
-    fs::copy(&md_path, dest.join(md_path.file_name().unwrap()))?;
+    fs::copy(md_path, dest.join(md_path.file_name().unwrap()))?;
 
     if img_dir.is_dir() {
         let dest_img = dest.join("img");
         fs::create_dir_all(&dest_img)?;
-        for entry in fs::read_dir(&img_dir)? {
-            let entry = entry?;
-            let src = entry.path();
-            if src.is_file() {
-                fs::copy(&src, dest_img.join(entry.file_name()))?;
-            }
+        for (image, hash) in finding.images.iter().zip(&image_hashes) {
+            let name = Path::new(image).file_name().unwrap();
+            fs::copy(blobs::blob_path(&pog.blobs_dir(), hash), dest_img.join(name))?;
         }
     }
 
-    Ok(finding)
+    Ok(ImportOutcome::Imported(finding))
 }
 
 /// Bulk-import: treat every sub-directory of `folder` as a finding folder.
 pub fn import_bulk(folder: &Path, pog: &PogDir) -> Result<Vec<Finding>> {
+    import_bulk_format(folder, pog, ImportFormat::Auto)
+}
+
+/// Bulk-import under an explicit [`ImportFormat`].
+///
+/// If `path` is a directory, every sub-directory is imported as a native
+/// finding folder (unchanged from [`import_bulk`]) — a scanner export
+/// doesn't have pog's per-finding folder structure, so `format` only
+/// matters when `path` is a single file, in which case it's handed
+/// straight to [`import_file`].
+pub fn import_bulk_format(path: &Path, pog: &PogDir, format: ImportFormat) -> Result<Vec<Finding>> {
+    if path.is_file() {
+        return import_file(path, pog, format);
+    }
+
     let mut findings = Vec::new();
 
-    let mut entries: Vec<_> = fs::read_dir(folder)?
+    let mut entries: Vec<_> = fs::read_dir(path)?
         .filter_map(|e| e.ok())
         .filter(|e| e.path().is_dir())
         .collect();
     entries.sort_by_key(|e| e.file_name());
 
     for entry in entries {
-        let finding = import_finding(&entry.path(), pog)?;
+        let outcome = import_finding(&entry.path(), pog)?;
+        findings.push(outcome.into_finding());
+    }
+
+    Ok(findings)
+}
+
+/// Recursively import a messy engagement directory, descending arbitrarily
+/// deep rather than only one level like [`import_bulk`].
+///
+/// A directory is recognized as a finding folder by the presence of exactly
+/// one `.md` file (optionally plus `img/`). An `asset.md` encountered along
+/// the way is imported via [`import_asset`] and becomes the default asset
+/// for every finding folder nested beneath it; a finding folder whose own
+/// front-matter omits `asset` falls back to the nearest ancestor's
+/// registered asset, or failing that, to its own enclosing directory name —
+/// much like Zola deriving a section from folder structure.
+pub fn import_recursive(root: &Path, pog: &PogDir) -> Result<Vec<Finding>> {
+    let mut dirs: Vec<PathBuf> = WalkDir::new(root)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_dir())
+        .map(|e| e.into_path())
+        .collect();
+    dirs.sort_by_key(|d| d.components().count());
+
+    let mut registered_asset: HashMap<PathBuf, String> = HashMap::new();
+    let mut findings = Vec::new();
+
+    for dir in dirs {
+        let inherited = dir.parent().and_then(|p| registered_asset.get(p)).cloned();
+
+        let asset_md = dir.join("asset.md");
+        if asset_md.is_file() {
+            let asset = import_asset(&asset_md, pog)?;
+            registered_asset.insert(dir.clone(), asset.name);
+        } else if let Some(asset) = inherited {
+            registered_asset.insert(dir.clone(), asset);
+        }
+
+        if let Some(md_path) = finding_markdown_file(&dir) {
+            let default_asset = registered_asset.get(&dir).cloned().or_else(|| {
+                dir.parent()
+                    .and_then(|p| p.file_name())
+                    .and_then(|n| n.to_str())
+                    .map(str::to_string)
+            });
+            let outcome = import_finding_at(&dir, &md_path, pog, default_asset.as_deref())?;
+            findings.push(outcome.into_finding());
+        }
+    }
+
+    Ok(findings)
+}
+
+/// If `dir` contains exactly one `.md` file and it isn't `asset.md`, treat
+/// `dir` as a finding folder and return that file's path.
+fn finding_markdown_file(dir: &Path) -> Option<PathBuf> {
+    let md_files: Vec<PathBuf> = fs::read_dir(dir)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("md")))
+        .collect();
+
+    match md_files.as_slice() {
+        [only] if only.file_name().and_then(|n| n.to_str()) != Some("asset.md") => Some(only.clone()),
+        _ => None,
+    }
+}
+
+/// Import every finding out of a single scanner-export file (SARIF/CSV/JSON,
+/// or a bare native `.md` file) via the [`crate::adapters`] registry.
+///
+/// Unlike [`import_finding`], there's no enclosing finding folder to copy
+/// images from — each finding's POGDIR folder gets a copy of `path` itself
+/// as its evidence file.
+pub fn import_file(path: &Path, pog: &PogDir, format: ImportFormat) -> Result<Vec<Finding>> {
+    let adapter = crate::adapters::resolve(path, format)?;
+    let parsed = adapter.parse(path)?;
+
+    let db = pog.open_db()?;
+    let embedder = HashingEmbedder::default();
+    let mut findings = Vec::with_capacity(parsed.len());
+
+    for mut finding in parsed {
+        let slug = if finding.slug.is_empty() {
+            finding.title.to_lowercase().replace(' ', "-")
+        } else {
+            finding.slug.clone()
+        };
+
+        let (id, hex_id, _is_new) = db.upsert_finding(&finding, &slug)?;
+        finding.id = Some(id);
+        finding.hex_id = hex_id.clone();
+
+        let vector = embedder.embed(&format!("{} {}", finding.title, finding.description));
+        db.store_embedding(&hex_id, &vector)?;
+
+        let dest = pog.finding_dir(&finding.asset, &hex_id, &slug);
+        fs::create_dir_all(&dest)?;
+        if let Some(name) = path.file_name() {
+            fs::copy(path, dest.join(name))?;
+        }
+
         findings.push(finding);
     }
 
@@ -92,23 +278,31 @@ pub fn import_bulk(folder: &Path, pog: &PogDir) -> Result<Vec<Finding>> {
 
 /// Locate the first `.md` file in a directory.
 fn find_markdown(dir: &Path) -> Result<std::path::PathBuf> {
-    for entry in fs::read_dir(dir)? {
-        let entry = entry?;
+    find_primary_file(dir).ok_or_else(|| StorageError::MissingMarkdown(dir.display().to_string()))
+}
+
+/// Locate the first `.md` file in a directory, e.g. a finding folder's
+/// report content. Returns `None` instead of erroring — used by read-only
+/// callers (like the TUI's preview pane) that want to fall back quietly.
+pub fn find_primary_file(dir: &Path) -> Option<std::path::PathBuf> {
+    let entries = fs::read_dir(dir).ok()?;
+    for entry in entries.filter_map(|e| e.ok()) {
         let path = entry.path();
         if path.is_file()
             && let Some(ext) = path.extension()
             && ext.eq_ignore_ascii_case("md")
         {
-            return Ok(path);
+            return Some(path);
         }
     }
-    Err(StorageError::MissingMarkdown(dir.display().to_string()))
+    None
 }
 
 /// Parse a finding markdown file.
 ///
-/// Expected format: YAML front-matter between `---` fences followed by
-/// the free-form markdown report content.
+/// Expected format: YAML front-matter between `---` fences (or TOML
+/// between `+++` fences, Zola-style) followed by the free-form markdown
+/// report content.
 ///
 /// ```markdown
 /// ---
@@ -118,6 +312,10 @@ fn find_markdown(dir: &Path) -> Result<std::path::PathBuf> {
 /// location: https://example.com/api/users?id=1
 /// date: 2025/10/02
 /// status: Open
+/// tags: [auth, idor]
+/// references:
+///   - https://owasp.org/www-community/attacks/SQL_Injection
+/// cwe: CWE-89
 /// ---
 ///
 /// The `id` parameter is directly concatenated into a raw SQL query …
@@ -125,61 +323,37 @@ fn find_markdown(dir: &Path) -> Result<std::path::PathBuf> {
 ///
 /// Parsing is intentionally lenient: missing fields get sensible defaults.
 /// The asset field is normalised to lowercase with underscores for spaces.
-fn parse_finding_md(raw: &str, slug: &str) -> Result<Finding> {
-    let mut title = slug.to_string();
-    let mut severity = Severity::Info;
-    let mut asset = String::from("unknown");
-    let mut date = String::new();
-    let mut location = String::new();
-    let mut status = Status::Open;
-    let report_content;
-
-    // ── split on front-matter fences ──
-    let trimmed = raw.trim_start();
-    if trimmed.starts_with("---") {
-        // Find the closing `---`
-        let after_open = &trimmed[3..];
-        // Skip the rest of the opening line (e.g. trailing whitespace)
-        let after_open = after_open.trim_start_matches(|c: char| c != '\n');
-        let after_open = after_open.strip_prefix('\n').unwrap_or(after_open);
-
-        if let Some(close) = after_open.find("\n---") {
-            let front = &after_open[..close];
-            let body = &after_open[close + 4..]; // skip "\n---"
-            // Skip the rest of the closing `---` line
-            let body = body.trim_start_matches(|c: char| c != '\n');
-            let body = body.strip_prefix('\n').unwrap_or(body);
-
-            // ── parse front-matter key: value lines ──
-            for line in front.lines() {
-                let line = line.trim();
-                if line.is_empty() || line.starts_with('#') {
-                    continue;
-                }
-                if let Some((key, value)) = line.split_once(':') {
-                    let key = key.trim().to_lowercase();
-                    let value = value.trim().to_string();
-                    match key.as_str() {
-                        "title" => title = value,
-                        "severity" => severity = value.parse().unwrap_or(Severity::Info),
-                        "asset" => asset = normalise_asset(&value),
-                        "date" => date = value,
-                        "location" => location = value,
-                        "status" => status = value.parse().unwrap_or(Status::Open),
-                        _ => {} // ignore unknown keys
-                    }
-                }
-            }
-
-            report_content = body.trim().to_string();
-        } else {
-            // Opening `---` but no closing fence — treat everything as report content
-            report_content = raw.trim().to_string();
-        }
-    } else {
-        // No front-matter at all — whole file is report content
-        report_content = raw.trim().to_string();
-    }
+/// A fenced block that *is* present but fails to deserialize is a hard
+/// error, surfacing the YAML/TOML parser's own line/column.
+pub(crate) fn parse_finding_md(raw: &str, slug: &str) -> Result<Finding> {
+    let (front, body) = crate::frontmatter::parse_front_matter(raw)?;
+    let front = front.unwrap_or_default();
+
+    let title = front.title.unwrap_or_else(|| slug.to_string());
+    let asset = front.asset.as_deref().map(normalise_asset).unwrap_or_else(|| "unknown".to_string());
+    let date = front.date.unwrap_or_default();
+    let location = front.location.unwrap_or_default();
+    let status = front.status.as_deref().and_then(|s| s.parse().ok()).unwrap_or(Status::Open);
+
+    // A `cvss:` vector is validated eagerly, and — when `severity:` is
+    // absent from the front-matter — also used to derive it, rather than
+    // defaulting to `Severity::Info`.
+    let parsed_cvss = front
+        .cvss
+        .as_deref()
+        .map(|v| models::cvss::Cvss::parse(v).map_err(|e| StorageError::ParseError(e.to_string())))
+        .transpose()?;
+    let cvss_vector = front.cvss;
+    let severity = front
+        .severity
+        .as_deref()
+        .and_then(|s| s.parse().ok())
+        .or_else(|| parsed_cvss.map(|c| c.severity()))
+        .unwrap_or(Severity::Info);
+
+    // Pasted vendor-advisory/PoC markup can carry arbitrary HTML; strip it
+    // down to a safe allowlist before it reaches the report pipeline.
+    let description = sanitize_html(body.trim(), &SanitizeConfig::default());
 
     Ok(Finding {
         id: None,
@@ -190,15 +364,21 @@ fn parse_finding_md(raw: &str, slug: &str) -> Result<Finding> {
         asset,
         date,
         location,
-        report_content,
+        description,
         status,
         images: Vec::new(),
+        cvss_vector,
+        snippet: None,
+        tags: front.tags,
+        references: front.references,
+        cwe: front.cwe,
+        extra: front.extra,
     })
 }
 
 /// Normalise an asset name: lowercase, spaces → underscores, collapse
 /// consecutive underscores, strip leading/trailing underscores.
-fn normalise_asset(raw: &str) -> String {
+pub(crate) fn normalise_asset(raw: &str) -> String {
     let s: String = raw
         .trim()
         .to_lowercase()
@@ -266,9 +446,9 @@ pub fn import_asset(file: &Path, pog: &PogDir) -> Result<Asset> {
     let raw = fs::read_to_string(file)?;
     let asset = parse_asset_md(&raw)?;
     let db = pog.open_db()?;
-    let id = db.upsert_asset(&asset)?;
+    let (id, created_at, updated_at, last_seen) = db.upsert_asset(&asset)?;
     write_asset_md(&asset, pog)?;
-    Ok(Asset { id: Some(id), ..asset })
+    Ok(Asset { id: Some(id), created_at, updated_at, last_seen, ..asset })
 }
 
 /// Bulk-import assets: the file contains multiple assets separated by `---`.
@@ -288,9 +468,9 @@ pub fn import_assets_bulk(file: &Path, pog: &PogDir) -> Result<Vec<Asset>> {
             continue;
         }
         let asset = parse_asset_md(trimmed)?;
-        let id = db.upsert_asset(&asset)?;
+        let (id, created_at, updated_at, last_seen) = db.upsert_asset(&asset)?;
         write_asset_md(&asset, pog)?;
-        assets.push(Asset { id: Some(id), ..asset });
+        assets.push(Asset { id: Some(id), created_at, updated_at, last_seen, ..asset });
     }
 
     Ok(assets)
@@ -303,6 +483,7 @@ fn parse_asset_md(raw: &str) -> Result<Asset> {
     let mut contact = String::from("-");
     let mut criticality = String::from("-");
     let mut dns_or_ip = String::from("-");
+    let mut parent = None;
 
     for line in raw.lines() {
         let trimmed = line.trim();
@@ -322,6 +503,8 @@ fn parse_asset_md(raw: &str) -> Result<Asset> {
             if !value.is_empty() { criticality = value; }
         } else if let Some(value) = extract_field(trimmed, "dns/ip") {
             if !value.is_empty() { dns_or_ip = value; }
+        } else if let Some(value) = extract_field(trimmed, "parent") {
+            if !value.is_empty() { parent = Some(normalise_asset(&value)); }
         }
     }
 
@@ -336,6 +519,12 @@ fn parse_asset_md(raw: &str) -> Result<Asset> {
         contact,
         criticality,
         dns_or_ip,
+        // Stamped by `Database::upsert_asset` on persist; a freshly parsed
+        // asset hasn't been persisted yet.
+        created_at: "-".into(),
+        updated_at: "-".into(),
+        last_seen: "-".into(),
+        parent,
     })
 }
 
@@ -351,10 +540,14 @@ fn write_asset_md(asset: &Asset, pog: &PogDir) -> Result<()> {
 
 /// Render an asset to its canonical Markdown representation.
 fn render_asset_md(asset: &Asset) -> String {
-    format!(
+    let mut md = format!(
         "# {}\n\n- **Description:** {}\n- **Contact:** {}\n- **Criticality:** {}\n- **DNS/IP:** {}\n",
         asset.name, asset.description, asset.contact, asset.criticality, asset.dns_or_ip,
-    )
+    );
+    if let Some(parent) = &asset.parent {
+        md.push_str(&format!("- **Parent:** {parent}\n"));
+    }
+    md
 }
 
 // ---------------------------------------------------------------------------
@@ -400,7 +593,7 @@ This allows an attacker to execute arbitrary SQL commands.
         assert_eq!(f.date, "2026/01/15");
         assert_eq!(f.location, "https://example.com/api/users?id=1");
         assert_eq!(f.status, Status::Open);
-        assert!(f.report_content.contains("User input is directly concatenated"));
+        assert!(f.description.contains("User input is directly concatenated"));
     }
 
     #[test]
@@ -412,7 +605,7 @@ This allows an attacker to execute arbitrary SQL commands.
         assert_eq!(f.asset, "unknown");         // default
         assert_eq!(f.date, "");                 // default
         assert_eq!(f.status, Status::Open);     // default
-        assert!(f.report_content.contains("Stack smash"));
+        assert!(f.description.contains("Stack smash"));
     }
 
     #[test]
@@ -420,7 +613,7 @@ This allows an attacker to execute arbitrary SQL commands.
         let md = "Just a raw description.\n";
         let f = parse_finding_md(md, "raw-finding").unwrap();
         assert_eq!(f.title, "raw-finding"); // slug used as fallback
-        assert!(f.report_content.contains("Just a raw description"));
+        assert!(f.description.contains("Just a raw description"));
     }
 
     #[test]
@@ -432,6 +625,73 @@ This allows an attacker to execute arbitrary SQL commands.
         assert_eq!(normalise_asset(""), "unknown");
     }
 
+    #[test]
+    fn test_parse_structured_frontmatter_fields() {
+        let md = "\
+---
+title: SQL Injection
+severity: Critical
+asset: Web App
+tags: [auth, idor]
+references:
+  - https://owasp.org/www-community/attacks/SQL_Injection
+cwe: CWE-89
+---
+
+Body.
+";
+        let f = parse_finding_md(md, "sql-injection").unwrap();
+        assert_eq!(f.tags, vec!["auth", "idor"]);
+        assert_eq!(f.references, vec!["https://owasp.org/www-community/attacks/SQL_Injection"]);
+        assert_eq!(f.cwe.as_deref(), Some("CWE-89"));
+    }
+
+    #[test]
+    fn test_parse_toml_frontmatter() {
+        let md = "\
++++
+title = \"SQL Injection\"
+severity = \"Critical\"
+asset = \"Web App\"
++++
+
+Body.
+";
+        let f = parse_finding_md(md, "sql-injection").unwrap();
+        assert_eq!(f.title, "SQL Injection");
+        assert_eq!(f.asset, "web_app");
+        assert!(f.description.contains("Body"));
+    }
+
+    #[test]
+    fn test_malformed_frontmatter_errors() {
+        let md = "---\ntitle: [unterminated\n---\n\nBody.\n";
+        let err = parse_finding_md(md, "bad").unwrap_err();
+        assert!(err.to_string().contains("invalid YAML front-matter"));
+    }
+
+    #[test]
+    fn test_severity_derived_from_cvss_when_absent() {
+        let md = "---\ntitle: SQLi\nasset: web_app\ncvss: CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H\n---\n\nBody.\n";
+        let f = parse_finding_md(md, "sqli").unwrap();
+        assert_eq!(f.severity, Severity::Critical);
+        assert_eq!(f.cvss_vector.as_deref(), Some("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"));
+    }
+
+    #[test]
+    fn test_explicit_severity_overrides_cvss() {
+        let md = "---\ntitle: SQLi\nasset: web_app\nseverity: Low\ncvss: CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H\n---\n\nBody.\n";
+        let f = parse_finding_md(md, "sqli").unwrap();
+        assert_eq!(f.severity, Severity::Low);
+    }
+
+    #[test]
+    fn test_malformed_cvss_vector_errors() {
+        let md = "---\ntitle: SQLi\nasset: web_app\ncvss: not-a-vector\n---\n\nBody.\n";
+        let err = parse_finding_md(md, "sqli").unwrap_err();
+        assert!(err.to_string().contains("invalid CVSS vector"));
+    }
+
     #[test]
     fn test_import_single_finding() {
         let tmp = TempDir::new().unwrap();
@@ -439,7 +699,9 @@ This allows an attacker to execute arbitrary SQL commands.
         let pog = PogDir::init_at(pog_dir.path()).unwrap();
 
         let folder = create_finding_folder(&tmp, "sql-injection", sample_md());
-        let f = import_finding(&folder, &pog).unwrap();
+        let outcome = import_finding(&folder, &pog).unwrap();
+        assert!(!outcome.is_unchanged());
+        let f = outcome.into_finding();
 
         assert_eq!(f.title, "SQL Injection");
         assert_eq!(f.asset, "web_app");
@@ -484,6 +746,54 @@ This allows an attacker to execute arbitrary SQL commands.
         assert!(pog.finding_dir("web_app", "0x002", "finding-b").exists());
     }
 
+    #[test]
+    fn test_import_recursive_derives_asset_from_directory() {
+        let tmp = TempDir::new().unwrap();
+        let pog_dir = TempDir::new().unwrap();
+        let pog = PogDir::init_at(pog_dir.path()).unwrap();
+
+        // acme/web/api/sqli/finding.md — no `asset:` in front-matter, so it
+        // should fall back to the enclosing "api" directory.
+        let nested = tmp.path().join("acme/web/api/sqli");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(
+            nested.join("finding.md"),
+            "---\ntitle: SQL Injection\nseverity: Critical\n---\n\nBody.\n",
+        ).unwrap();
+
+        let findings = import_recursive(tmp.path(), &pog).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].title, "SQL Injection");
+        assert_eq!(findings[0].asset, "api");
+    }
+
+    #[test]
+    fn test_import_recursive_honors_asset_md() {
+        let tmp = TempDir::new().unwrap();
+        let pog_dir = TempDir::new().unwrap();
+        let pog = PogDir::init_at(pog_dir.path()).unwrap();
+
+        let web_dir = tmp.path().join("acme/web");
+        fs::create_dir_all(&web_dir).unwrap();
+        fs::write(web_dir.join("asset.md"), "# Web App\n\n- **Criticality:** High\n").unwrap();
+
+        let finding_dir = web_dir.join("api/sqli");
+        fs::create_dir_all(&finding_dir).unwrap();
+        fs::write(
+            finding_dir.join("finding.md"),
+            "---\ntitle: SQL Injection\nseverity: Critical\n---\n\nBody.\n",
+        ).unwrap();
+
+        let findings = import_recursive(tmp.path(), &pog).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].asset, "web_app");
+
+        let db = pog.open_db().unwrap();
+        let assets = db.all_assets().unwrap();
+        assert_eq!(assets.len(), 1);
+        assert_eq!(assets[0].name, "web_app");
+    }
+
     #[test]
     fn test_reimport_upserts() {
         let tmp = TempDir::new().unwrap();
@@ -497,7 +807,9 @@ This allows an attacker to execute arbitrary SQL commands.
         fs::write(folder.join("finding.md"),
             "---\ntitle: SQL Injection v2\nseverity: Critical\nasset: Web App\ndate: 2026/01/20\nstatus: Resolved\n---\n\nFixed.\n"
         ).unwrap();
-        let f = import_finding(&folder, &pog).unwrap();
+        let outcome = import_finding(&folder, &pog).unwrap();
+        assert!(!outcome.is_unchanged());
+        let f = outcome.into_finding();
         assert_eq!(f.title, "SQL Injection v2");
         assert_eq!(f.status, Status::Resolved);
 
@@ -507,6 +819,49 @@ This allows an attacker to execute arbitrary SQL commands.
         assert_eq!(all[0].title, "SQL Injection v2");
     }
 
+    #[test]
+    fn test_reimport_unchanged_is_skipped() {
+        let tmp = TempDir::new().unwrap();
+        let pog_dir = TempDir::new().unwrap();
+        let pog = PogDir::init_at(pog_dir.path()).unwrap();
+
+        let folder = create_finding_folder(&tmp, "sqli", sample_md());
+        let first = import_finding(&folder, &pog).unwrap();
+        assert!(!first.is_unchanged());
+
+        // Re-importing with byte-identical markdown and images should be a
+        // no-op: same hex_id, and the DB/file copy steps are skipped.
+        let second = import_finding(&folder, &pog).unwrap();
+        assert!(second.is_unchanged());
+        assert_eq!(second.finding().hex_id, first.finding().hex_id);
+
+        let db = pog.open_db().unwrap();
+        assert_eq!(db.all_findings().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_duplicate_images_dedup_into_one_blob() {
+        let tmp = TempDir::new().unwrap();
+        let pog_dir = TempDir::new().unwrap();
+        let pog = PogDir::init_at(pog_dir.path()).unwrap();
+
+        let md = "---\ntitle: Finding A\nseverity: High\nasset: web_app\ndate: 2026/01/15\n---\n\nDesc A\n";
+        let folder_a = create_finding_folder(&tmp, "finding-a", md);
+        fs::write(folder_a.join("img/proof.png"), b"same-bytes").unwrap();
+
+        let folder_b = tmp.path().join("finding-b");
+        fs::create_dir_all(folder_b.join("img")).unwrap();
+        fs::write(folder_b.join("finding.md"),
+            "---\ntitle: Finding B\nseverity: Low\nasset: web_app\ndate: 2026/01/16\n---\n\nDesc B\n").unwrap();
+        fs::write(folder_b.join("img/other.png"), b"same-bytes").unwrap();
+
+        import_finding(&folder_a, &pog).unwrap();
+        import_finding(&folder_b, &pog).unwrap();
+
+        let blobs: Vec<_> = fs::read_dir(pog.blobs_dir()).unwrap().filter_map(|e| e.ok()).collect();
+        assert_eq!(blobs.len(), 1);
+    }
+
     #[test]
     fn test_missing_md_errors() {
         let tmp = TempDir::new().unwrap();
@@ -534,4 +889,15 @@ This allows an attacker to execute arbitrary SQL commands.
         );
         assert_eq!(extract_field("random line", "severity"), None);
     }
+
+    #[test]
+    fn test_asset_parent_round_trips_through_markdown() {
+        let md = "# API\n\n- **Description:** Public API\n- **Parent:** Acme.com\n";
+        let asset = parse_asset_md(md).unwrap();
+        assert_eq!(asset.parent.as_deref(), Some("acme_com"));
+
+        let rendered = render_asset_md(&asset);
+        let reparsed = parse_asset_md(&rendered).unwrap();
+        assert_eq!(reparsed.parent, asset.parent);
+    }
 }
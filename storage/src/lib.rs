@@ -1,11 +1,37 @@
+mod adapters;
+mod blobs;
+mod cache;
+mod document;
 mod error;
 mod db;
+mod embedding;
+mod frontmatter;
 mod pogdir;
 mod import;
+mod preprocessor;
 mod report;
+mod rrule;
+mod sanitize;
+mod sarif;
+mod scan_import;
+mod search;
+mod watch;
 
+pub use adapters::{ImportAdapter, ImportFormat};
+pub use cache::Cache;
 pub use error::StorageError;
 pub use db::Database;
+pub use document::ReportDocument;
+pub use embedding::{cosine_similarity, Embedder, HashingEmbedder};
 pub use pogdir::PogDir;
-pub use import::{import_finding, import_bulk, import_asset, import_assets_bulk};
+pub use import::{
+    import_finding, import_bulk, import_bulk_format, import_file, import_recursive,
+    import_asset, import_assets_bulk, find_primary_file, ImportOutcome,
+};
+pub use preprocessor::{CmdPreprocessor, Preprocessor, ReportContext, HTML_BACKEND, LATEX_BACKEND};
 pub use report::generate_report;
+pub use rrule::Rrule;
+pub use sanitize::{sanitize_html, SanitizeConfig};
+pub use sarif::findings_to_sarif;
+pub use scan_import::{import_scan, ScanFormat};
+pub use watch::{watch_dir, ChangeEvent};
@@ -2,6 +2,7 @@ use std::path::{Path, PathBuf};
 use std::fs;
 
 use crate::error::{Result, StorageError};
+use crate::cache::Cache;
 use crate::db::Database;
 
 /// Default directory name inside the user's home.
@@ -24,6 +25,7 @@ const DEFAULT_CLIENT_FILE: &str = "default_client";
 /// │       └── findings/
 /// └── default_client          ← plain-text file with the active client name
 /// ```
+#[derive(Clone)]
 pub struct PogDir {
     root: PathBuf,
 }
@@ -162,6 +164,11 @@ impl PogDir {
         self.asset_dir(asset).join(format!("{hex_id}_{slug}"))
     }
 
+    /// Directory where the content-addressed image blob store lives.
+    pub fn blobs_dir(&self) -> PathBuf {
+        self.root.join("blobs")
+    }
+
     /// Wipe the entire client directory and recreate the empty structure.
     pub fn clean(&self) -> Result<()> {
         if self.root.exists() {
@@ -171,6 +178,21 @@ impl PogDir {
         Ok(())
     }
 
+    /// Open the on-disk artifact cache for this POGDIR's client.
+    ///
+    /// The client name is taken from the root directory's own name (e.g.
+    /// `clients/acme-corp/` → `acme-corp`), so each client gets an
+    /// independent cache directory, mirroring the `pog.db`/`findings/`
+    /// split already done per-client under `POGDIR`.
+    pub fn cache(&self) -> Result<Cache> {
+        let client = self
+            .root
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("default");
+        Cache::for_client(client)
+    }
+
     // ------------------------------------------------------------------
     // Private helpers
     // ------------------------------------------------------------------
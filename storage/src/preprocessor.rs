@@ -0,0 +1,256 @@
+//! Pluggable report preprocessors, modeled on mdBook's preprocessor
+//! protocol.
+//!
+//! `crate::report::render_report` parses a template into a `Vec<Block>`
+//! and, before converting it to LaTeX, hands it to each configured
+//! [`Preprocessor`] in order — letting callers auto-insert remediation
+//! tables, redact secrets, or expand custom shortcodes without forking
+//! the crate. [`CmdPreprocessor`] implements this over an external
+//! command using mdBook's own wire protocol: the block stream is written
+//! to the child's stdin as JSON and the (possibly modified) stream is
+//! read back from its stdout, and a `<cmd> supports <backend>` handshake
+//! lets the command opt out for a given output format by exit code.
+//! [`Preprocessor`] itself is the in-process extension point — a native
+//! Rust type can implement it directly and skip the subprocess entirely.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use serde::Serialize;
+
+use crate::error::{Result, StorageError};
+use crate::report::Block;
+
+/// `ReportContext::backend` value for the LaTeX/PDF output path.
+pub const LATEX_BACKEND: &str = "latex";
+
+/// `ReportContext::backend` value for the standalone-HTML output path.
+pub const HTML_BACKEND: &str = "html";
+
+/// Read-only metadata handed to every [`Preprocessor`] alongside the
+/// block stream.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportContext {
+    pub asset: String,
+    pub from: String,
+    pub to: String,
+    /// Output format being rendered to, e.g. [`LATEX_BACKEND`].
+    pub backend: String,
+}
+
+/// A transform applied to the parsed block stream before
+/// `crate::report::blocks_to_latex` renders it.
+pub trait Preprocessor {
+    /// Short name used in error messages.
+    fn name(&self) -> &str;
+
+    /// Whether this preprocessor applies to `ctx.backend`. Defaults to
+    /// "always" for in-process preprocessors; [`CmdPreprocessor`]
+    /// overrides this to delegate to the command's own handshake.
+    fn supports(&self, ctx: &ReportContext) -> bool {
+        let _ = ctx;
+        true
+    }
+
+    /// Transform the block stream, returning the (possibly modified)
+    /// replacement.
+    fn run(&self, ctx: &ReportContext, blocks: Vec<Block>) -> Result<Vec<Block>>;
+}
+
+/// Wire payload written to a [`CmdPreprocessor`] child's stdin.
+#[derive(Serialize)]
+struct CmdInput<'a> {
+    context: &'a ReportContext,
+    blocks: &'a [Block],
+}
+
+/// A [`Preprocessor`] backed by an external command, speaking mdBook's
+/// preprocessor protocol.
+///
+/// `command` is a full shell-style command line (e.g. `"python3
+/// redact.py"`); it's split on whitespace into a program and its leading
+/// arguments, and the handshake/transform invocations append arguments of
+/// their own (`supports <backend>` for the handshake, none for the transform).
+pub struct CmdPreprocessor {
+    name: String,
+    program: String,
+    args: Vec<String>,
+}
+
+impl CmdPreprocessor {
+    pub fn new(name: impl Into<String>, command: impl AsRef<str>) -> Self {
+        let mut parts = command.as_ref().split_whitespace();
+        let program = parts.next().unwrap_or_default().to_string();
+        let args: Vec<String> = parts.map(str::to_string).collect();
+        Self { name: name.into(), program, args }
+    }
+}
+
+impl Preprocessor for CmdPreprocessor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn supports(&self, ctx: &ReportContext) -> bool {
+        // A command that fails to spawn is assumed to support every
+        // backend; one that runs and exits non-zero for `supports
+        // <backend>` (including for an unrecognized sub-command) opts out
+        // of that backend.
+        Command::new(&self.program)
+            .args(&self.args)
+            .arg("supports")
+            .arg(&ctx.backend)
+            .status()
+            .map(|status| status.success())
+            .unwrap_or(true)
+    }
+
+    fn run(&self, ctx: &ReportContext, blocks: Vec<Block>) -> Result<Vec<Block>> {
+        let payload = CmdInput { context: ctx, blocks: &blocks };
+        let json = serde_json::to_vec(&payload).map_err(|e| {
+            StorageError::TemplateError(format!("preprocessor `{}`: {e}", self.name))
+        })?;
+
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+
+        child
+            .stdin
+            .take()
+            .expect("child spawned with piped stdin")
+            .write_all(&json)?;
+        let output = child.wait_with_output()?;
+
+        if !output.status.success() {
+            return Err(StorageError::TemplateError(format!(
+                "preprocessor `{}` exited with {}",
+                self.name, output.status,
+            )));
+        }
+
+        serde_json::from_slice(&output.stdout).map_err(|e| {
+            StorageError::TemplateError(format!(
+                "preprocessor `{}` printed an invalid block stream: {e}",
+                self.name
+            ))
+        })
+    }
+}
+
+/// Run `preprocessors` over `blocks` in order, skipping any that opt out
+/// via [`Preprocessor::supports`] for `ctx.backend`.
+pub(crate) fn run_preprocessors(
+    preprocessors: &[Box<dyn Preprocessor>],
+    ctx: &ReportContext,
+    mut blocks: Vec<Block>,
+) -> Result<Vec<Block>> {
+    for pp in preprocessors {
+        if !pp.supports(ctx) {
+            continue;
+        }
+        blocks = pp.run(ctx, blocks)?;
+    }
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx() -> ReportContext {
+        ReportContext {
+            asset: "web.corp".into(),
+            from: "2026/01/01".into(),
+            to: "2026/01/31".into(),
+            backend: LATEX_BACKEND.into(),
+        }
+    }
+
+    struct AppendPreprocessor(String);
+
+    impl Preprocessor for AppendPreprocessor {
+        fn name(&self) -> &str {
+            &self.0
+        }
+
+        fn run(&self, _ctx: &ReportContext, mut blocks: Vec<Block>) -> Result<Vec<Block>> {
+            blocks.push(Block::Text(self.0.clone()));
+            Ok(blocks)
+        }
+    }
+
+    struct OptOutPreprocessor;
+
+    impl Preprocessor for OptOutPreprocessor {
+        fn name(&self) -> &str {
+            "opt-out"
+        }
+
+        fn supports(&self, _ctx: &ReportContext) -> bool {
+            false
+        }
+
+        fn run(&self, _ctx: &ReportContext, _blocks: Vec<Block>) -> Result<Vec<Block>> {
+            panic!("should never run once supports() returns false");
+        }
+    }
+
+    #[test]
+    fn cmd_preprocessor_splits_program_and_args() {
+        let pp = CmdPreprocessor::new("redact", "python3 redact.py --strict");
+        assert_eq!(pp.name(), "redact");
+        assert_eq!(pp.program, "python3");
+        assert_eq!(pp.args, vec!["redact.py".to_string(), "--strict".to_string()]);
+    }
+
+    #[test]
+    fn preprocessor_default_supports_is_always_true() {
+        let pp = AppendPreprocessor("noop".into());
+        assert!(pp.supports(&ctx()));
+    }
+
+    #[test]
+    fn run_preprocessors_applies_in_order() {
+        let preprocessors: Vec<Box<dyn Preprocessor>> = vec![
+            Box::new(AppendPreprocessor("first".into())),
+            Box::new(AppendPreprocessor("second".into())),
+        ];
+        let blocks = run_preprocessors(&preprocessors, &ctx(), vec![Block::Title("Report".into())]).unwrap();
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Title("Report".into()),
+                Block::Text("first".into()),
+                Block::Text("second".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn run_preprocessors_skips_ones_that_opt_out() {
+        let preprocessors: Vec<Box<dyn Preprocessor>> = vec![Box::new(OptOutPreprocessor)];
+        let blocks = run_preprocessors(&preprocessors, &ctx(), vec![Block::Title("Report".into())]).unwrap();
+        assert_eq!(blocks, vec![Block::Title("Report".into())]);
+    }
+
+    #[test]
+    fn run_preprocessors_empty_list_is_a_no_op() {
+        let blocks = run_preprocessors(&[], &ctx(), vec![Block::Title("Report".into())]).unwrap();
+        assert_eq!(blocks, vec![Block::Title("Report".into())]);
+    }
+
+    #[test]
+    fn cmd_input_serializes_context_and_blocks() {
+        let blocks = vec![Block::Title("Report".into()), Block::PageBreak];
+        let payload = CmdInput { context: &ctx(), blocks: &blocks };
+        let json = serde_json::to_string(&payload).unwrap();
+        assert!(json.contains("\"asset\":\"web.corp\""));
+        assert!(json.contains("\"backend\":\"latex\""));
+        assert!(json.contains("Title"));
+        assert!(json.contains("PageBreak"));
+    }
+}
@@ -5,18 +5,44 @@
 // LaTeX document, and compiles it to PDF using the tectonic crate (an
 // embedded TeX engine — no external dependencies required).
 
+use crate::cache::Cache;
 use crate::error::{Result, StorageError};
 use crate::pogdir::PogDir;
+use crate::preprocessor::{Preprocessor, ReportContext};
 use models::{Finding, Severity};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
 // ───────────────────────── public API ─────────────────────────
 
-/// Generate a PDF report from a `.tmpl` template.
+/// Generate a report from a `.tmpl` template.
+///
+/// The output format is chosen from `output_path`'s extension: `.html`
+/// renders a single self-contained HTML file (inlined CSS and
+/// base64-embedded images, via [`blocks_to_html`]); anything else renders
+/// a PDF through tectonic, as before.
 ///
 /// `findings` are numbered sequentially starting at 1 and exposed to the
 /// template together with aggregate counters and the supplied metadata.
+///
+/// When `cache` is `Some`, the rendered output is memoized under a key
+/// derived from the findings, template, and date range: a repeat `pog
+/// report` run with nothing changed is served straight from disk without
+/// re-rendering the template or recompiling LaTeX. Pass `None` (wired to
+/// `--no-cache`) to always regenerate.
+///
+/// `preprocessors` run in order on the parsed block stream between
+/// parsing the template and converting it to the output format — see
+/// [`crate::preprocessor`]. Pass `&[]` for none.
+///
+/// `legacy_latex_escape` switches the PDF path back to the old
+/// `inputenc`/`fontenc` preamble and its fixed Unicode-to-LaTeX
+/// transliteration table, for users still compiling with pdfLaTeX instead
+/// of tectonic's native XeTeX engine. The default (`false`) loads
+/// `fontspec` and lets non-ASCII text — CJK, Cyrillic, Greek, accented
+/// hostnames — render natively. Has no effect on HTML output.
 pub fn generate_report(
     findings: &[Finding],
     template_path: &str,
@@ -25,6 +51,75 @@ pub fn generate_report(
     from: &str,
     to: &str,
     pog: &PogDir,
+    cache: Option<&Cache>,
+    preprocessors: &[Box<dyn Preprocessor>],
+    legacy_latex_escape: bool,
+) -> Result<()> {
+    let cache_key = cache.map(|_| {
+        // The output format and escape mode change the rendered bytes too.
+        let ext = Path::new(output_path).extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase();
+        let key = report_cache_key(findings, template_path, asset, from, to, preprocessors);
+        format!("{key}-{ext}-{legacy_latex_escape}")
+    });
+
+    if let (Some(cache), Some(key)) = (cache, cache_key.as_deref()) {
+        if let Some(bytes) = cache.get_report(key) {
+            fs::write(output_path, bytes)?;
+            return Ok(());
+        }
+    }
+
+    render_report(
+        findings, template_path, output_path, asset, from, to, pog, preprocessors,
+        legacy_latex_escape,
+    )?;
+
+    if let (Some(cache), Some(key)) = (cache, cache_key.as_deref()) {
+        let bytes = fs::read(output_path)?;
+        cache.put_report(key, &bytes)?;
+    }
+
+    Ok(())
+}
+
+/// Cache key for a rendered report: a hash of the asset and date range, a
+/// hash of each finding's full serialized content (so any edit to a finding
+/// busts the key), the template's mtime, plus a hash of the configured
+/// preprocessors' names (so enabling/disabling or swapping one busts a
+/// previously cached render). Every part is hex, so the key is safe to use
+/// in a file name.
+fn report_cache_key(
+    findings: &[Finding],
+    template_path: &str,
+    asset: &str,
+    from: &str,
+    to: &str,
+    preprocessors: &[Box<dyn Preprocessor>],
+) -> String {
+    let mut parts: Vec<String> = findings
+        .iter()
+        .map(|f| serde_json::to_string(f).unwrap_or_default())
+        .collect();
+    parts.sort();
+    let findings_digest = Cache::key(&parts.iter().map(String::as_str).collect::<Vec<_>>());
+    let template_digest = Cache::mtime_key(&[Path::new(template_path)]);
+    let preprocessor_names: Vec<&str> = preprocessors.iter().map(|pp| pp.name()).collect();
+    let preprocessor_digest = Cache::key(&preprocessor_names);
+    let scope_digest = Cache::key(&[asset, from, to]);
+    format!("{scope_digest}-{findings_digest}-{template_digest}-{preprocessor_digest}")
+}
+
+/// Render the template and compile it to PDF, bypassing the cache.
+fn render_report(
+    findings: &[Finding],
+    template_path: &str,
+    output_path: &str,
+    asset: &str,
+    from: &str,
+    to: &str,
+    pog: &PogDir,
+    preprocessors: &[Box<dyn Preprocessor>],
+    legacy_latex_escape: bool,
 ) -> Result<()> {
     let raw = fs::read_to_string(template_path)?;
 
@@ -41,8 +136,8 @@ pub fn generate_report(
 
     // Register a `latex` filter so templates can safely embed variables
     // inside `#! latex` blocks:  {{ asset|latex }}
-    env.add_filter("latex", |value: String| -> String {
-        latex_escape(&value)
+    env.add_filter("latex", move |value: String| -> String {
+        latex_escape(&value, legacy_latex_escape)
     });
 
     env.add_template("report", &raw)
@@ -103,11 +198,38 @@ pub fn generate_report(
         .render(&ctx)
         .map_err(|e| StorageError::TemplateError(e.to_string()))?;
 
-    // ── parse blocks and render via LaTeX ──
+    // ── parse blocks and run preprocessors ──
+    // Output-format selector: a `.html` output path renders a standalone
+    // HTML document via `blocks_to_html` (no TeX engine required); anything
+    // else takes the original `blocks_to_latex` → tectonic PDF path.
+    let is_html = Path::new(output_path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("html"));
+    let backend_name = if is_html {
+        crate::preprocessor::HTML_BACKEND
+    } else {
+        crate::preprocessor::LATEX_BACKEND
+    };
+
     let blocks = parse_blocks(&rendered);
+    let pp_ctx = ReportContext {
+        asset: asset.to_string(),
+        from: from.to_string(),
+        to: to.to_string(),
+        backend: backend_name.to_string(),
+    };
+    let blocks = crate::preprocessor::run_preprocessors(preprocessors, &pp_ctx, blocks)?;
     copy_template_assets(template_dir, work_dir.path())?;
-    let latex_src = blocks_to_latex(&blocks, asset);
-    render_pdf(&latex_src, output_path, work_dir.path())?;
+
+    // ── convert to the selected output format ──
+    if is_html {
+        let html = blocks_to_html(&blocks, asset, work_dir.path());
+        fs::write(output_path, html)?;
+    } else {
+        let latex_src = blocks_to_latex(&blocks, asset, legacy_latex_escape);
+        render_pdf(&latex_src, output_path, work_dir.path())?;
+    }
 
     Ok(())
 }
@@ -235,16 +357,23 @@ fn copy_template_assets(template_dir: &Path, work_dir: &Path) -> Result<()> {
 
 /// Intermediate representation of a report element, parsed from `#!`
 /// directives and plain text in the rendered template.
-#[derive(Debug, PartialEq)]
-enum Block {
+///
+/// `pub(crate)` and serde-derived so [`crate::preprocessor`] can hand the
+/// parsed stream to external/in-process preprocessors as JSON and accept
+/// a (possibly modified) stream back.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) enum Block {
     Title(String),
     Subtitle(String),
     Section(String),
     /// Finding card: (severity label, heading text).
     Finding(String, String),
     Meta(String, String),
-    /// Table rows – first row is the header.
-    Table(Vec<Vec<String>>),
+    /// Table rows – first row is the header. Per-column alignment, parsed
+    /// from the `|:---|---:|` separator row the same way [`parse_col_aligns`]
+    /// does for markdown tables; empty when the input had no separator or
+    /// its separator had no `:` markers.
+    Table(Vec<ColAlign>, Vec<Vec<String>>),
     /// Free-form markdown content.
     Text(String),
     /// Raw LaTeX passthrough — inserted verbatim into the document.
@@ -255,17 +384,109 @@ enum Block {
     Spacer(f32),
     PageBreak,
     HRule,
+    /// `#! bibliography path/to/refs.bib style=ieee|apa` — loads a BibTeX
+    /// file and selects the citation style for subsequent `[@key]`
+    /// markers. See [`Bibliography`].
+    Bibliography(String, BibStyle),
+    /// Rendered references section: `(key, entry)` pairs in first-citation
+    /// order, plus the active style. [`blocks_to_latex`] appends one of
+    /// these automatically once any `[@key]` marker has resolved; it's
+    /// also a regular `Block` so tests can construct one directly.
+    References(Vec<(String, BibEntry)>, BibStyle),
+    /// `#! chart pie|bar` followed by `Label | count` rows: a
+    /// severity-distribution (or other categorical) chart.
+    Chart(ChartKind, Vec<(String, f64)>),
+    /// `#! ref KEY | text | url` — defines one CVE/CWE/advisory reference,
+    /// citable from markdown text via an inline `[[KEY]]` span. See
+    /// [`ReferenceSet`].
+    Reference(String, String, String),
+    /// `#! references` — renders the numbered, back-linked reference list
+    /// for every `[[key]]` cited so far, in first-citation order.
+    ReferenceList,
+    /// `#! theme <name>` — selects a built-in [`Theme`] by name, or loads
+    /// one from a TOML/JSON file path. Only the first occurrence in a
+    /// document takes effect; see [`Theme::named`].
+    Theme(String),
+    /// `#! footnotes endnotes` — collect footnotes into a numbered list at
+    /// the end of the document (`true`) instead of the default per-page
+    /// `\footnote`/inline rendering (`false`). See [`Footnotes`].
+    FootnoteMode(bool),
+}
+
+/// Visualization style for `Block::Chart`, selected via `#! chart pie` or
+/// `#! chart bar`.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub(crate) enum ChartKind {
+    #[default]
+    Pie,
+    Bar,
+}
+
+impl ChartKind {
+    fn parse(s: &str) -> Self {
+        match s.trim().to_lowercase().as_str() {
+            "bar" => ChartKind::Bar,
+            _ => ChartKind::Pie,
+        }
+    }
 }
 
 // ───────────────────────── block parser ─────────────────────────
 
+/// Strip one `[^id]: definition` line, returning the id and the trimmed
+/// definition text. `id` must be a single bracket-free, space-free token
+/// so it can't be confused with the `[^ ... ]` inline footnote form.
+fn strip_footnote_def(line: &str) -> Option<(&str, &str)> {
+    let rest = line.strip_prefix("[^")?;
+    let (id, rest) = rest.split_once(']')?;
+    let def = rest.strip_prefix(':')?.trim();
+    if id.is_empty() || id.contains(' ') {
+        return None;
+    }
+    Some((id, def))
+}
+
+/// Pull every `[^id]: definition` line out of `text` (GFM reference-style
+/// footnote definitions, which may appear anywhere in the document, often
+/// far from their `[^id]` citation) and substitute each matching `[^id]`
+/// with the literal `[^ definition ]` inline form — so reference-style
+/// and inline footnotes converge to the one `MdSpan::Footnote` code path
+/// in [`parse_inline_spans`]. An `[^id]` with no matching definition is
+/// left untouched (and so stays plain text, per [`parse_inline_spans`]
+/// only recognizing the space-delimited inline form).
+fn substitute_footnote_refs(text: &str) -> String {
+    let mut defs: BTreeMap<String, String> = BTreeMap::new();
+    let mut kept_lines: Vec<&str> = Vec::new();
+    for line in text.lines() {
+        match strip_footnote_def(line.trim()) {
+            Some((id, def)) => {
+                defs.insert(id.to_string(), def.to_string());
+            }
+            None => kept_lines.push(line),
+        }
+    }
+    if defs.is_empty() {
+        return text.to_string();
+    }
+    let mut out = kept_lines.join("\n");
+    for (id, def) in &defs {
+        out = out.replace(&format!("[^{id}]"), &format!("[^ {def} ]"));
+    }
+    out
+}
+
 /// Parse the rendered template text into a sequence of [`Block`]s.
 fn parse_blocks(text: &str) -> Vec<Block> {
+    let text = substitute_footnote_refs(text);
+    let text = text.as_str();
     let mut blocks: Vec<Block> = Vec::new();
     let mut table_rows: Vec<Vec<String>> = Vec::new();
+    let mut table_aligns: Vec<ColAlign> = Vec::new();
     let mut text_buf = String::new();
     let mut in_latex = false;
     let mut latex_buf = String::new();
+    let mut chart_kind: Option<ChartKind> = None;
+    let mut chart_rows: Vec<(String, f64)> = Vec::new();
 
     let flush_text = |buf: &mut String, out: &mut Vec<Block>| {
         let trimmed = buf.trim().to_string();
@@ -275,10 +496,20 @@ fn parse_blocks(text: &str) -> Vec<Block> {
         buf.clear();
     };
 
-    let flush_table = |rows: &mut Vec<Vec<String>>, out: &mut Vec<Block>| {
+    let flush_table = |aligns: &mut Vec<ColAlign>, rows: &mut Vec<Vec<String>>, out: &mut Vec<Block>| {
         if !rows.is_empty() {
-            out.push(Block::Table(std::mem::take(rows)));
+            out.push(Block::Table(std::mem::take(aligns), std::mem::take(rows)));
+        }
+        aligns.clear();
+    };
+
+    let flush_chart = |kind: &mut Option<ChartKind>, rows: &mut Vec<(String, f64)>, out: &mut Vec<Block>| {
+        if let Some(k) = kind.take() {
+            if !rows.is_empty() {
+                out.push(Block::Chart(k, std::mem::take(rows)));
+            }
         }
+        rows.clear();
     };
 
     for line in text.lines() {
@@ -304,6 +535,7 @@ fn parse_blocks(text: &str) -> Vec<Block> {
         // Blank lines between text paragraphs get preserved inside the
         // text buffer as empty lines.
         if trimmed.is_empty() {
+            flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
             if !text_buf.is_empty() {
                 text_buf.push('\n');
             }
@@ -316,16 +548,20 @@ fn parse_blocks(text: &str) -> Vec<Block> {
 
             let rest = rest.trim();
             if let Some(arg) = rest.strip_prefix("title ") {
-                flush_table(&mut table_rows, &mut blocks);
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
                 blocks.push(Block::Title(arg.trim().to_string()));
             } else if let Some(arg) = rest.strip_prefix("subtitle ") {
-                flush_table(&mut table_rows, &mut blocks);
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
                 blocks.push(Block::Subtitle(arg.trim().to_string()));
             } else if let Some(arg) = rest.strip_prefix("section ") {
-                flush_table(&mut table_rows, &mut blocks);
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
                 blocks.push(Block::Section(arg.trim().to_string()));
             } else if let Some(arg) = rest.strip_prefix("finding ") {
-                flush_table(&mut table_rows, &mut blocks);
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
                 let arg = arg.trim();
                 if let Some(pos) = arg.find(' ') {
                     let sev = arg[..pos].to_string();
@@ -333,38 +569,91 @@ fn parse_blocks(text: &str) -> Vec<Block> {
                     blocks.push(Block::Finding(sev, heading));
                 }
             } else if let Some(arg) = rest.strip_prefix("meta ") {
-                flush_table(&mut table_rows, &mut blocks);
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
                 if let Some(pos) = arg.find(':') {
                     let key = arg[..pos].trim().to_string();
                     let val = arg[pos + 1..].trim().to_string();
                     blocks.push(Block::Meta(key, val));
                 }
+            } else if let Some(arg) = rest.strip_prefix("chart ") {
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
+                chart_kind = Some(ChartKind::parse(arg));
             } else if rest == "index" {
-                flush_table(&mut table_rows, &mut blocks);
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
                 blocks.push(Block::Index);
             } else if let Some(arg) = rest.strip_prefix("spacer ") {
-                flush_table(&mut table_rows, &mut blocks);
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
                 if let Ok(mm) = arg.trim().parse::<f32>() {
                     blocks.push(Block::Spacer(mm));
                 }
             } else if rest == "pagebreak" {
-                flush_table(&mut table_rows, &mut blocks);
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
                 blocks.push(Block::PageBreak);
             } else if rest == "hr" {
-                flush_table(&mut table_rows, &mut blocks);
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
                 blocks.push(Block::HRule);
             } else if rest == "latex" {
-                flush_table(&mut table_rows, &mut blocks);
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
                 in_latex = true;
                 latex_buf.clear();
             } else if let Some(arg) = rest.strip_prefix("latex ") {
-                flush_table(&mut table_rows, &mut blocks);
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
                 blocks.push(Block::Latex(arg.to_string()));
+            } else if let Some(arg) = rest.strip_prefix("bibliography ") {
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
+                let arg = arg.trim();
+                let mut path = arg;
+                let mut style = BibStyle::Ieee;
+                if let Some((p, s)) = arg.rsplit_once(' ')
+                    && let Some(style_arg) = s.strip_prefix("style=") {
+                    path = p.trim();
+                    style = BibStyle::parse(style_arg);
+                }
+                blocks.push(Block::Bibliography(path.to_string(), style));
+            } else if let Some(arg) = rest.strip_prefix("ref ") {
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
+                let cols = split_table_row(arg.trim());
+                if cols.len() == 3 {
+                    blocks.push(Block::Reference(cols[0].clone(), cols[1].clone(), cols[2].clone()));
+                }
+            } else if rest == "references" {
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
+                blocks.push(Block::ReferenceList);
+            } else if let Some(arg) = rest.strip_prefix("theme ") {
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
+                blocks.push(Block::Theme(arg.trim().to_string()));
+            } else if let Some(arg) = rest.strip_prefix("footnotes ") {
+                flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+                flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
+                blocks.push(Block::FootnoteMode(arg.trim() == "endnotes"));
             }
             // #! comment lines are silently ignored
             continue;
         }
 
+        // ── chart rows: `Label | count`, reusing the `|`-delimited parser ──
+        if chart_kind.is_some() && trimmed.contains('|') {
+            let cols = split_table_row(trimmed);
+            if cols.len() == 2
+                && let Ok(count) = cols[1].parse::<f64>()
+            {
+                chart_rows.push((cols[0].clone(), count));
+            }
+            continue;
+        }
+
         // ── pipe-delimited table row ──
         if trimmed.contains('|') && !trimmed.starts_with('-') {
             // Strip leading/trailing empty cells produced by lines
@@ -385,6 +674,10 @@ fn parse_blocks(text: &str) -> Vec<Block> {
             if is_separator {
                 if table_rows.is_empty() {
                     flush_text(&mut text_buf, &mut blocks);
+                } else if trimmed.contains(':') {
+                    // An all-dash separator carries no alignment markers, so
+                    // its columns keep the default layout.
+                    table_aligns = parse_col_aligns(trimmed);
                 }
                 continue;
             }
@@ -395,7 +688,8 @@ fn parse_blocks(text: &str) -> Vec<Block> {
         }
 
         // ── plain text ──
-        flush_table(&mut table_rows, &mut blocks);
+        flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+        flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
         if !text_buf.is_empty() {
             text_buf.push('\n');
         }
@@ -403,7 +697,8 @@ fn parse_blocks(text: &str) -> Vec<Block> {
     }
 
     flush_text(&mut text_buf, &mut blocks);
-    flush_table(&mut table_rows, &mut blocks);
+    flush_table(&mut table_aligns, &mut table_rows, &mut blocks);
+    flush_chart(&mut chart_kind, &mut chart_rows, &mut blocks);
 
     blocks
 }
@@ -415,8 +710,37 @@ fn parse_blocks(text: &str) -> Vec<Block> {
 enum MdBlock {
     Paragraph(Vec<MdSpan>),
     Heading(u8, Vec<MdSpan>),
-    BulletItem(Vec<MdSpan>),
-    CodeBlock(String),
+    /// Indent level (0 = top), computed from leading-space count.
+    BulletItem(u8, Vec<MdSpan>),
+    /// Item number as written in the source, indent level, and content —
+    /// e.g. `3. Do the thing` at the top level is `OrderedItem(3, 0, ...)`.
+    OrderedItem(u32, u8, Vec<MdSpan>),
+    /// A GFM task list item — `- [ ] ...` (unchecked) or `- [x] ...`
+    /// (checked), indent level, and content. Grouped into the same
+    /// `itemize`/`<ul>` as plain [`MdBlock::BulletItem`]s (see
+    /// `ListKind::Bullet`), with a checkbox glyph prepended per item.
+    TaskItem(u8, bool, Vec<MdSpan>),
+    /// A fenced code block, with the optional language token captured from
+    /// the opening fence (e.g. ```` ```python ````), used to select a
+    /// syntax-highlighting table — see [`lang_spec`].
+    CodeBlock { lang: Option<String>, code: String },
+    /// A `> ...` callout. Nested `> > ...` quotes become a `BlockQuote`
+    /// among these children, since each level of `>` is stripped one at a
+    /// time before the contents are re-parsed.
+    BlockQuote(Vec<MdBlock>),
+    /// A GFM pipe table: per-column alignment (from the `|---|:--:|`
+    /// separator row) and all rows including the header (row 0), each
+    /// cell holding parsed inline spans.
+    Table(Vec<ColAlign>, Vec<Vec<Vec<MdSpan>>>),
+}
+
+/// A GFM table column's alignment, parsed from the colons in its
+/// `|---|:--:|` separator cell (`:--` left, `--:` right, `:-:` center).
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ColAlign {
+    Left,
+    Center,
+    Right,
 }
 
 /// An inline markdown span.
@@ -429,14 +753,115 @@ enum MdSpan {
     Code(String),
     Link(String, String), // (display, url)
     Image(String, String), // (alt text, file path)
+    /// `[@key]` or `[@key1; @key2]` — one or more BibTeX citation keys.
+    Citation(Vec<String>),
+    /// `[[key]]` — an inline citation of a `#! ref`-defined reference.
+    Reference(String),
+    /// An aside rendered as a footnote — inline `[^ body text ]`, or a
+    /// reference-style `[^id]` whose body came from a matching
+    /// `[^id]: definition` line (substituted to this same form before
+    /// [`parse_inline_spans`] ever sees it — see [`substitute_footnote_refs`]).
+    Footnote(String),
 }
 
 // ───────────────────────── markdown parser ─────────────────────────
 
 /// Parse multi-line markdown text into block-level elements.
+/// Count of leading ASCII space characters (tabs aren't expanded; the repo's
+/// descriptions are hand-written with spaces).
+fn leading_spaces(line: &str) -> usize {
+    line.chars().take_while(|c| *c == ' ').count()
+}
+
+/// Two leading spaces per nesting level, matching common Markdown editors'
+/// default list-indent width.
+fn indent_level(spaces: usize) -> u8 {
+    (spaces / 2) as u8
+}
+
+/// Strip a `N. ` or `N) ` ordered-list marker, returning the item number
+/// and the remaining text.
+fn strip_ordered_marker(s: &str) -> Option<(u32, &str)> {
+    let digits_end = s.find(|c: char| !c.is_ascii_digit())?;
+    if digits_end == 0 {
+        return None;
+    }
+    let n: u32 = s[..digits_end].parse().ok()?;
+    let rest = &s[digits_end..];
+    let rest = rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") "))?;
+    Some((n, rest))
+}
+
+/// Strip a GFM task-list marker (`- [ ] `/`- [x] `/`* [X] `), returning
+/// whether it's checked and the remaining text.
+fn strip_task_marker(s: &str) -> Option<(bool, &str)> {
+    let rest = s.strip_prefix("- ").or_else(|| s.strip_prefix("* "))?;
+    let rest = rest.strip_prefix('[')?;
+    let (mark, rest) = rest.split_at(1);
+    let rest = rest.strip_prefix("] ")?;
+    match mark {
+        " " => Some((false, rest)),
+        "x" | "X" => Some((true, rest)),
+        _ => None,
+    }
+}
+
+/// Whether `line` is a GFM table separator row, e.g. `|---|:--:|---:|`.
+fn is_table_separator(line: &str) -> bool {
+    let line = line.trim();
+    if !line.contains('-') {
+        return false;
+    }
+    let cells: Vec<&str> = line.trim_matches('|').split('|').map(str::trim).collect();
+    !cells.is_empty()
+        && cells
+            .iter()
+            .all(|c| !c.is_empty() && c.chars().all(|ch| ch == '-' || ch == ':'))
+}
+
+/// Parse per-column alignment from a separator row's colons.
+fn parse_col_aligns(sep_line: &str) -> Vec<ColAlign> {
+    sep_line
+        .trim()
+        .trim_matches('|')
+        .split('|')
+        .map(|c| {
+            let c = c.trim();
+            let left = c.starts_with(':');
+            let right = c.ends_with(':');
+            match (left, right) {
+                (true, true) => ColAlign::Center,
+                (false, true) => ColAlign::Right,
+                _ => ColAlign::Left,
+            }
+        })
+        .collect()
+}
+
+/// Split a `| a | b |` row into its trimmed cell texts.
+fn split_table_row(line: &str) -> Vec<String> {
+    line.trim()
+        .trim_matches('|')
+        .split('|')
+        .map(|c| c.trim().to_string())
+        .collect()
+}
+
+/// Maximum nesting depth for blockquotes (`>`, `> >`, `> > >`, …). Pasted,
+/// mangled email quotes in an imported finding can carry thousands of
+/// leading `>` characters; without a cap, each level recurses into
+/// `parse_markdown` and blows the stack well before any real report would
+/// need more than a handful of levels.
+const MAX_BLOCKQUOTE_DEPTH: usize = 32;
+
 fn parse_markdown(text: &str) -> Vec<MdBlock> {
+    parse_markdown_at_depth(text, 0)
+}
+
+fn parse_markdown_at_depth(text: &str, depth: usize) -> Vec<MdBlock> {
     let mut out = Vec::new();
     let mut in_code = false;
+    let mut code_lang: Option<String> = None;
     let mut code_buf = String::new();
     let mut para_buf = String::new();
 
@@ -448,19 +873,25 @@ fn parse_markdown(text: &str) -> Vec<MdBlock> {
         buf.clear();
     };
 
-    for line in text.lines() {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
         let trimmed = line.trim();
 
         // ── fenced code blocks ──
         if trimmed.starts_with("```") {
             if in_code {
-                out.push(MdBlock::CodeBlock(code_buf.trim_end().to_string()));
+                out.push(MdBlock::CodeBlock { lang: code_lang.take(), code: code_buf.trim_end().to_string() });
                 code_buf.clear();
                 in_code = false;
             } else {
                 flush_para(&mut para_buf, &mut out);
+                let info = trimmed.trim_start_matches('`').trim();
+                code_lang = if info.is_empty() { None } else { Some(info.to_string()) };
                 in_code = true;
             }
+            i += 1;
             continue;
         }
         if in_code {
@@ -468,12 +899,63 @@ fn parse_markdown(text: &str) -> Vec<MdBlock> {
                 code_buf.push('\n');
             }
             code_buf.push_str(line);
+            i += 1;
             continue;
         }
 
         // ── blank line → flush paragraph ──
         if trimmed.is_empty() {
             flush_para(&mut para_buf, &mut out);
+            i += 1;
+            continue;
+        }
+
+        // ── block quotes (consume the whole `>`-prefixed run, recurse) ──
+        if trimmed.starts_with('>') {
+            flush_para(&mut para_buf, &mut out);
+            let mut quoted = String::new();
+            while i < lines.len() && lines[i].trim_start().starts_with('>') {
+                let stripped = lines[i].trim_start().strip_prefix('>').unwrap_or("");
+                let stripped = stripped.strip_prefix(' ').unwrap_or(stripped);
+                if !quoted.is_empty() {
+                    quoted.push('\n');
+                }
+                quoted.push_str(stripped);
+                i += 1;
+            }
+            let nested = if depth < MAX_BLOCKQUOTE_DEPTH {
+                parse_markdown_at_depth(&quoted, depth + 1)
+            } else {
+                // Depth limit reached: stop recursing and render whatever's
+                // left as a single flat paragraph instead of nesting further.
+                vec![MdBlock::Paragraph(parse_inline_spans(quoted.trim()))]
+            };
+            out.push(MdBlock::BlockQuote(nested));
+            continue;
+        }
+
+        // ── GFM tables (header + separator lookahead) ──
+        if trimmed.contains('|')
+            && i + 1 < lines.len()
+            && is_table_separator(lines[i + 1])
+        {
+            flush_para(&mut para_buf, &mut out);
+            let aligns = parse_col_aligns(lines[i + 1]);
+            let header = split_table_row(trimmed)
+                .iter()
+                .map(|c| parse_inline_spans(c))
+                .collect::<Vec<_>>();
+            let mut rows = vec![header];
+            i += 2;
+            while i < lines.len() && lines[i].trim().contains('|') && !lines[i].trim().is_empty() {
+                let row = split_table_row(lines[i].trim())
+                    .iter()
+                    .map(|c| parse_inline_spans(c))
+                    .collect::<Vec<_>>();
+                rows.push(row);
+                i += 1;
+            }
+            out.push(MdBlock::Table(aligns, rows));
             continue;
         }
 
@@ -481,28 +963,50 @@ fn parse_markdown(text: &str) -> Vec<MdBlock> {
         if let Some(rest) = trimmed.strip_prefix("### ") {
             flush_para(&mut para_buf, &mut out);
             out.push(MdBlock::Heading(3, parse_inline_spans(rest)));
+            i += 1;
             continue;
         }
         if let Some(rest) = trimmed.strip_prefix("## ") {
             flush_para(&mut para_buf, &mut out);
             out.push(MdBlock::Heading(2, parse_inline_spans(rest)));
+            i += 1;
             continue;
         }
         if let Some(rest) = trimmed.strip_prefix("# ") {
             flush_para(&mut para_buf, &mut out);
             out.push(MdBlock::Heading(1, parse_inline_spans(rest)));
+            i += 1;
+            continue;
+        }
+
+        // ── ordered list items (indent-aware) ──
+        let indent = indent_level(leading_spaces(line));
+        if let Some((n, rest)) = strip_ordered_marker(trimmed) {
+            flush_para(&mut para_buf, &mut out);
+            out.push(MdBlock::OrderedItem(n, indent, parse_inline_spans(rest)));
+            i += 1;
+            continue;
+        }
+
+        // ── task list items (indent-aware; checked before plain bullets) ──
+        if let Some((checked, rest)) = strip_task_marker(trimmed) {
+            flush_para(&mut para_buf, &mut out);
+            out.push(MdBlock::TaskItem(indent, checked, parse_inline_spans(rest)));
+            i += 1;
             continue;
         }
 
-        // ── bullet list items ──
+        // ── bullet list items (indent-aware) ──
         if let Some(rest) = trimmed.strip_prefix("- ") {
             flush_para(&mut para_buf, &mut out);
-            out.push(MdBlock::BulletItem(parse_inline_spans(rest)));
+            out.push(MdBlock::BulletItem(indent, parse_inline_spans(rest)));
+            i += 1;
             continue;
         }
         if let Some(rest) = trimmed.strip_prefix("* ") {
             flush_para(&mut para_buf, &mut out);
-            out.push(MdBlock::BulletItem(parse_inline_spans(rest)));
+            out.push(MdBlock::BulletItem(indent, parse_inline_spans(rest)));
+            i += 1;
             continue;
         }
 
@@ -511,11 +1015,12 @@ fn parse_markdown(text: &str) -> Vec<MdBlock> {
             para_buf.push(' ');
         }
         para_buf.push_str(trimmed);
+        i += 1;
     }
 
     flush_para(&mut para_buf, &mut out);
     if in_code && !code_buf.is_empty() {
-        out.push(MdBlock::CodeBlock(code_buf.trim_end().to_string()));
+        out.push(MdBlock::CodeBlock { lang: code_lang.take(), code: code_buf.trim_end().to_string() });
     }
 
     out
@@ -538,6 +1043,33 @@ fn parse_inline_spans(text: &str) -> Vec<MdSpan> {
     };
 
     while i < len {
+        // ── citation: [@key] or [@key1; @key2] ──
+        if chars[i] == '['
+            && let Some((keys, end)) = try_parse_citation(&chars, i) {
+                flush_plain(&mut plain, &mut spans);
+                spans.push(MdSpan::Citation(keys));
+                i = end;
+                continue;
+            }
+
+        // ── reference citation: [[key]] ──
+        if chars[i] == '['
+            && let Some((key, end)) = try_parse_reference(&chars, i) {
+                flush_plain(&mut plain, &mut spans);
+                spans.push(MdSpan::Reference(key));
+                i = end;
+                continue;
+            }
+
+        // ── footnote: [^ body text ] ──
+        if chars[i] == '['
+            && let Some((body, end)) = try_parse_footnote(&chars, i) {
+                flush_plain(&mut plain, &mut spans);
+                spans.push(MdSpan::Footnote(body));
+                i = end;
+                continue;
+            }
+
         // ── image: ![alt](path) ──
         if chars[i] == '!'
             && i + 1 < len
@@ -602,6 +1134,83 @@ fn parse_inline_spans(text: &str) -> Vec<MdSpan> {
     spans
 }
 
+/// Try to parse `[@key]` or `[@key1; @key2]` starting at position `start`
+/// (which must point at `[`). Returns the bare keys (the `@` stripped).
+fn try_parse_citation(chars: &[char], start: usize) -> Option<(Vec<String>, usize)> {
+    let mut i = start + 1;
+    if i >= chars.len() || chars[i] != '@' {
+        return None;
+    }
+    let mut inner = String::new();
+    while i < chars.len() && chars[i] != ']' {
+        inner.push(chars[i]);
+        i += 1;
+    }
+    if i >= chars.len() {
+        return None;
+    }
+    i += 1; // skip ']'
+
+    let keys: Vec<String> = inner
+        .split(';')
+        .map(|part| part.trim().trim_start_matches('@').trim().to_string())
+        .filter(|k| !k.is_empty())
+        .collect();
+    if keys.is_empty() {
+        return None;
+    }
+    Some((keys, i))
+}
+
+/// Try to parse `[[key]]` — an inline reference citation — starting at
+/// position `start` (which must point at the first `[`).
+fn try_parse_reference(chars: &[char], start: usize) -> Option<(String, usize)> {
+    if start + 1 >= chars.len() || chars[start + 1] != '[' {
+        return None;
+    }
+    let mut i = start + 2;
+    let mut key = String::new();
+    while i < chars.len() && chars[i] != ']' {
+        key.push(chars[i]);
+        i += 1;
+    }
+    if i + 1 >= chars.len() || chars[i] != ']' || chars[i + 1] != ']' {
+        return None;
+    }
+    i += 2;
+    let key = key.trim().to_string();
+    if key.is_empty() {
+        return None;
+    }
+    Some((key, i))
+}
+
+/// Try to parse `[^ footnote body ]` — an inline footnote aside —
+/// starting at position `start` (which must point at the `[`). The space
+/// right after `^` distinguishes this from a reference-style `[^id]`,
+/// which [`substitute_footnote_refs`] has already rewritten to this form
+/// by the time `parse_inline_spans` runs, or otherwise left as plain text.
+fn try_parse_footnote(chars: &[char], start: usize) -> Option<(String, usize)> {
+    if start + 2 >= chars.len() || chars[start + 1] != '^' || chars[start + 2] != ' ' {
+        return None;
+    }
+    let mut i = start + 3;
+    let mut body = String::new();
+    while i < chars.len() && chars[i] != ']' {
+        body.push(chars[i]);
+        i += 1;
+    }
+    if i >= chars.len() {
+        return None;
+    }
+    i += 1; // skip ']'
+    let body = body.trim().to_string();
+    if body.is_empty() {
+        return None;
+    }
+    Some((body, i))
+}
+
 /// Try to parse `[display](url)` starting at position `start`.
 fn try_parse_link(chars: &[char], start: usize) -> Option<(String, String, usize)> {
     // `start` must point at '['
@@ -680,6 +1289,13 @@ fn spans_to_plain(spans: &[MdSpan]) -> String {
             | MdSpan::Code(t) => out.push_str(t),
             MdSpan::Link(display, _) => out.push_str(display),
             MdSpan::Image(alt, _) => out.push_str(alt),
+            MdSpan::Citation(keys) => {
+                out.push('[');
+                out.push_str(&keys.iter().map(|k| format!("@{k}")).collect::<Vec<_>>().join("; "));
+                out.push(']');
+            }
+            MdSpan::Reference(key) => out.push_str(&format!("[[{key}]]")),
+            MdSpan::Footnote(body) => out.push_str(body),
         }
     }
     out
@@ -688,7 +1304,19 @@ fn spans_to_plain(spans: &[MdSpan]) -> String {
 // ───────────────────────── LaTeX helpers ─────────────────────────
 
 /// Escape characters that are special in LaTeX.
-fn latex_escape(text: &str) -> String {
+/// Escape text for embedding in LaTeX source.
+///
+/// By default (`legacy == false`) this only escapes TeX's genuine
+/// metacharacters; every other codepoint passes through verbatim, relying
+/// on [`latex_preamble`]'s `fontspec` setup to render it natively (CJK,
+/// Cyrillic, Greek, accented hostnames, emoji, …) since tectonic is a
+/// XeTeX engine. With `legacy == true` it additionally transliterates a
+/// fixed table of common Unicode punctuation/symbols into their nearest
+/// LaTeX macro or ASCII approximation, for the old `inputenc`/`fontenc`
+/// pdfLaTeX-style preamble — see the `legacy_latex_escape` flag on
+/// [`generate_report`]. Either way, anything left over still passes
+/// through raw rather than failing.
+fn latex_escape(text: &str, legacy: bool) -> String {
     let mut out = String::with_capacity(text.len());
     for ch in text.chars() {
         match ch {
@@ -703,35 +1331,42 @@ fn latex_escape(text: &str) -> String {
             '~' => out.push_str(r"\textasciitilde{}"),
             '^' => out.push_str(r"\textasciicircum{}"),
             // Unicode dashes
-            '\u{2013}' => out.push_str("--"),          // en-dash –
-            '\u{2014}' => out.push_str("---"),         // em-dash —
+            '\u{2013}' if legacy => out.push_str("--"),          // en-dash –
+            '\u{2014}' if legacy => out.push_str("---"),         // em-dash —
             // Unicode quotes
-            '\u{2018}' => out.push_str("`"),            // left single quote ‘
-            '\u{2019}' => out.push_str("'"),            // right single quote ’
-            '\u{201C}' => out.push_str("``"),           // left double quote “
-            '\u{201D}' => out.push_str("''"),           // right double quote ”
+            '\u{2018}' if legacy => out.push_str("`"),            // left single quote ‘
+            '\u{2019}' if legacy => out.push_str("'"),            // right single quote ’
+            '\u{201C}' if legacy => out.push_str("``"),           // left double quote “
+            '\u{201D}' if legacy => out.push_str("''"),           // right double quote ”
             // Currency & symbols
-            '\u{20AC}' => out.push_str("\\texteuro{}"),  // euro sign €
-            '\u{00A3}' => out.push_str("\\textsterling{}"), // pound sign
-            '\u{00A9}' => out.push_str("\\textcopyright{}"), // copyright
-            '\u{00AE}' => out.push_str("\\textregistered{}"), // registered
-            '\u{2122}' => out.push_str("\\texttrademark{}"), // trademark
-            '\u{00B0}' => out.push_str("\\textdegree{}"), // degree
+            '\u{20AC}' if legacy => out.push_str("\\texteuro{}"),  // euro sign €
+            '\u{00A3}' if legacy => out.push_str("\\textsterling{}"), // pound sign
+            '\u{00A9}' if legacy => out.push_str("\\textcopyright{}"), // copyright
+            '\u{00AE}' if legacy => out.push_str("\\textregistered{}"), // registered
+            '\u{2122}' if legacy => out.push_str("\\texttrademark{}"), // trademark
+            '\u{00B0}' if legacy => out.push_str("\\textdegree{}"), // degree
             // Math operators
-            '\u{00D7}' => out.push_str("$\\times$"),    // multiplication sign ×
-            '\u{00F7}' => out.push_str("$\\div$"),      // division sign
-            '\u{2264}' => out.push_str("$\\leq$"),      // less-than or equal
-            '\u{2265}' => out.push_str("$\\geq$"),      // greater-than or equal
-            '\u{2248}' => out.push_str("$\\approx$"),   // approximately
-            '\u{2260}' => out.push_str("$\\neq$"),      // not equal
+            '\u{00D7}' if legacy => out.push_str("$\\times$"),    // multiplication sign ×
+            '\u{00F7}' if legacy => out.push_str("$\\div$"),      // division sign
+            '\u{2264}' if legacy => out.push_str("$\\leq$"),      // less-than or equal
+            '\u{2265}' if legacy => out.push_str("$\\geq$"),      // greater-than or equal
+            '\u{2248}' if legacy => out.push_str("$\\approx$"),   // approximately
+            '\u{2260}' if legacy => out.push_str("$\\neq$"),      // not equal
             // Arrows
-            '\u{2192}' => out.push_str("$\\rightarrow$"), // right arrow
-            '\u{2190}' => out.push_str("$\\leftarrow$"),  // left arrow
+            '\u{2192}' if legacy => out.push_str("$\\rightarrow$"), // right arrow
+            '\u{2190}' if legacy => out.push_str("$\\leftarrow$"),  // left arrow
             // Misc
-            '\u{2022}' => out.push_str("\\textbullet{}"), // bullet
-            '\u{2026}' => out.push_str("\\ldots{}"),     // ellipsis
-            '\u{00AB}' => out.push_str("\\guillemotleft{}"), // «
-            '\u{00BB}' => out.push_str("\\guillemotright{}"), // »
+            '\u{2022}' if legacy => out.push_str("\\textbullet{}"), // bullet
+            '\u{2026}' if legacy => out.push_str("\\ldots{}"),     // ellipsis
+            '\u{00AB}' if legacy => out.push_str("\\guillemotleft{}"), // «
+            '\u{00BB}' if legacy => out.push_str("\\guillemotright{}"), // »
+            // The private-use codepoints `resolve_citations`/`resolve_references_latex`/
+            // `resolve_footnotes_latex` scan the *rendered* text for (see
+            // `CITATION_MARKER` et al.) — a finding field that happens to
+            // contain one of these verbatim must not be able to forge a
+            // marker once rendered, so they're scrubbed here rather than
+            // passed through like other Unicode.
+            '\u{E000}' | '\u{E001}' | '\u{E002}' | '\u{E003}' => out.push('\u{FFFD}'),
             _ => out.push(ch),
         }
     }
@@ -739,7 +1374,7 @@ fn latex_escape(text: &str) -> String {
 }
 
 /// Convert a severity label to a LaTeX xcolor name.
-fn severity_latex_color(sev: &str) -> &str {
+fn severity_latex_color(sev: &str) -> &'static str {
     match sev.to_lowercase().as_str() {
         "critical" => "SevCritical",
         "high" => "SevHigh",
@@ -750,48 +1385,101 @@ fn severity_latex_color(sev: &str) -> &str {
     }
 }
 
+/// Fallback colors for `Block::Chart` labels that aren't a known severity,
+/// cycled by position so a non-severity chart still gets distinct wedges.
+const CHART_NEUTRAL_PALETTE: &[&str] = &["CorpAccent", "CorpGray", "CorpDark", "CorpRule"];
+
+/// Resolve a chart label to an xcolor name: the severity palette when the
+/// label is a known severity, otherwise a neutral color cycled by `idx`.
+fn chart_color(label: &str, idx: usize) -> &'static str {
+    match severity_latex_color(label) {
+        "black" => CHART_NEUTRAL_PALETTE[idx % CHART_NEUTRAL_PALETTE.len()],
+        color => color,
+    }
+}
+
+/// Render a `Block::Chart` as a `pgf-pie` wedge chart, with each slice's
+/// percentage computed from `data` and labeled with its name and raw count.
+fn latex_pie_chart(data: &[(String, f64)], legacy: bool) -> String {
+    let total: f64 = data.iter().map(|(_, n)| n).sum();
+    if total <= 0.0 {
+        return String::new();
+    }
+
+    let colors: Vec<&str> = data.iter().enumerate().map(|(i, (label, _))| chart_color(label, i)).collect();
+    let slices: Vec<String> = data
+        .iter()
+        .map(|(label, n)| format!("{:.1}/{} ({})", (n / total) * 100.0, latex_escape(label, legacy), n))
+        .collect();
+
+    format!(
+        "\\begin{{center}}\n\\begin{{tikzpicture}}\n\\pie[color={{{}}}]{{{}}}\n\\end{{tikzpicture}}\n\\end{{center}}\n\n",
+        colors.join(","),
+        slices.join(", "),
+    )
+}
+
+/// Render a `Block::Chart` as a plain `tikz` bar chart (no `pgfplots`
+/// dependency): one colored bar per `(label, count)`, scaled to the
+/// tallest bar, with the count printed above each bar and the label below.
+fn latex_bar_chart(data: &[(String, f64)], legacy: bool) -> String {
+    let max = data.iter().map(|(_, n)| *n).fold(0.0_f64, f64::max);
+    if max <= 0.0 {
+        return String::new();
+    }
+
+    let bar_width = 1.2_f64;
+    let gap = 0.6_f64;
+    let max_height = 6.0_f64;
+
+    let mut bars = String::new();
+    for (i, (label, n)) in data.iter().enumerate() {
+        let x = i as f64 * (bar_width + gap);
+        let height = (n / max) * max_height;
+        let color = chart_color(label, i);
+        bars.push_str(&format!(
+            "\\draw[fill={color}] ({x:.2},0) rectangle ({:.2},{height:.2});\n\
+             \\node[above] at ({:.2},{height:.2}) {{{n}}};\n\
+             \\node[below, align=center] at ({:.2},0) {{{}}};\n",
+            x + bar_width,
+            x + bar_width / 2.0,
+            x + bar_width / 2.0,
+            latex_escape(label, legacy),
+        ));
+    }
+
+    format!(
+        "\\begin{{center}}\n\\begin{{tikzpicture}}\n{bars}\\end{{tikzpicture}}\n\\end{{center}}\n\n",
+    )
+}
+
 /// Render a slice of [`MdSpan`]s to LaTeX inline markup.
-fn spans_to_latex(spans: &[MdSpan]) -> String {
+fn spans_to_latex(spans: &[MdSpan], legacy: bool) -> String {
+    let renderer = LatexBackend::new(legacy, Theme::default());
     let mut out = String::new();
     for s in spans {
         match s {
-            MdSpan::Plain(t) => out.push_str(&latex_escape(t)),
-            MdSpan::Bold(t) => {
-                out.push_str(r"\textbf{");
-                out.push_str(&latex_escape(t));
-                out.push('}');
-            }
-            MdSpan::Italic(t) => {
-                out.push_str(r"\textit{");
-                out.push_str(&latex_escape(t));
-                out.push('}');
-            }
-            MdSpan::BoldItalic(t) => {
-                out.push_str(r"\textbf{\textit{");
-                out.push_str(&latex_escape(t));
-                out.push_str("}}");
-            }
-            MdSpan::Code(t) => {
-                out.push_str(r"\code{");
-                out.push_str(&latex_escape(t));
-                out.push('}');
-            }
-            MdSpan::Link(display, url) => {
-                out.push_str(r"\href{");
-                out.push_str(&latex_escape(url));
-                out.push_str("}{");
-                out.push_str(&latex_escape(display));
-                out.push('}');
-            }
-            MdSpan::Image(alt, path) => {
-                out.push_str("\n\n\\begin{center}\n");
-                out.push_str(&format!("\\IfFileExists{{{}}}{{", path));
-                out.push_str(&format!("\\includegraphics[width=0.9\\linewidth]{{{}}}\\\\[2mm]\n", path));
-                if !alt.is_empty() {
-                    out.push_str(&format!("{{\\small\\color{{CorpGray}}\\textit{{{}}}}}\n", latex_escape(alt)));
-                }
-                out.push_str("}{}");
-                out.push_str("\\end{center}\n\n");
+            MdSpan::Plain(t) => out.push_str(&renderer.plain(t)),
+            MdSpan::Bold(t) => out.push_str(&renderer.bold(t)),
+            MdSpan::Italic(t) => out.push_str(&renderer.italic(t)),
+            MdSpan::BoldItalic(t) => out.push_str(&renderer.bold_italic(t)),
+            MdSpan::Code(t) => out.push_str(&renderer.code(t)),
+            MdSpan::Link(display, url) => out.push_str(&renderer.link(display, url)),
+            MdSpan::Image(alt, path) => out.push_str(&renderer.image(alt, path)),
+            MdSpan::Citation(keys) => {
+                out.push_str(CITATION_MARKER);
+                out.push_str(&keys.join(","));
+                out.push(CITATION_MARKER_END);
+            }
+            MdSpan::Reference(key) => {
+                out.push_str(REFERENCE_MARKER);
+                out.push_str(key);
+                out.push(REFERENCE_MARKER_END);
+            }
+            MdSpan::Footnote(body) => {
+                out.push_str(FOOTNOTE_MARKER);
+                out.push_str(body);
+                out.push(FOOTNOTE_MARKER_END);
             }
         }
     }
@@ -799,1258 +1487,4427 @@ fn spans_to_latex(spans: &[MdSpan]) -> String {
 }
 
 /// Render markdown text to LaTeX markup (block-level).
-fn md_to_latex(text: &str) -> String {
-    let md_blocks = parse_markdown(text);
-    let mut out = String::new();
+/// Which environment/tag a nested list level is rendered with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ListKind {
+    Bullet,
+    Ordered,
+}
+
+/// Open/close whatever list environments are needed so `stack` ends up
+/// holding exactly one entry per nesting level from 0 to `depth`, with
+/// `kind` at `depth` — closing deeper levels (and a wrong-kind list at
+/// `depth` itself) first, then opening any newly-needed levels.
+/// `start` is only used when opening a fresh `Ordered` level.
+fn sync_list_stack(
+    stack: &mut Vec<ListKind>,
+    depth: u8,
+    kind: ListKind,
+    start: u32,
+    out: &mut String,
+    open: impl Fn(ListKind, u32) -> String,
+    close: impl Fn(ListKind) -> String,
+) {
+    let depth = depth as usize;
+    while stack.len() > depth + 1 || (stack.len() == depth + 1 && stack[depth] != kind) {
+        let k = stack.pop().unwrap();
+        out.push_str(&close(k));
+    }
+    while stack.len() < depth + 1 {
+        stack.push(kind);
+        out.push_str(&open(kind, start));
+    }
+}
+
+/// Close every currently-open list level, deepest first.
+fn close_all_lists(stack: &mut Vec<ListKind>, out: &mut String, close: impl Fn(ListKind) -> String) {
+    while let Some(k) = stack.pop() {
+        out.push_str(&close(k));
+    }
+}
+
+fn latex_list_open(kind: ListKind, start: u32) -> String {
+    match kind {
+        ListKind::Bullet => "\\begin{itemize}\n".to_string(),
+        ListKind::Ordered if start != 1 => {
+            format!("\\begin{{enumerate}}\n  \\setcounter{{enumi}}{{{}}}\n", start.saturating_sub(1))
+        }
+        ListKind::Ordered => "\\begin{enumerate}\n".to_string(),
+    }
+}
+
+fn latex_list_close(kind: ListKind) -> String {
+    match kind {
+        ListKind::Bullet => "\\end{itemize}\n".to_string(),
+        ListKind::Ordered => "\\end{enumerate}\n".to_string(),
+    }
+}
+
+/// Render a GFM table as a `booktabs`-styled `tabular`, with per-column
+/// alignment from `aligns` and row 0 of `rows` used as the header.
+fn md_table_to_latex(aligns: &[ColAlign], rows: &[Vec<Vec<MdSpan>>], legacy: bool) -> String {
+    let col_spec: String = aligns
+        .iter()
+        .map(|a| match a {
+            ColAlign::Left => 'l',
+            ColAlign::Center => 'c',
+            ColAlign::Right => 'r',
+        })
+        .collect();
+
+    let mut out = format!("\\begin{{tabular}}{{{col_spec}}}\n\\toprule\n");
+    for (i, row) in rows.iter().enumerate() {
+        let cells: Vec<String> = row.iter().map(|spans| spans_to_latex(spans, legacy)).collect();
+        out.push_str(&cells.join(" & "));
+        out.push_str(" \\\\\n");
+        if i == 0 {
+            out.push_str("\\midrule\n");
+        }
+    }
+    out.push_str("\\bottomrule\n\\end{tabular}\n\n");
+    out
+}
 
-    let mut in_itemize = false;
+fn render_md_blocks_to_latex(blocks: &[MdBlock], legacy: bool) -> String {
+    let mut out = String::new();
+    let mut lists: Vec<ListKind> = Vec::new();
 
-    for mb in &md_blocks {
+    for mb in blocks {
         match mb {
             MdBlock::Paragraph(spans) => {
-                if in_itemize {
-                    out.push_str("\\end{itemize}\n");
-                    in_itemize = false;
-                }
-                out.push_str(&spans_to_latex(spans));
+                close_all_lists(&mut lists, &mut out, latex_list_close);
+                out.push_str(&spans_to_latex(spans, legacy));
                 out.push_str("\n\n");
             }
             MdBlock::Heading(level, spans) => {
-                if in_itemize {
-                    out.push_str("\\end{itemize}\n");
-                    in_itemize = false;
-                }
+                close_all_lists(&mut lists, &mut out, latex_list_close);
                 let cmd = match level {
                     1 => "subsection*",
                     2 => "subsubsection*",
                     _ => "paragraph*",
                 };
-                out.push_str(&format!("\\{}{{{}}}\n\n", cmd, spans_to_latex(spans)));
+                out.push_str(&format!("\\{}{{{}}}\n\n", cmd, spans_to_latex(spans, legacy)));
             }
-            MdBlock::BulletItem(spans) => {
-                if !in_itemize {
-                    out.push_str("\\begin{itemize}\n");
-                    in_itemize = true;
-                }
-                out.push_str(&format!("  \\item {}\n", spans_to_latex(spans)));
+            MdBlock::BulletItem(depth, spans) => {
+                sync_list_stack(&mut lists, *depth, ListKind::Bullet, 1, &mut out, latex_list_open, latex_list_close);
+                out.push_str(&format!("  \\item {}\n", spans_to_latex(spans, legacy)));
             }
-            MdBlock::CodeBlock(code) => {
-                if in_itemize {
-                    out.push_str("\\end{itemize}\n");
-                    in_itemize = false;
-                }
-                out.push_str("\\begin{lstlisting}\n");
-                out.push_str(code);
-                out.push_str("\n\\end{lstlisting}\n\n");
+            MdBlock::OrderedItem(n, depth, spans) => {
+                sync_list_stack(&mut lists, *depth, ListKind::Ordered, *n, &mut out, latex_list_open, latex_list_close);
+                out.push_str(&format!("  \\item {}\n", spans_to_latex(spans, legacy)));
+            }
+            MdBlock::TaskItem(depth, checked, spans) => {
+                sync_list_stack(&mut lists, *depth, ListKind::Bullet, 1, &mut out, latex_list_open, latex_list_close);
+                let glyph = if *checked { r"$\boxtimes$" } else { r"$\square$" };
+                out.push_str(&format!("  \\item {} {}\n", glyph, spans_to_latex(spans, legacy)));
+            }
+            MdBlock::CodeBlock { lang, code } => {
+                close_all_lists(&mut lists, &mut out, latex_list_close);
+                out.push_str(&code_block_to_latex(lang.as_deref(), code, legacy));
+            }
+            MdBlock::BlockQuote(children) => {
+                close_all_lists(&mut lists, &mut out, latex_list_close);
+                out.push_str("\\begin{quote}\\color{CorpAccent}\n");
+                out.push_str(&render_md_blocks_to_latex(children, legacy));
+                out.push_str("\\end{quote}\n\n");
+            }
+            MdBlock::Table(aligns, rows) => {
+                close_all_lists(&mut lists, &mut out, latex_list_close);
+                out.push_str(&md_table_to_latex(aligns, rows, legacy));
             }
         }
     }
 
-    if in_itemize {
-        out.push_str("\\end{itemize}\n");
-    }
-
+    close_all_lists(&mut lists, &mut out, latex_list_close);
     out
 }
 
-// ───────────────────────── blocks → LaTeX document ─────────────────────────
+fn md_to_latex(text: &str, legacy: bool) -> String {
+    render_md_blocks_to_latex(&parse_markdown(text), legacy)
+}
 
-/// Convert the parsed blocks into a complete LaTeX document string.
-fn blocks_to_latex(blocks: &[Block], asset: &str) -> String {
-    let mut body = String::new();
-    let mut after_section = false;
+// ───────────────────────── syntax highlighting ─────────────────────────
 
-    for block in blocks {
-        match block {
-            Block::Title(t) => {
-                body.push_str(&format!(
-                    "\\thispagestyle{{empty}}\n\
-                     \\vspace*{{40mm}}\n\
-                     \\begin{{center}}\n\
-                     {{\\color{{CorpDark}}\\rule{{0.6\\textwidth}}{{2pt}}}}\\\\[6mm]\n\
-                     {{\\Huge\\bfseries\\color{{CorpDark}} {}}}\\\\[6mm]\n\
-                     {{\\color{{CorpDark}}\\rule{{0.6\\textwidth}}{{2pt}}}}\n\
-                     \\end{{center}}\n\
-                     \\vspace{{10mm}}\n\n",
-                    latex_escape(t),
-                ));
-            }
-            Block::Subtitle(t) => {
-                body.push_str(&format!(
-                    "\\begin{{center}}\n\
-                     {{\\Large\\color{{CorpGray}} {}}}\n\
-                     \\end{{center}}\n\
-                     \\vspace{{4mm}}\n\n",
-                    latex_escape(t),
-                ));
-            }
-            Block::Section(t) => {
-                body.push_str(&format!(
-                    "\\section{{{}}}\n\n",
-                    latex_escape(t),
-                ));
-                after_section = true;
-            }
-            Block::Finding(sev, heading) => {
-                let color = severity_latex_color(sev);
-                if !after_section {
-                    body.push_str("\\clearpage\n");
-                }
-                after_section = false;
-                body.push_str(&format!(
-                    "\\noindent\\colorbox{{{}!10}}{{\\parbox{{\\dimexpr\\textwidth-2\\fboxsep}}{{%\n\
-                       \\large\\bfseries\\color{{CorpDark}} {}\n\
-                       \\hfill {{\\normalsize\\colorbox{{{}}}{{\\color{{white}}\\textbf{{\\,{}\\,}}}}}}\n\
-                     }}}}\n\
-                     \\vspace{{0.5mm}}\n\
-                     {{\\noindent\\color{{{}}}\\rule{{\\textwidth}}{{1.5pt}}}}\n\
-                     \\nopagebreak\n\
-                     \\vspace{{1mm}}\n\n",
-                    color,
-                    latex_escape(heading),
-                    color,
-                    latex_escape(sev),
-                    color,
-                ));
-            }
-            Block::Meta(key, val) => {
-                after_section = false;
-                body.push_str(&format!(
-                    "\\noindent{{\\color{{CorpGray}}\\textbf{{{}:}}}} {}\\par\\vspace{{-0.3\\parskip}}\n",
-                    latex_escape(key),
-                    latex_escape(val),
-                ));
-            }
-            Block::Table(rows) => {
-                if rows.is_empty() {
-                    continue;
-                }
-                let ncols = rows[0].len();
-                // Use first column as fixed width, rest expand.
-                let col_spec = if ncols <= 2 {
-                    "l X".to_string()
-                } else {
-                    let mut s = String::from("l ");
-                    for _ in 1..ncols {
-                        s.push_str("X ");
-                    }
-                    s
-                };
-                // Increase row height for better readability
-                body.push_str("{\\renewcommand{\\arraystretch}{1.35}\n");
-                body.push_str(&format!(
-                    "\\noindent\n\\begin{{tabularx}}{{\\textwidth}}{{{}}}\n\\toprule\n",
-                    col_spec.trim(),
-                ));
-                // header row
-                if let Some(header) = rows.first() {
-                    let cells: Vec<String> =
-                        header.iter().map(|c| format!("\\textbf{{\\color{{CorpDark}}{}}}", latex_escape(c))).collect();
-                    body.push_str("\\rowcolor{CorpRule!30}\n");
-                    body.push_str(&cells.join(" & "));
-                    body.push_str(" \\\\\n\\midrule\n");
-                }
-                // data rows (with alternating background)
-                for (idx, row) in rows.iter().skip(1).enumerate() {
-                    if idx % 2 == 1 {
-                        body.push_str("\\rowcolor{CodeBg}\n");
-                    }
-                    let cells: Vec<String> =
-                        row.iter().map(|c| latex_escape(c)).collect();
-                    body.push_str(&cells.join(" & "));
-                    body.push_str(" \\\\\n");
-                }
-                body.push_str("\\bottomrule\n\\end{tabularx}\n}\n\\vspace{4mm}\n\n");
-            }
-            Block::Latex(raw) => {
-                after_section = false;
-                body.push_str(raw);
-                body.push_str("\n\n");
+/// A token category produced by [`tokenize_code_line`]. Carries no styling
+/// itself — each output backend (LaTeX, HTML) maps these to its own markup.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CodeTokenKind {
+    Keyword,
+    Str,
+    Comment,
+    Plain,
+}
+
+/// A compact per-language table driving [`tokenize_code_line`]: its
+/// keyword set, line-comment prefix (if any), and string delimiter
+/// characters. Deliberately minimal — this is a highlighter for readability
+/// in pentest report snippets, not a full language grammar.
+struct LangSpec {
+    keywords: &'static [&'static str],
+    line_comment: Option<&'static str>,
+    string_delims: &'static [char],
+}
+
+const BASH_KEYWORDS: &[&str] = &[
+    "if", "then", "else", "elif", "fi", "for", "while", "do", "done", "case", "esac", "function",
+    "return", "export", "local", "in", "break", "continue", "sudo", "echo",
+];
+const PYTHON_KEYWORDS: &[&str] = &[
+    "def", "class", "if", "elif", "else", "for", "while", "return", "import", "from", "as", "with",
+    "try", "except", "finally", "raise", "pass", "break", "continue", "lambda", "yield", "None",
+    "True", "False", "and", "or", "not", "in", "is",
+];
+const SQL_KEYWORDS: &[&str] = &[
+    "SELECT", "FROM", "WHERE", "INSERT", "INTO", "VALUES", "UPDATE", "SET", "DELETE", "JOIN",
+    "LEFT", "RIGHT", "INNER", "OUTER", "ON", "GROUP", "BY", "ORDER", "HAVING", "UNION", "AND",
+    "OR", "NOT", "NULL", "LIKE", "LIMIT", "DROP", "TABLE", "CREATE", "ALTER", "AS",
+];
+const HTTP_KEYWORDS: &[&str] = &[
+    "GET", "POST", "PUT", "DELETE", "PATCH", "HEAD", "OPTIONS", "HTTP/1.0", "HTTP/1.1", "HTTP/2",
+];
+const JSON_KEYWORDS: &[&str] = &["true", "false", "null"];
+
+/// Look up the highlighting table for a fenced code block's info-string
+/// language token (case-insensitive, with common aliases). Returns `None`
+/// for unrecognized/absent languages, so callers fall back to plain
+/// escaped verbatim.
+fn lang_spec(lang: &str) -> Option<LangSpec> {
+    match lang.trim().to_lowercase().as_str() {
+        "bash" | "sh" | "shell" => Some(LangSpec { keywords: BASH_KEYWORDS, line_comment: Some("#"), string_delims: &['"', '\''] }),
+        "python" | "py" => Some(LangSpec { keywords: PYTHON_KEYWORDS, line_comment: Some("#"), string_delims: &['"', '\''] }),
+        "sql" => Some(LangSpec { keywords: SQL_KEYWORDS, line_comment: Some("--"), string_delims: &['\''] }),
+        "http" => Some(LangSpec { keywords: HTTP_KEYWORDS, line_comment: None, string_delims: &['"'] }),
+        "json" => Some(LangSpec { keywords: JSON_KEYWORDS, line_comment: None, string_delims: &['"'] }),
+        _ => None,
+    }
+}
+
+/// Tokenize one line of code per `spec`. An unrecognized line-comment
+/// prefix never matches, so this falls through to word/plain scanning; a
+/// string that never finds its closing delimiter just runs to end of
+/// line — this never drops characters, it only ever reclassifies them.
+fn tokenize_code_line(line: &str, spec: &LangSpec) -> Vec<(CodeTokenKind, String)> {
+    let chars: Vec<char> = line.chars().collect();
+    let len = chars.len();
+    let mut out = Vec::new();
+    let mut plain = String::new();
+    let mut i = 0;
+
+    let flush_plain = |p: &mut String, out: &mut Vec<(CodeTokenKind, String)>| {
+        if !p.is_empty() {
+            out.push((CodeTokenKind::Plain, std::mem::take(p)));
+        }
+    };
+
+    while i < len {
+        // ── line comment: consumes the rest of the line ──
+        if let Some(prefix) = spec.line_comment {
+            let prefix_chars: Vec<char> = prefix.chars().collect();
+            if chars[i..].starts_with(prefix_chars.as_slice()) {
+                flush_plain(&mut plain, &mut out);
+                out.push((CodeTokenKind::Comment, chars[i..].iter().collect()));
+                break;
             }
-            Block::Text(t) => {
-                after_section = false;
-                body.push_str(&md_to_latex(t));
+        }
+
+        // ── string literal: unterminated strings just run to EOL ──
+        if spec.string_delims.contains(&chars[i]) {
+            flush_plain(&mut plain, &mut out);
+            let delim = chars[i];
+            let start = i;
+            i += 1;
+            while i < len && chars[i] != delim {
+                i += 1;
             }
-            Block::Index => {
-                body.push_str("\\tableofcontents\n\\vspace{6mm}\n\n");
+            if i < len {
+                i += 1; // consume the closing delimiter
             }
-            Block::Spacer(mm) => {
-                body.push_str(&format!("\\vspace{{{}mm}}\n\n", mm));
+            out.push((CodeTokenKind::Str, chars[start..i].iter().collect()));
+            continue;
+        }
+
+        // ── word: keyword if it's in the table, plain text otherwise ──
+        if chars[i].is_alphabetic() || chars[i] == '_' {
+            let start = i;
+            while i < len && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
             }
-            Block::PageBreak => {
-                body.push_str("\\clearpage\n\n");
+            let word: String = chars[start..i].iter().collect();
+            if spec.keywords.contains(&word.as_str()) {
+                flush_plain(&mut plain, &mut out);
+                out.push((CodeTokenKind::Keyword, word));
+            } else {
+                plain.push_str(&word);
             }
-            Block::HRule => {
-                body.push_str("\\noindent{\\color{CorpRule}\\rule{\\textwidth}{0.4pt}}\n\\vspace{2mm}\n\n");
+            continue;
+        }
+
+        plain.push(chars[i]);
+        i += 1;
+    }
+
+    flush_plain(&mut plain, &mut out);
+    out
+}
+
+/// Render a fenced code block to LaTeX. A recognized `lang` gets
+/// token-colored output (reusing the `CorpAccent`/`CorpGray`/`SevLow`
+/// palette from [`latex_preamble`]); anything else falls back to the
+/// original plain `lstlisting` verbatim block, so highlighting can never
+/// break compilation for a language this highlighter doesn't know.
+fn code_block_to_latex(lang: Option<&str>, code: &str, legacy: bool) -> String {
+    let spec = lang.and_then(lang_spec);
+    let Some(spec) = spec else {
+        return format!("\\begin{{lstlisting}}\n{code}\n\\end{{lstlisting}}\n\n");
+    };
+
+    // Every emitted token is run through `latex_escape`, so the output
+    // stays valid LaTeX even for code containing `\`, `{`, `}`, `#`, `$`,
+    // or `%`; spaces are turned into `~` (a literal space, not a macro
+    // argument separator) so indentation survives outside a verbatim
+    // environment.
+    let color_escape = |t: &str| latex_escape(t, legacy).replace(' ', "~");
+
+    let mut out = String::from("{\\ttfamily\\small\n\\begin{flushleft}\n");
+    for line in code.lines() {
+        if line.is_empty() {
+            out.push_str("~\\\\\n");
+            continue;
+        }
+        for (kind, text) in tokenize_code_line(line, &spec) {
+            let escaped = color_escape(&text);
+            match kind {
+                CodeTokenKind::Keyword => out.push_str(&format!("\\textcolor{{CorpAccent}}{{\\textbf{{{escaped}}}}}")),
+                CodeTokenKind::Str => out.push_str(&format!("\\textcolor{{SevLow}}{{{escaped}}}")),
+                CodeTokenKind::Comment => out.push_str(&format!("\\textcolor{{CorpGray}}{{\\textit{{{escaped}}}}}")),
+                CodeTokenKind::Plain => out.push_str(&escaped),
             }
         }
+        out.push_str("\\\\\n");
     }
+    out.push_str("\\end{flushleft}}\n\n");
+    out
+}
 
-    format!(
-        "{PREAMBLE}\n\\begin{{document}}\n\n{body}\\end{{document}}\n",
-        PREAMBLE = latex_preamble(asset),
-        body = body,
-    )
+// ───────────────────────── bibliography ─────────────────────────
+
+/// A sentinel byte sequence (from the Unicode Private Use Area, so it can
+/// never appear in real template text) left by [`spans_to_latex`] at each
+/// `[@key]` citation. [`resolve_citations`] replaces it once the active
+/// [`Bibliography`] is known, which may be several blocks later than the
+/// citation itself (the `#! bibliography` directive can appear anywhere).
+const CITATION_MARKER: &str = "\u{E000}CITE:";
+const CITATION_MARKER_END: char = '\u{E000}';
+
+/// Same trick as [`CITATION_MARKER`], for `[[key]]` reference spans: a
+/// [`ReferenceSet`] may still be gaining entries from `#! ref` directives
+/// after the marker is written, so resolution is deferred until the
+/// containing [`Block::Text`] is rendered.
+const REFERENCE_MARKER: &str = "\u{E002}REF:";
+const REFERENCE_MARKER_END: char = '\u{E002}';
+
+/// Same trick as [`CITATION_MARKER`], for `[^ ... ]` footnote spans: the
+/// body is known up front, but whether it becomes an inline `\footnote`
+/// or an accumulated, numbered endnote depends on the document-wide
+/// `#! footnotes` mode, which may not be seen until a later block.
+const FOOTNOTE_MARKER: &str = "\u{E003}FN:";
+const FOOTNOTE_MARKER_END: char = '\u{E003}';
+
+/// One parsed BibTeX entry. Unrecognized fields are dropped; missing ones
+/// are left empty.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+struct BibEntry {
+    entry_type: String,
+    /// `and`-separated author list, one name per entry, in BibTeX's
+    /// `"Surname, Given Names"` or plain `"Given Names Surname"` form.
+    authors: Vec<String>,
+    title: String,
+    year: String,
+    journal: String,
+    url: String,
 }
 
-/// The LaTeX preamble: document class, packages, colour definitions, and
-/// style settings that produce a professional-looking security report.
-fn latex_preamble(asset: &str) -> String {
-    let escaped_asset = latex_escape(asset);
-    r#"\documentclass[11pt,a4paper]{article}
+/// CSL-like citation style selected by `#! bibliography refs.bib
+/// style=ieee|apa`. Defaults to [`BibStyle::Ieee`] when omitted or
+/// unrecognized.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+enum BibStyle {
+    #[default]
+    Ieee,
+    Apa,
+}
 
-% ── geometry ──
-\usepackage[top=25mm,bottom=30mm,left=25mm,right=25mm]{geometry}
+impl BibStyle {
+    fn parse(s: &str) -> Self {
+        match s.trim().to_lowercase().as_str() {
+            "apa" => BibStyle::Apa,
+            _ => BibStyle::Ieee,
+        }
+    }
+}
 
-% ── encoding & fonts ──
-\usepackage[utf8]{inputenc}
-\usepackage[T1]{fontenc}
-\usepackage[scaled=0.92]{helvet}
-\usepackage{courier}
-\usepackage{microtype}
-\renewcommand{\familydefault}{\sfdefault}
+/// Bibliography state threaded through [`blocks_to_latex`]: the entries
+/// loaded from the `#! bibliography` directive, the active style, and the
+/// set of keys cited so far in first-appearance order (the numbering used
+/// by [`BibStyle::Ieee`] and the ordering of the References section).
+#[derive(Default)]
+struct Bibliography {
+    entries: BTreeMap<String, BibEntry>,
+    style: BibStyle,
+    order: Vec<String>,
+}
 
-% ── packages ──
-\usepackage{xcolor}
-\usepackage{hyperref}
-\usepackage{booktabs}
-\usepackage{tabularx}
-\usepackage{listings}
-\usepackage{parskip}
-\usepackage{fancyhdr}
-\usepackage{graphicx}
-\usepackage{etoolbox}
-\usepackage{colortbl}
-\usepackage{textcomp}
+impl Bibliography {
+    /// Resolve one `[@key]`/`[@key1; @key2]` group to its rendered
+    /// citation text, recording first-appearance order for unseen known
+    /// keys. Unknown keys keep their literal `[@key]` marker — run through
+    /// `escape_unknown` (`latex_escape`/`html_escape`, format-specific
+    /// since `key` is attacker-controlled text, not trusted bib data) —
+    /// and push a warning to stderr rather than failing the build.
+    fn cite(&mut self, keys: &[String], escape_unknown: impl Fn(&str) -> String) -> String {
+        let parts: Vec<String> = keys
+            .iter()
+            .map(|key| {
+                let Some(entry) = self.entries.get(key) else {
+                    eprintln!("pog: report: unknown citation key `@{key}`");
+                    return format!("[@{}]", escape_unknown(key));
+                };
+                let number = match self.order.iter().position(|k| k == key) {
+                    Some(pos) => pos + 1,
+                    None => {
+                        self.order.push(key.clone());
+                        self.order.len()
+                    }
+                };
+                match self.style {
+                    BibStyle::Ieee => number.to_string(),
+                    BibStyle::Apa => format!("{}, {}", author_surname(&entry.authors.first().cloned().unwrap_or_default()), entry.year),
+                }
+            })
+            .collect();
 
-% ── corporate colours ──
-\definecolor{CorpDark}{HTML}{1E293B}
-\definecolor{CorpAccent}{HTML}{334155}
-\definecolor{CorpRule}{HTML}{CBD5E1}
-\definecolor{CorpGray}{HTML}{64748B}
-\definecolor{CodeBg}{HTML}{F1F5F9}
+        match self.style {
+            BibStyle::Ieee => format!("[{}]", parts.join(", ")),
+            BibStyle::Apa => format!("({})", parts.join("; ")),
+        }
+    }
+}
 
-% ── severity colours ──
-\definecolor{SevCritical}{HTML}{991B1B}
-\definecolor{SevHigh}{HTML}{C2410C}
-\definecolor{SevMedium}{HTML}{B45309}
-\definecolor{SevLow}{HTML}{15803D}
-\definecolor{SevInfo}{HTML}{1D4ED8}
+/// `[[key]]` reference state threaded through [`blocks_to_document`]: the
+/// `(text, url)` pairs defined by `#! ref key | text | url` directives,
+/// and the set of keys cited so far in first-citation order — the
+/// numbering used both inline and by the `#! references` section.
+#[derive(Default)]
+struct ReferenceSet {
+    entries: BTreeMap<String, (String, String)>,
+    order: Vec<String>,
+}
 
-% ── hyperlinks ──
-\hypersetup{
-  colorlinks=true,
-  linkcolor=CorpDark,
-  urlcolor=SevInfo,
-  bookmarks=true,
-  bookmarksnumbered=true,
+impl ReferenceSet {
+    /// Look up (and assign, on first sight) the 1-based citation number
+    /// for `key`. Returns `None` for a key with no matching `#! ref`
+    /// directive, so callers can degrade to the raw key instead of
+    /// failing the build.
+    fn number(&mut self, key: &str) -> Option<usize> {
+        if !self.entries.contains_key(key) {
+            eprintln!("pog: report: unknown reference key `[[{key}]]`");
+            return None;
+        }
+        Some(match self.order.iter().position(|k| k == key) {
+            Some(pos) => pos + 1,
+            None => {
+                self.order.push(key.to_string());
+                self.order.len()
+            }
+        })
+    }
 }
 
-% ── listings (code blocks) ──
-\lstset{
-  basicstyle=\small\ttfamily,
-  backgroundcolor=\color{CodeBg},
-  frame=single,
-  rulecolor=\color{CorpRule},
-  framerule=0.4pt,
-  breaklines=true,
-  breakatwhitespace=false,
-  postbreak=\mbox{\textcolor{CorpGray}{$\hookrightarrow$}\space},
-  xleftmargin=6mm,
-  xrightmargin=6mm,
-  aboveskip=8pt,
-  belowskip=8pt,
+/// `[^ ... ]`/reference-style `[^id]` footnote state threaded through
+/// [`blocks_to_document`]: whether `#! footnotes endnotes` selected
+/// endnote mode (see [`Block::FootnoteMode`]), and — only in that mode —
+/// each footnote's rendered body in encounter order, used both for the
+/// running `\textsuperscript`/`<sup>` number and the end-of-document list.
+#[derive(Default)]
+struct Footnotes {
+    endnotes: bool,
+    entries: Vec<String>,
 }
 
-% ── section styling ──
-\makeatletter
-\renewcommand{\section}{%
-  \@startsection{section}{1}{0pt}{-2ex plus -1ex minus -0.2ex}{1.2ex plus 0.2ex}{%
-    \large\bfseries\color{CorpDark}}}
-\makeatother
+/// Split a single BibTeX entry's field list (the text after the citation
+/// key's trailing comma) into individual `name = {value}` fields, honoring
+/// brace nesting so commas inside a value (e.g. in a title) don't split it.
+fn split_bibtex_fields(fields: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut depth = 0i32;
+    let mut buf = String::new();
+    for ch in fields.chars() {
+        match ch {
+            '{' => { depth += 1; buf.push(ch); }
+            '}' => { depth -= 1; buf.push(ch); }
+            ',' if depth <= 0 => {
+                if !buf.trim().is_empty() {
+                    out.push(std::mem::take(&mut buf));
+                } else {
+                    buf.clear();
+                }
+            }
+            _ => buf.push(ch),
+        }
+    }
+    if !buf.trim().is_empty() {
+        out.push(buf);
+    }
+    out
+}
 
-% ── TOC styling ──
-\setcounter{tocdepth}{1}
-\setcounter{secnumdepth}{2}
-\makeatletter
-\renewcommand{\l@section}[2]{%
-  \addpenalty{-\@highpenalty}%
-  \vskip 8pt plus 2pt
-  \setlength\@tempdima{2em}%
-  \begingroup
-    \parindent\z@ \rightskip\@tocrmarg
-    \parfillskip -\rightskip
-    \leavevmode\large\bfseries\color{CorpDark}
-    #1\nobreak
-    \leaders\hbox{$\m@th\mkern 4mu\cdot\mkern 4mu$}\hfill
-    \nobreak\hb@xt@\@pnumwidth{\hss #2}%
-    \par
-  \endgroup
-  \penalty\@highpenalty}
-\renewcommand{\l@subsection}[2]{%
-  \vskip 2pt
-  \setlength\@tempdima{3em}%
-  \begingroup
-    \parindent 1.5em \rightskip\@tocrmarg
-    \parfillskip -\rightskip
-    \leavevmode\normalsize\color{CorpAccent}
-    #1\nobreak
-    \leaders\hbox{$\m@th\mkern 4mu\cdot\mkern 4mu$}\hfill
-    \nobreak\hb@xt@\@pnumwidth{\hss #2}%
-    \par
-  \endgroup}
-\makeatother
+/// Strip a single layer of `{...}` or `"..."` delimiters from a BibTeX
+/// field value.
+fn strip_bib_delimiters(value: &str) -> String {
+    let value = value.trim();
+    if value.len() >= 2 && ((value.starts_with('{') && value.ends_with('}')) || (value.starts_with('"') && value.ends_with('"'))) {
+        value[1..value.len() - 1].to_string()
+    } else {
+        value.to_string()
+    }
+}
 
-% ── breakable inline code ──
-\makeatletter
-\newcommand{\code}[1]{{%
-  \ttfamily\hyphenpenalty=10000\exhyphenpenalty=10000
-  \@code@loop#1\@nil
-}}
-\def\@code@loop{\@ifnextchar\@nil{\@gobble}{\@code@char}}
-\def\@code@char#1{#1\discretionary{}{}{}\@code@loop}
-\makeatother
+/// Parse a `.bib` file's contents into entries keyed by citation key.
+///
+/// Recognizes entries of the form `@type{key, field = {value}, ...}`.
+/// Field names are matched case-insensitively; only `author`, `title`,
+/// `year`, `journal`, and `url` are kept. `author` is split on `" and "`
+/// into individual names.
+fn parse_bibtex(text: &str) -> BTreeMap<String, BibEntry> {
+    let mut entries = BTreeMap::new();
+    let mut rest = text;
+
+    while let Some(at) = rest.find('@') {
+        rest = &rest[at + 1..];
+        let Some(open_brace) = rest.find('{') else { break };
+        let entry_type = rest[..open_brace].trim().to_lowercase();
+        rest = &rest[open_brace + 1..];
+
+        let mut depth = 1;
+        let mut close = None;
+        for (i, ch) in rest.char_indices() {
+            match ch {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        close = Some(i);
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        let Some(close) = close else { break };
+        let body = &rest[..close];
+        rest = &rest[close + 1..];
 
-% ── headers / footers ──
-\pagestyle{fancy}
-\fancyhf{}
-\renewcommand{\headrulewidth}{0.4pt}
-\renewcommand{\headrule}{\hbox to\headwidth{\color{CorpRule}\leaders\hrule height \headrulewidth\hfill}}
-\fancyhead[L]{\small\color{CorpGray}\textit{Security Assessment Report -- %%ASSET%%}}
-\fancyhead[R]{\small\color{CorpGray}\thepage}
-\fancyfoot[C]{}
-\renewcommand{\footrulewidth}{0pt}
-"#
-    .replace("%%ASSET%%", &escaped_asset)
+        let Some(comma) = body.find(',') else { continue };
+        let key = body[..comma].trim().to_string();
+        if key.is_empty() {
+            continue;
+        }
+
+        let mut entry = BibEntry { entry_type, ..Default::default() };
+        for field in split_bibtex_fields(&body[comma + 1..]) {
+            let Some(eq) = field.find('=') else { continue };
+            let name = field[..eq].trim().to_lowercase();
+            let value = strip_bib_delimiters(field[eq + 1..].trim());
+            match name.as_str() {
+                "author" => entry.authors = value.split(" and ").map(|a| a.trim().to_string()).collect(),
+                "title" => entry.title = value,
+                "year" => entry.year = value,
+                "journal" => entry.journal = value,
+                "url" => entry.url = value,
+                _ => {}
+            }
+        }
+        entries.insert(key, entry);
+    }
+
+    entries
 }
 
-// ───────────────────────── PDF compilation ─────────────────────────
+/// Parse a `.ris` export into entries keyed by citation key, normalized
+/// into the same [`BibEntry`] shape `parse_bibtex` produces.
+///
+/// Recognizes tagged lines of the form `XX  - value`, one record per
+/// `TY  - ...` / `ER  -` pair. `AU` repeats for multiple authors; `PY`/`Y1`
+/// supply the year and `T1`/`TI` the title. RIS has no native citation key,
+/// so `ID` is used when present, else one is derived from the first
+/// author's surname and year (e.g. `smith2021`), falling back to `ref1`,
+/// `ref2`, ... for records with neither.
+fn parse_ris(text: &str) -> BTreeMap<String, BibEntry> {
+    let mut entries = BTreeMap::new();
+    let mut entry = BibEntry::default();
+    let mut id: Option<String> = None;
+    let mut seen_any = false;
+    let mut untitled = 0;
+
+    let flush = |entries: &mut BTreeMap<String, BibEntry>, entry: BibEntry, id: Option<String>, untitled: &mut usize| {
+        let key = id.unwrap_or_else(|| {
+            let surname = entry.authors.first().map(|a| author_surname(a)).filter(|s| !s.is_empty());
+            match surname {
+                Some(surname) if !entry.year.is_empty() => format!("{}{}", surname.to_lowercase(), entry.year),
+                Some(surname) => surname.to_lowercase(),
+                None => {
+                    *untitled += 1;
+                    format!("ref{untitled}")
+                }
+            }
+        });
+        entries.insert(key, entry);
+    };
 
-/// Compile the LaTeX source to PDF using the embedded tectonic engine
-/// and write the result to `output_path`.  No external TeX installation
-/// is required.
-fn render_pdf(latex_src: &str, output_path: &str, work_dir: &Path) -> Result<()> {
-    use tectonic::config::PersistentConfig;
-    use tectonic::driver::{OutputFormat, ProcessingSessionBuilder};
-    use tectonic::status::NoopStatusBackend;
+    for line in text.lines() {
+        let line = line.trim_end();
+        let Some((tag, value)) = line.split_once("  -") else { continue };
+        let tag = tag.trim();
+        let value = value.trim();
+        match tag {
+            "TY" => { seen_any = true; entry.entry_type = value.to_lowercase(); }
+            "AU" | "A1" => entry.authors.push(value.to_string()),
+            "TI" | "T1" => entry.title = value.to_string(),
+            "PY" | "Y1" => entry.year = value.split('/').next().unwrap_or(value).to_string(),
+            "UR" | "UL" => entry.url = value.to_string(),
+            "JO" | "JF" | "T2" => entry.journal = value.to_string(),
+            "ID" => id = Some(value.to_string()),
+            "ER" => {
+                if seen_any {
+                    flush(&mut entries, std::mem::take(&mut entry), id.take(), &mut untitled);
+                }
+                seen_any = false;
+            }
+            _ => {}
+        }
+    }
+    if seen_any {
+        flush(&mut entries, entry, id, &mut untitled);
+    }
 
-    let mut status = NoopStatusBackend::default();
+    entries
+}
 
-    let config = PersistentConfig::open(false).map_err(|e| {
-        StorageError::PdfError(format!("tectonic configuration error: {e}"))
-    })?;
+/// Split a BibTeX author name — either `"Given Names Surname"` or BibTeX's
+/// own `"Surname, Given Names"` — into `(surname, initials)`, e.g.
+/// `("Author", "A.")`.
+fn split_author_name(name: &str) -> (String, String) {
+    let name = name.trim();
+    let (last, first_names) = match name.split_once(',') {
+        Some((last, first)) => (last.trim().to_string(), first.trim().to_string()),
+        None => match name.rsplit_once(' ') {
+            Some((rest, last)) => (last.to_string(), rest.to_string()),
+            None => (name.to_string(), String::new()),
+        },
+    };
+    let initials = first_names
+        .split_whitespace()
+        .filter_map(|p| p.chars().next())
+        .map(|c| format!("{c}."))
+        .collect::<Vec<_>>()
+        .join(" ");
+    (last, initials)
+}
 
-    let bundle = config.default_bundle(false, &mut status).map_err(|e| {
-        StorageError::PdfError(format!("tectonic bundle error: {e}"))
-    })?;
+/// Surname alone, for APA inline citations, e.g. `(Author, 2021)`.
+fn author_surname(name: &str) -> String {
+    split_author_name(name).0
+}
 
-    let format_cache_path = config.format_cache_path().map_err(|e| {
-        StorageError::PdfError(format!("tectonic format cache error: {e}"))
-    })?;
+/// IEEE's initials-then-surname author format, e.g. `"A. Author"`.
+fn ieee_author_name(name: &str) -> String {
+    let (last, initials) = split_author_name(name);
+    if initials.is_empty() { last } else { format!("{initials} {last}") }
+}
 
-    let mut sb = ProcessingSessionBuilder::default();
-    sb.bundle(bundle)
-        .primary_input_buffer(latex_src.as_bytes())
-        .tex_input_name("texput.tex")
-        .format_name("latex")
-        .format_cache_path(format_cache_path)
-        .keep_logs(false)
-        .keep_intermediates(false)
-        .print_stdout(false)
-        .output_format(OutputFormat::Pdf)
-        .filesystem_root(work_dir)
-        .do_not_write_output_files();
+/// APA's surname-then-initials author format, e.g. `"Author, A."`.
+fn apa_author_name(name: &str) -> String {
+    let (last, initials) = split_author_name(name);
+    if initials.is_empty() { last } else { format!("{last}, {initials}") }
+}
 
-    let mut sess = sb.create(&mut status).map_err(|e| {
-        StorageError::PdfError(format!("tectonic LaTeX compilation failed: {e}"))
-    })?;
+/// Replace every [`CITATION_MARKER`] left by [`spans_to_latex`]/
+/// [`spans_to_html`] in `text` with its resolved citation text, via `bib`.
+/// `escape_unknown` HTML/TeX-escapes an unknown key's fallback text —
+/// format-specific, since `latex_escape`/`html_escape`/`md_to_latex`/
+/// `md_to_html` have already scrubbed any *forged* marker out of ordinary
+/// text by the time this runs (see their `\u{E000}`/etc. handling), but a
+/// genuinely unknown key is still attacker-controlled and needs escaping
+/// for whichever backend is rendering it.
+fn resolve_citations(text: &str, bib: &mut Bibliography, escape_unknown: impl Fn(&str) -> String) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(CITATION_MARKER) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start + CITATION_MARKER.len()..];
+        let Some(end) = rest.find(CITATION_MARKER_END) else { break };
+        let keys: Vec<String> = rest[..end].split(',').map(|k| k.to_string()).collect();
+        out.push_str(&bib.cite(&keys, &escape_unknown));
+        rest = &rest[end + CITATION_MARKER_END.len_utf8()..];
+    }
+    out.push_str(rest);
+    out
+}
 
-    sess.run(&mut status).map_err(|e| {
-        StorageError::PdfError(format!("tectonic LaTeX compilation failed: {e}"))
-    })?;
+/// Replace every [`REFERENCE_MARKER`] left by [`spans_to_latex`] with a
+/// superscript `\hyperref` back to its `#! references` entry, via `refs`.
+/// A key with no matching `#! ref` directive degrades to its raw
+/// (escaped) text rather than failing the build. `key` comes from
+/// `try_parse_reference`'s `[[key]]` parsing of untrusted finding
+/// description text, so it's run through `latex_escape` before being
+/// interpolated anywhere — including into the `ref:{key}` label, which is
+/// why [`render_reference_list`] escapes the label it defines the exact
+/// same way, so the two still match for known keys.
+fn resolve_references_latex(text: &str, refs: &mut ReferenceSet, legacy: bool) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(REFERENCE_MARKER) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start + REFERENCE_MARKER.len()..];
+        let Some(end) = rest.find(REFERENCE_MARKER_END) else { break };
+        let key = &rest[..end];
+        let escaped_key = latex_escape(key, legacy);
+        out.push_str(&match refs.number(key) {
+            Some(n) => format!("\\textsuperscript{{\\hyperref[ref:{escaped_key}]{{[{n}]}}}}"),
+            None => escaped_key,
+        });
+        rest = &rest[end + REFERENCE_MARKER_END.len_utf8()..];
+    }
+    out.push_str(rest);
+    out
+}
 
-    let mut files = sess.into_file_data();
-    let pdf_data = files
-        .remove("texput.pdf")
-        .ok_or_else(|| StorageError::PdfError("tectonic: no PDF output produced".into()))?
-        .data;
+/// Replace every [`FOOTNOTE_MARKER`] left by [`spans_to_latex`] with
+/// either an inline `\footnote{...}` (default) or, in `footnotes.endnotes`
+/// mode, a `\textsuperscript{N}` back-reference — recording the rendered
+/// body in `footnotes.entries` for [`LatexBackend::endnotes`] to emit
+/// later. The body is run through [`parse_inline_spans`]/[`spans_to_latex`]
+/// itself, so a footnote can contain bold/code/links like any other text.
+fn resolve_footnotes_latex(text: &str, footnotes: &mut Footnotes, legacy: bool) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(FOOTNOTE_MARKER) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start + FOOTNOTE_MARKER.len()..];
+        let Some(end) = rest.find(FOOTNOTE_MARKER_END) else { break };
+        let body = spans_to_latex(&parse_inline_spans(&rest[..end]), legacy);
+        if footnotes.endnotes {
+            footnotes.entries.push(body);
+            out.push_str(&format!("\\textsuperscript{{{}}}", footnotes.entries.len()));
+        } else {
+            out.push_str(&format!("\\footnote{{{body}}}"));
+        }
+        rest = &rest[end + FOOTNOTE_MARKER_END.len_utf8()..];
+    }
+    out.push_str(rest);
+    out
+}
 
-    // Ensure output directory exists
-    if let Some(parent) = Path::new(output_path).parent() {
+/// Render the `#! references` section as a numbered, `\label`-ed list so
+/// inline `[[key]]` citations can `\hyperref` back to their entry.
+/// `refs` is `(key, text, url)` in first-citation order; empty when no
+/// key was ever cited.
+fn render_reference_list(refs: &[(String, String, String)], legacy: bool) -> String {
+    if refs.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from("\\section*{References}\n\n\\begin{enumerate}\n");
+    for (key, text, url) in refs {
+        out.push_str(&format!(
+            "\\item\\label{{ref:{}}} \\href{{{}}}{{{}}}\n",
+            latex_escape(key, legacy),
+            latex_escape(url, legacy),
+            latex_escape(text, legacy),
+        ));
+    }
+    out.push_str("\\end{enumerate}\n\n");
+    out
+}
+
+/// Render one IEEE-numeric reference line, e.g.
+/// `[3] A. Author, "Title," *Journal*, 2021.`
+fn format_ieee_reference(number: usize, entry: &BibEntry, legacy: bool) -> String {
+    let authors: Vec<String> = entry.authors.iter().map(|a| ieee_author_name(a)).collect();
+    format!(
+        "[{number}] {}, \"{},\" \\textit{{{}}}, {}.\\\\\n",
+        latex_escape(&authors.join(", "), legacy),
+        latex_escape(&entry.title, legacy),
+        latex_escape(&entry.journal, legacy),
+        latex_escape(&entry.year, legacy),
+    )
+}
+
+/// Render one APA author-year reference line, e.g.
+/// `Author, A. (2021). Title. *Journal*.`
+fn format_apa_reference(entry: &BibEntry, legacy: bool) -> String {
+    let authors: Vec<String> = entry.authors.iter().map(|a| apa_author_name(a)).collect();
+    format!(
+        "{} ({}). {}. \\textit{{{}}}.\\\\\n",
+        latex_escape(&authors.join(", "), legacy),
+        latex_escape(&entry.year, legacy),
+        latex_escape(&entry.title, legacy),
+        latex_escape(&entry.journal, legacy),
+    )
+}
+
+/// Render a References section for `refs` (in citation order) under the
+/// given `style`. Empty when `refs` is empty.
+fn render_references(refs: &[(String, BibEntry)], style: BibStyle, legacy: bool) -> String {
+    if refs.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from("\\section*{References}\n\n");
+    for (i, (_, entry)) in refs.iter().enumerate() {
+        out.push_str(&match style {
+            BibStyle::Ieee => format_ieee_reference(i + 1, entry, legacy),
+            BibStyle::Apa => format_apa_reference(entry, legacy),
+        });
+    }
+    out.push('\n');
+    out
+}
+
+// ───────────────────────── report backend abstraction ─────────────────────────
+
+/// One output format's rendering of each [`Block`] kind. [`blocks_to_document`]
+/// walks the parsed block stream exactly once and delegates every rendering
+/// block to these methods (directives that only set state, such as
+/// bibliography, `#! ref`, theme, and footnote mode, are handled by the
+/// walker itself) — adding a third output format is implementing this trait,
+/// not re-walking the IR. [`LatexBackend`] and [`HtmlBackend`] are the two
+/// built-in implementations, used by [`blocks_to_latex`]/[`blocks_to_html`].
+trait ReportBackend {
+    fn title(&mut self, text: &str) -> String;
+    fn subtitle(&mut self, text: &str) -> String;
+    fn section(&mut self, text: &str) -> String;
+    fn finding(&mut self, severity: &str, heading: &str) -> String;
+    fn meta(&mut self, key: &str, val: &str) -> String;
+    fn table(&mut self, aligns: &[ColAlign], rows: &[Vec<String>]) -> String;
+    /// Render markdown `text`, with `[@key]` citation markers resolved via
+    /// `bib`, `[[key]]` reference markers resolved via `refs`, and
+    /// `[^ ... ]` footnote markers resolved via `footnotes`.
+    fn text(&mut self, text: &str, bib: &mut Bibliography, refs: &mut ReferenceSet, footnotes: &mut Footnotes) -> String;
+    /// A table of contents / index placeholder.
+    fn index(&mut self) -> String;
+    fn spacer(&mut self, mm: f32) -> String;
+    fn page_break(&mut self) -> String;
+    fn hrule(&mut self) -> String;
+    /// A raw `#! latex ...` block. LaTeX emits it verbatim, since that's
+    /// its native language; HTML has no use for raw LaTeX source, so it's
+    /// the escape hatch's HTML-side counterpart — see each impl.
+    fn latex_or_html_passthrough(&mut self, raw: &str) -> String;
+    fn references(&mut self, refs: &[(String, BibEntry)], style: BibStyle) -> String;
+    /// A `#! references` block: the numbered, back-linked `[[key]]`
+    /// reference list, given as `(key, text, url)` in first-citation
+    /// order.
+    fn reference_list(&mut self, refs: &[(String, String, String)]) -> String;
+    /// The `#! footnotes endnotes` end-of-document list: each footnote's
+    /// already-rendered body (from [`resolve_footnotes_latex`]/
+    /// [`resolve_footnotes_html`]), in encounter order. Empty — and so a
+    /// no-op — when endnote mode is off or no footnote was ever seen.
+    fn endnotes(&mut self, notes: &[String]) -> String;
+    /// A `#! chart pie|bar` severity/category breakdown, given as
+    /// `(label, count)` pairs.
+    fn chart(&mut self, kind: ChartKind, data: &[(String, f64)]) -> String;
+    /// Wrap the fully-rendered `body` into a complete document.
+    fn finish(self, body: String, asset: &str) -> String;
+}
+
+/// One output format's rendering of each inline [`MdSpan`] kind — the
+/// span-level counterpart to [`ReportBackend`], implemented by the same
+/// [`LatexBackend`]/[`HtmlBackend`] types. `MdSpan::Citation`/`Reference`/
+/// `Footnote` aren't covered here: each needs marker-based deferred
+/// resolution (see [`CITATION_MARKER`]/[`REFERENCE_MARKER`]/
+/// [`FOOTNOTE_MARKER`]) once document-wide state (the bibliography, the
+/// reference set, or the footnotes mode) is known, which isn't always the
+/// case yet when `spans_to_latex`/`spans_to_html` walk the spans — so
+/// they emit the marker directly instead of dispatching through a method
+/// that would need that same state passed in regardless.
+trait SpanRenderer {
+    fn plain(&self, text: &str) -> String;
+    fn bold(&self, text: &str) -> String;
+    fn italic(&self, text: &str) -> String;
+    fn bold_italic(&self, text: &str) -> String;
+    fn code(&self, text: &str) -> String;
+    fn link(&self, display: &str, url: &str) -> String;
+    fn image(&self, alt: &str, path: &str) -> String;
+}
+
+/// Walk `blocks` once, delegating each block kind to `backend`, and thread
+/// a single [`Bibliography`] and [`ReferenceSet`] through so `[@key]`/
+/// `[[key]]` citations resolve the same way regardless of output format
+/// (`Bibliography::cite`/`ReferenceSet::number` return plain data, not
+/// format-specific markup).
+fn blocks_to_document(blocks: &[Block], asset: &str, mut backend: impl ReportBackend) -> String {
+    let mut body = String::new();
+    let mut bib = Bibliography::default();
+    let mut refs = ReferenceSet::default();
+    let mut footnotes = Footnotes::default();
+    let mut explicit_references = false;
+
+    for block in blocks {
+        match block {
+            Block::Title(t) => body.push_str(&backend.title(t)),
+            Block::Subtitle(t) => body.push_str(&backend.subtitle(t)),
+            Block::Section(t) => body.push_str(&backend.section(t)),
+            Block::Finding(sev, heading) => body.push_str(&backend.finding(sev, heading)),
+            Block::Meta(key, val) => body.push_str(&backend.meta(key, val)),
+            Block::Table(aligns, rows) => body.push_str(&backend.table(aligns, rows)),
+            Block::Latex(raw) => body.push_str(&backend.latex_or_html_passthrough(raw)),
+            Block::Text(t) => body.push_str(&backend.text(t, &mut bib, &mut refs, &mut footnotes)),
+            Block::Index => body.push_str(&backend.index()),
+            Block::Spacer(mm) => body.push_str(&backend.spacer(*mm)),
+            Block::PageBreak => body.push_str(&backend.page_break()),
+            Block::HRule => body.push_str(&backend.hrule()),
+            Block::Bibliography(path, style) => {
+                bib.style = *style;
+                match fs::read_to_string(path) {
+                    Ok(content) => {
+                        bib.entries = if Path::new(path).extension().and_then(|e| e.to_str()) == Some("ris") {
+                            parse_ris(&content)
+                        } else {
+                            parse_bibtex(&content)
+                        };
+                    }
+                    Err(e) => eprintln!("pog: report: could not read bibliography `{path}`: {e}"),
+                }
+            }
+            Block::References(bib_refs, style) => {
+                explicit_references = true;
+                body.push_str(&backend.references(bib_refs, *style));
+            }
+            Block::Chart(kind, data) => body.push_str(&backend.chart(*kind, data)),
+            Block::Reference(key, text, url) => {
+                refs.entries.insert(key.clone(), (text.clone(), url.clone()));
+            }
+            Block::ReferenceList => {
+                let cited: Vec<(String, String, String)> = refs.order
+                    .iter()
+                    .filter_map(|key| refs.entries.get(key).map(|(text, url)| (key.clone(), text.clone(), url.clone())))
+                    .collect();
+                body.push_str(&backend.reference_list(&cited));
+            }
+            // Already resolved up front by `blocks_to_latex` (the theme
+            // is document-wide, not something to re-apply mid-stream);
+            // the HTML backend has no use for a LaTeX palette at all.
+            Block::Theme(_) => {}
+            Block::FootnoteMode(endnotes) => footnotes.endnotes = *endnotes,
+        }
+    }
+
+    // Auto-generated References section for whatever citations resolved
+    // above — unless the template already rendered one itself via an
+    // explicit `Block::References`.
+    if !explicit_references && !bib.order.is_empty() {
+        let refs: Vec<(String, BibEntry)> = bib.order
+            .iter()
+            .filter_map(|key| bib.entries.get(key).map(|entry| (key.clone(), entry.clone())))
+            .collect();
+        body.push_str(&backend.references(&refs, bib.style));
+    }
+
+    // Auto-generated Notes section for whatever footnotes accumulated
+    // above in endnote mode; inline mode never populates `entries`, so
+    // this is a no-op then.
+    if !footnotes.entries.is_empty() {
+        body.push_str(&backend.endnotes(&footnotes.entries));
+    }
+
+    backend.finish(body, asset)
+}
+
+/// Convert the parsed blocks into a complete LaTeX document string.
+///
+/// `legacy_escape` selects the `inputenc`/`fontenc` preamble and the old
+/// Unicode-transliteration table in [`latex_escape`] instead of the default
+/// `fontspec` setup — see [`generate_report`].
+fn blocks_to_latex(blocks: &[Block], asset: &str, legacy_escape: bool) -> String {
+    // The theme is document-wide, so resolve the first `#! theme`
+    // directive (if any) up front rather than re-resolving it on every
+    // `Block::Theme` the generic walker sees — see `Block::Theme`'s arm
+    // in `blocks_to_document`.
+    let theme = blocks
+        .iter()
+        .find_map(|b| match b { Block::Theme(name) => Some(Theme::named(name)), _ => None })
+        .unwrap_or_default();
+    blocks_to_document(blocks, asset, LatexBackend::new(legacy_escape, theme))
+}
+
+/// [`ReportBackend`] that lowers the block stream to LaTeX — the original
+/// (and still default) report output, compiled to PDF via tectonic.
+struct LatexBackend {
+    /// Suppresses the `\clearpage` a [`Block::Finding`] would otherwise
+    /// start with right after a `\section`, which already starts its own
+    /// page. Mirrors every other untouched-on-most-blocks field in the
+    /// original single-function walker this was factored out of.
+    after_section: bool,
+    /// Mirrors the `legacy_latex_escape` flag on [`generate_report`]:
+    /// `inputenc`/`fontenc` + transliteration when `true`, `fontspec` +
+    /// verbatim Unicode passthrough when `false`.
+    legacy_escape: bool,
+    /// Palette/fonts/geometry/branding — see [`Theme`].
+    theme: Theme,
+}
+
+impl LatexBackend {
+    fn new(legacy_escape: bool, theme: Theme) -> Self {
+        Self { after_section: false, legacy_escape, theme }
+    }
+}
+
+impl ReportBackend for LatexBackend {
+    fn title(&mut self, t: &str) -> String {
+        format!(
+            "\\thispagestyle{{empty}}\n\
+             \\vspace*{{40mm}}\n\
+             \\begin{{center}}\n\
+             {{\\color{{CorpDark}}\\rule{{0.6\\textwidth}}{{2pt}}}}\\\\[6mm]\n\
+             {{\\Huge\\bfseries\\color{{CorpDark}} {}}}\\\\[6mm]\n\
+             {{\\color{{CorpDark}}\\rule{{0.6\\textwidth}}{{2pt}}}}\n\
+             \\end{{center}}\n\
+             \\vspace{{10mm}}\n\n",
+            latex_escape(t, self.legacy_escape),
+        )
+    }
+
+    fn subtitle(&mut self, t: &str) -> String {
+        format!(
+            "\\begin{{center}}\n\
+             {{\\Large\\color{{CorpGray}} {}}}\n\
+             \\end{{center}}\n\
+             \\vspace{{4mm}}\n\n",
+            latex_escape(t, self.legacy_escape),
+        )
+    }
+
+    fn section(&mut self, t: &str) -> String {
+        self.after_section = true;
+        format!("\\section{{{}}}\n\n", latex_escape(t, self.legacy_escape))
+    }
+
+    fn finding(&mut self, sev: &str, heading: &str) -> String {
+        let color = severity_latex_color(sev);
+        let mut out = String::new();
+        if !self.after_section {
+            out.push_str("\\clearpage\n");
+        }
+        self.after_section = false;
+        out.push_str(&format!(
+            "\\noindent\\colorbox{{{}!10}}{{\\parbox{{\\dimexpr\\textwidth-2\\fboxsep}}{{%\n\
+               \\large\\bfseries\\color{{CorpDark}} {}\n\
+               \\hfill {{\\normalsize\\colorbox{{{}}}{{\\color{{white}}\\textbf{{\\,{}\\,}}}}}}\n\
+             }}}}\n\
+             \\vspace{{0.5mm}}\n\
+             {{\\noindent\\color{{{}}}\\rule{{\\textwidth}}{{1.5pt}}}}\n\
+             \\nopagebreak\n\
+             \\vspace{{1mm}}\n\n",
+            color,
+            latex_escape(heading, self.legacy_escape),
+            color,
+            latex_escape(sev, self.legacy_escape),
+            color,
+        ));
+        out
+    }
+
+    fn meta(&mut self, key: &str, val: &str) -> String {
+        self.after_section = false;
+        format!(
+            "\\noindent{{\\color{{CorpGray}}\\textbf{{{}:}}}} {}\\par\\vspace{{-0.3\\parskip}}\n",
+            latex_escape(key, self.legacy_escape),
+            latex_escape(val, self.legacy_escape),
+        )
+    }
+
+    fn table(&mut self, aligns: &[ColAlign], rows: &[Vec<String>]) -> String {
+        if rows.is_empty() {
+            return String::new();
+        }
+        let mut body = String::new();
+        let ncols = rows[0].len();
+        // First column is fixed width, the rest expand (`X`); each column's
+        // token is swapped for its separator-row alignment, when given.
+        let col_spec = (0..ncols)
+            .map(|i| match (i == 0, aligns.get(i)) {
+                (true, Some(ColAlign::Center)) => "c".to_string(),
+                (true, Some(ColAlign::Right)) => "r".to_string(),
+                (true, _) => "l".to_string(),
+                (false, Some(ColAlign::Left)) => r">{\raggedright\arraybackslash}X".to_string(),
+                (false, Some(ColAlign::Center)) => r">{\centering\arraybackslash}X".to_string(),
+                (false, Some(ColAlign::Right)) => r">{\raggedleft\arraybackslash}X".to_string(),
+                (false, None) => "X".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        // Increase row height for better readability
+        body.push_str("{\\renewcommand{\\arraystretch}{1.35}\n");
+        body.push_str(&format!(
+            "\\noindent\n\\begin{{tabularx}}{{\\textwidth}}{{{}}}\n\\toprule\n",
+            col_spec.trim(),
+        ));
+        // header row
+        if let Some(header) = rows.first() {
+            let cells: Vec<String> =
+                header.iter().map(|c| format!("\\textbf{{\\color{{CorpDark}}{}}}", latex_escape(c, self.legacy_escape))).collect();
+            body.push_str("\\rowcolor{CorpRule!30}\n");
+            body.push_str(&cells.join(" & "));
+            body.push_str(" \\\\\n\\midrule\n");
+        }
+        // data rows (with alternating background)
+        for (idx, row) in rows.iter().skip(1).enumerate() {
+            if idx % 2 == 1 {
+                body.push_str("\\rowcolor{CodeBg}\n");
+            }
+            let cells: Vec<String> = row.iter().map(|c| latex_escape(c, self.legacy_escape)).collect();
+            body.push_str(&cells.join(" & "));
+            body.push_str(" \\\\\n");
+        }
+        body.push_str("\\bottomrule\n\\end{tabularx}\n}\n\\vspace{4mm}\n\n");
+        body
+    }
+
+    fn text(&mut self, t: &str, bib: &mut Bibliography, refs: &mut ReferenceSet, footnotes: &mut Footnotes) -> String {
+        self.after_section = false;
+        let legacy = self.legacy_escape;
+        let rendered = resolve_citations(&md_to_latex(t, legacy), bib, |k| latex_escape(k, legacy));
+        let rendered = resolve_references_latex(&rendered, refs, legacy);
+        resolve_footnotes_latex(&rendered, footnotes, self.legacy_escape)
+    }
+
+    fn index(&mut self) -> String {
+        "\\tableofcontents\n\\vspace{6mm}\n\n".to_string()
+    }
+
+    fn spacer(&mut self, mm: f32) -> String {
+        format!("\\vspace{{{}mm}}\n\n", mm)
+    }
+
+    fn page_break(&mut self) -> String {
+        "\\clearpage\n\n".to_string()
+    }
+
+    fn hrule(&mut self) -> String {
+        "\\noindent{\\color{CorpRule}\\rule{\\textwidth}{0.4pt}}\n\\vspace{2mm}\n\n".to_string()
+    }
+
+    fn latex_or_html_passthrough(&mut self, raw: &str) -> String {
+        self.after_section = false;
+        format!("{raw}\n\n")
+    }
+
+    fn references(&mut self, refs: &[(String, BibEntry)], style: BibStyle) -> String {
+        render_references(refs, style, self.legacy_escape)
+    }
+
+    fn reference_list(&mut self, refs: &[(String, String, String)]) -> String {
+        render_reference_list(refs, self.legacy_escape)
+    }
+
+    fn endnotes(&mut self, notes: &[String]) -> String {
+        if notes.is_empty() {
+            return String::new();
+        }
+        let mut out = String::from("\\section*{Notes}\n\n\\begin{enumerate}\n");
+        for note in notes {
+            out.push_str(&format!("  \\item {note}\n"));
+        }
+        out.push_str("\\end{enumerate}\n\n");
+        out
+    }
+
+    fn chart(&mut self, kind: ChartKind, data: &[(String, f64)]) -> String {
+        self.after_section = false;
+        match kind {
+            ChartKind::Pie => latex_pie_chart(data, self.legacy_escape),
+            ChartKind::Bar => latex_bar_chart(data, self.legacy_escape),
+        }
+    }
+
+    fn finish(self, body: String, asset: &str) -> String {
+        format!(
+            "{PREAMBLE}\n\\begin{{document}}\n\n{body}\\end{{document}}\n",
+            PREAMBLE = latex_preamble(asset, self.legacy_escape, &self.theme),
+            body = body,
+        )
+    }
+}
+
+impl SpanRenderer for LatexBackend {
+    fn plain(&self, text: &str) -> String {
+        latex_escape(text, self.legacy_escape)
+    }
+
+    fn bold(&self, text: &str) -> String {
+        format!(r"\textbf{{{}}}", latex_escape(text, self.legacy_escape))
+    }
+
+    fn italic(&self, text: &str) -> String {
+        format!(r"\textit{{{}}}", latex_escape(text, self.legacy_escape))
+    }
+
+    fn bold_italic(&self, text: &str) -> String {
+        format!(r"\textbf{{\textit{{{}}}}}", latex_escape(text, self.legacy_escape))
+    }
+
+    fn code(&self, text: &str) -> String {
+        format!(r"\code{{{}}}", latex_escape(text, self.legacy_escape))
+    }
+
+    fn link(&self, display: &str, url: &str) -> String {
+        format!(
+            r"\href{{{}}}{{{}}}",
+            latex_escape(url, self.legacy_escape),
+            latex_escape(display, self.legacy_escape),
+        )
+    }
+
+    fn image(&self, alt: &str, path: &str) -> String {
+        if !is_safe_image_path(path) {
+            return String::new();
+        }
+        let mut out = String::from("\n\n\\begin{center}\n");
+        out.push_str(&format!("\\IfFileExists{{{}}}{{", path));
+        out.push_str(&format!("\\includegraphics[width=0.9\\linewidth]{{{}}}\\\\[2mm]\n", path));
+        if !alt.is_empty() {
+            out.push_str(&format!("{{\\small\\color{{CorpGray}}\\textit{{{}}}}}\n", latex_escape(alt, self.legacy_escape)));
+        }
+        out.push_str("}{}");
+        out.push_str("\\end{center}\n\n");
+        out
+    }
+}
+
+// ───────────────────────── report theme ─────────────────────────
+
+/// On-disk theme overrides (TOML/JSON, loaded via `#! theme <path>`).
+/// Every field is optional so a user config can override just the colors
+/// or branding it cares about — mirrors `tui::theme::ThemeConfig`'s
+/// partial-override shape, but for the report palette/fonts/branding
+/// instead of terminal styles.
+#[derive(Clone, Debug, Default, Deserialize)]
+struct ThemeConfig {
+    dark: Option<String>,
+    accent: Option<String>,
+    rule: Option<String>,
+    gray: Option<String>,
+    code_bg: Option<String>,
+    critical: Option<String>,
+    high: Option<String>,
+    medium: Option<String>,
+    low: Option<String>,
+    info: Option<String>,
+    font: Option<String>,
+    mono_font: Option<String>,
+    geometry: Option<String>,
+    header: Option<String>,
+    footer: Option<String>,
+    logo: Option<String>,
+}
+
+/// A report's visual identity — palette, fonts, page geometry, and
+/// header/footer/logo branding — interpolated into [`latex_preamble`] so
+/// different clients can be reskinned without patching source. Selected
+/// with `#! theme <name>` for a built-in theme or `#! theme <path>` to
+/// load one from a TOML/JSON file ([`Theme::named`]).
+#[derive(Clone, Debug, PartialEq)]
+struct Theme {
+    /// Hex (no `#`) xcolor value for `CorpDark`: headings, rules, title text.
+    dark: String,
+    /// Hex value for `CorpAccent`: subsection/TOC accents.
+    accent: String,
+    /// Hex value for `CorpRule`: horizontal rules and table borders.
+    rule: String,
+    /// Hex value for `CorpGray`: header/footer and secondary text.
+    gray: String,
+    /// Hex value for `CodeBg`: code block and alternating-row background.
+    code_bg: String,
+    critical: String,
+    high: String,
+    medium: String,
+    low: String,
+    info: String,
+    /// Main body `fontspec` family name.
+    font: String,
+    /// Monospace `fontspec` family name, used for code.
+    mono_font: String,
+    /// Raw `geometry` package options, e.g. `top=25mm,bottom=30mm,...`.
+    geometry: String,
+    /// Running header text; `%%ASSET%%` is interpolated with the report's asset.
+    header: String,
+    /// Running footer text. Empty by default (no footer).
+    footer: String,
+    /// Optional logo image path, shown in the running header when the
+    /// file exists at compile time (`\IfFileExists`).
+    logo: Option<String>,
+}
+
+impl Default for Theme {
+    /// The original hard-coded corporate palette, A4 geometry, and Noto
+    /// Sans fonts — unchanged output for documents with no `#! theme`.
+    fn default() -> Self {
+        Self {
+            dark: "1E293B".into(),
+            accent: "334155".into(),
+            rule: "CBD5E1".into(),
+            gray: "64748B".into(),
+            code_bg: "F1F5F9".into(),
+            critical: "991B1B".into(),
+            high: "C2410C".into(),
+            medium: "B45309".into(),
+            low: "15803D".into(),
+            info: "1D4ED8".into(),
+            font: "Noto Sans".into(),
+            mono_font: "Noto Sans Mono".into(),
+            geometry: "top=25mm,bottom=30mm,left=25mm,right=25mm".into(),
+            header: "Security Assessment Report -- %%ASSET%%".into(),
+            footer: String::new(),
+            logo: None,
+        }
+    }
+}
+
+impl Theme {
+    /// Apply a partial `config` on top of `self`, field by field: any
+    /// field `config` sets wins, everything else keeps `self`'s value.
+    fn extend(mut self, config: ThemeConfig) -> Self {
+        if let Some(v) = config.dark { self.dark = v; }
+        if let Some(v) = config.accent { self.accent = v; }
+        if let Some(v) = config.rule { self.rule = v; }
+        if let Some(v) = config.gray { self.gray = v; }
+        if let Some(v) = config.code_bg { self.code_bg = v; }
+        if let Some(v) = config.critical { self.critical = v; }
+        if let Some(v) = config.high { self.high = v; }
+        if let Some(v) = config.medium { self.medium = v; }
+        if let Some(v) = config.low { self.low = v; }
+        if let Some(v) = config.info { self.info = v; }
+        if let Some(v) = config.font { self.font = v; }
+        if let Some(v) = config.mono_font { self.mono_font = v; }
+        if let Some(v) = config.geometry { self.geometry = v; }
+        if let Some(v) = config.header { self.header = v; }
+        if let Some(v) = config.footer { self.footer = v; }
+        if config.logo.is_some() { self.logo = config.logo; }
+        self
+    }
+
+    /// Resolve a `#! theme` argument: a handful of built-in names, or
+    /// else a TOML/JSON file path layered over [`Theme::default`]. An
+    /// unknown name or unreadable/unparsable file falls back to the
+    /// default theme rather than failing the build.
+    fn named(name: &str) -> Self {
+        match name {
+            "default" | "" => Theme::default(),
+            "dark" => Theme::default().extend(ThemeConfig {
+                dark: Some("0F172A".into()),
+                accent: Some("1E293B".into()),
+                rule: Some("334155".into()),
+                gray: Some("94A3B8".into()),
+                code_bg: Some("1E293B".into()),
+                ..Default::default()
+            }),
+            "mono" => Theme::default().extend(ThemeConfig {
+                critical: Some("000000".into()),
+                high: Some("262626".into()),
+                medium: Some("4D4D4D".into()),
+                low: Some("737373".into()),
+                info: Some("999999".into()),
+                ..Default::default()
+            }),
+            path => match read_theme_config(Path::new(path)) {
+                Some(config) => Theme::default().extend(config),
+                None => {
+                    eprintln!("pog: report: could not load theme `{path}`, using default");
+                    Theme::default()
+                }
+            },
+        }
+    }
+}
+
+/// Read a `#! theme <path>` file, TOML or JSON by extension (defaulting
+/// to TOML), into a [`ThemeConfig`]. `None` on any read or parse error.
+fn read_theme_config(path: &Path) -> Option<ThemeConfig> {
+    let raw = fs::read_to_string(path).ok()?;
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::from_str(&raw).ok(),
+        _ => toml::from_str(&raw).ok(),
+    }
+}
+
+/// The LaTeX preamble: document class, packages, colour definitions, and
+/// style settings that produce a professional-looking security report.
+///
+/// `legacy` loads the old `inputenc`/`fontenc`/`helvet`/`courier` setup for
+/// pdfLaTeX-style compilation, matching [`latex_escape`]'s transliteration
+/// table. Otherwise it loads `fontspec` with bundled Unicode fonts, since
+/// [`render_pdf`] drives tectonic, a XeTeX engine — non-Latin text then
+/// renders natively without needing a substitution table at all.
+///
+/// `theme` supplies the palette, fonts, geometry, and header/footer/logo
+/// branding — see [`Theme`].
+fn latex_preamble(asset: &str, legacy: bool, theme: &Theme) -> String {
+    let escaped_asset = latex_escape(asset, legacy);
+    let fonts = if legacy {
+        "\\usepackage[utf8]{inputenc}\n\
+         \\usepackage[T1]{fontenc}\n\
+         \\usepackage[scaled=0.92]{helvet}\n\
+         \\usepackage{courier}\n\
+         \\usepackage{microtype}\n\
+         \\renewcommand{\\familydefault}{\\sfdefault}\n".to_string()
+    } else {
+        format!(
+            "\\usepackage{{fontspec}}\n\
+             \\usepackage{{microtype}}\n\
+             \\setmainfont{{{}}}\n\
+             \\setmonofont{{{}}}\n",
+            theme.font, theme.mono_font,
+        )
+    };
+    let logo = match &theme.logo {
+        Some(path) => format!("\\IfFileExists{{{path}}}{{\\includegraphics[height=8mm]{{{path}}}\\hspace{{2mm}}}}{{}}"),
+        None => String::new(),
+    };
+
+    let preamble = r#"\documentclass[11pt,a4paper]{article}
+
+% ── geometry ──
+\usepackage[%%GEOMETRY%%]{geometry}
+
+% ── encoding & fonts ──
+%%FONTS%%
+
+% ── packages ──
+\usepackage{xcolor}
+\usepackage{hyperref}
+\usepackage{booktabs}
+\usepackage{tabularx}
+\usepackage{listings}
+\usepackage{parskip}
+\usepackage{fancyhdr}
+\usepackage{graphicx}
+\usepackage{etoolbox}
+\usepackage{colortbl}
+\usepackage{textcomp}
+\usepackage{tikz}
+\usepackage{pgf-pie}
+
+% ── corporate colours ──
+\definecolor{CorpDark}{HTML}{%%DARK%%}
+\definecolor{CorpAccent}{HTML}{%%ACCENT%%}
+\definecolor{CorpRule}{HTML}{%%RULE%%}
+\definecolor{CorpGray}{HTML}{%%GRAY%%}
+\definecolor{CodeBg}{HTML}{%%CODE_BG%%}
+
+% ── severity colours ──
+\definecolor{SevCritical}{HTML}{%%CRITICAL%%}
+\definecolor{SevHigh}{HTML}{%%HIGH%%}
+\definecolor{SevMedium}{HTML}{%%MEDIUM%%}
+\definecolor{SevLow}{HTML}{%%LOW%%}
+\definecolor{SevInfo}{HTML}{%%INFO%%}
+
+% ── hyperlinks ──
+\hypersetup{
+  colorlinks=true,
+  linkcolor=CorpDark,
+  urlcolor=SevInfo,
+  bookmarks=true,
+  bookmarksnumbered=true,
+}
+
+% ── listings (code blocks) ──
+\lstset{
+  basicstyle=\small\ttfamily,
+  backgroundcolor=\color{CodeBg},
+  frame=single,
+  rulecolor=\color{CorpRule},
+  framerule=0.4pt,
+  breaklines=true,
+  breakatwhitespace=false,
+  postbreak=\mbox{\textcolor{CorpGray}{$\hookrightarrow$}\space},
+  xleftmargin=6mm,
+  xrightmargin=6mm,
+  aboveskip=8pt,
+  belowskip=8pt,
+}
+
+% ── section styling ──
+\makeatletter
+\renewcommand{\section}{%
+  \@startsection{section}{1}{0pt}{-2ex plus -1ex minus -0.2ex}{1.2ex plus 0.2ex}{%
+    \large\bfseries\color{CorpDark}}}
+\makeatother
+
+% ── TOC styling ──
+\setcounter{tocdepth}{1}
+\setcounter{secnumdepth}{2}
+\makeatletter
+\renewcommand{\l@section}[2]{%
+  \addpenalty{-\@highpenalty}%
+  \vskip 8pt plus 2pt
+  \setlength\@tempdima{2em}%
+  \begingroup
+    \parindent\z@ \rightskip\@tocrmarg
+    \parfillskip -\rightskip
+    \leavevmode\large\bfseries\color{CorpDark}
+    #1\nobreak
+    \leaders\hbox{$\m@th\mkern 4mu\cdot\mkern 4mu$}\hfill
+    \nobreak\hb@xt@\@pnumwidth{\hss #2}%
+    \par
+  \endgroup
+  \penalty\@highpenalty}
+\renewcommand{\l@subsection}[2]{%
+  \vskip 2pt
+  \setlength\@tempdima{3em}%
+  \begingroup
+    \parindent 1.5em \rightskip\@tocrmarg
+    \parfillskip -\rightskip
+    \leavevmode\normalsize\color{CorpAccent}
+    #1\nobreak
+    \leaders\hbox{$\m@th\mkern 4mu\cdot\mkern 4mu$}\hfill
+    \nobreak\hb@xt@\@pnumwidth{\hss #2}%
+    \par
+  \endgroup}
+\makeatother
+
+% ── breakable inline code ──
+\makeatletter
+\newcommand{\code}[1]{{%
+  \ttfamily\hyphenpenalty=10000\exhyphenpenalty=10000
+  \@code@loop#1\@nil
+}}
+\def\@code@loop{\@ifnextchar\@nil{\@gobble}{\@code@char}}
+\def\@code@char#1{#1\discretionary{}{}{}\@code@loop}
+\makeatother
+
+% ── headers / footers ──
+\pagestyle{fancy}
+\fancyhf{}
+\renewcommand{\headrulewidth}{0.4pt}
+\renewcommand{\headrule}{\hbox to\headwidth{\color{CorpRule}\leaders\hrule height \headrulewidth\hfill}}
+\fancyhead[L]{%%LOGO%%\small\color{CorpGray}\textit{%%HEADER%%}}
+\fancyhead[R]{\small\color{CorpGray}\thepage}
+\fancyfoot[C]{\small\color{CorpGray}\textit{%%FOOTER%%}}
+\renewcommand{\footrulewidth}{0pt}
+"#;
+    preamble
+        .replace("%%FONTS%%", &fonts)
+        .replace("%%GEOMETRY%%", &theme.geometry)
+        .replace("%%DARK%%", &theme.dark)
+        .replace("%%ACCENT%%", &theme.accent)
+        .replace("%%RULE%%", &theme.rule)
+        .replace("%%GRAY%%", &theme.gray)
+        .replace("%%CODE_BG%%", &theme.code_bg)
+        .replace("%%CRITICAL%%", &theme.critical)
+        .replace("%%HIGH%%", &theme.high)
+        .replace("%%MEDIUM%%", &theme.medium)
+        .replace("%%LOW%%", &theme.low)
+        .replace("%%INFO%%", &theme.info)
+        .replace("%%LOGO%%", &logo)
+        .replace("%%HEADER%%", &theme.header)
+        .replace("%%FOOTER%%", &theme.footer)
+        .replace("%%ASSET%%", &escaped_asset)
+}
+
+// ───────────────────────── HTML helpers ─────────────────────────
+
+/// Convert the parsed blocks into a complete, self-contained HTML document
+/// (inline `<style>`, base64-inlined images — no external files needed to
+/// view the report). `images_dir` is the work directory images were
+/// already copied/renamed into by [`prepare_finding_images`].
+fn blocks_to_html(blocks: &[Block], asset: &str, images_dir: &Path) -> String {
+    blocks_to_document(blocks, asset, HtmlBackend::new(images_dir))
+}
+
+/// Escape characters that are special in HTML.
+fn html_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            // Same scrub as `latex_escape` — these are the sentinel
+            // codepoints `resolve_citations`/`resolve_references_html`/
+            // `resolve_footnotes_html`/`INDEX_MARKER` search the rendered
+            // text for; left unescaped they'd let a finding field forge a
+            // marker that was never actually emitted by a real citation,
+            // reference, footnote, or index span.
+            '\u{E000}' | '\u{E001}' | '\u{E002}' | '\u{E003}' => out.push('\u{FFFD}'),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Turn arbitrary text into a lowercase, hyphen-separated HTML `id`, e.g.
+/// for anchor-linking the index to its sections.
+fn html_slug(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last_was_hyphen = true; // suppress a leading hyphen
+    for ch in text.to_lowercase().chars() {
+        if ch.is_ascii_alphanumeric() {
+            out.push(ch);
+            last_was_hyphen = false;
+        } else if !last_was_hyphen {
+            out.push('-');
+            last_was_hyphen = true;
+        }
+    }
+    if out.ends_with('-') {
+        out.pop();
+    }
+    out
+}
+
+/// Convert a severity label to an HTML/CSS class name sharing the LaTeX
+/// palette's severity colours (see [`latex_preamble`]'s `Sev*` colours).
+fn severity_html_class(sev: &str) -> &str {
+    match sev.to_lowercase().as_str() {
+        "critical" => "sev-critical",
+        "high" => "sev-high",
+        "medium" => "sev-medium",
+        "low" => "sev-low",
+        "info" => "sev-info",
+        _ => "sev-default",
+    }
+}
+
+/// Fallback colors for `Block::Chart` labels that aren't a known severity,
+/// cycled by position — the HTML-side counterpart to the LaTeX backend's
+/// `CHART_NEUTRAL_PALETTE`, using the same hex values.
+const HTML_CHART_NEUTRAL_PALETTE: &[&str] = &["#334155", "#64748B", "#1E293B", "#CBD5E1"];
+
+/// Resolve a chart label to a hex color sharing the LaTeX `Sev*` palette,
+/// falling back to a neutral color cycled by `idx` for non-severity labels.
+fn chart_hex_color(label: &str, idx: usize) -> &'static str {
+    match label.to_lowercase().as_str() {
+        "critical" => "#991B1B",
+        "high" => "#C2410C",
+        "medium" => "#B45309",
+        "low" => "#15803D",
+        "info" => "#1D4ED8",
+        _ => HTML_CHART_NEUTRAL_PALETTE[idx % HTML_CHART_NEUTRAL_PALETTE.len()],
+    }
+}
+
+/// Render a `Block::Chart` as a horizontal bar list, each bar's width
+/// scaled to the largest count. HTML has no pie-slice primitive without
+/// extra JS, so `pie` and `bar` charts render identically here — the
+/// kinds only diverge on the LaTeX side (`pgf-pie` vs hand-drawn `tikz`).
+fn html_chart(data: &[(String, f64)]) -> String {
+    let max = data.iter().map(|(_, n)| *n).fold(0.0_f64, f64::max);
+    if max <= 0.0 {
+        return String::new();
+    }
+
+    let mut out = String::from("<div class=\"chart\">\n");
+    for (i, (label, n)) in data.iter().enumerate() {
+        let pct = (n / max) * 100.0;
+        out.push_str(&format!(
+            "<div class=\"chart-row\"><span class=\"chart-label\">{}</span><div class=\"chart-bar\" style=\"width:{:.1}%;background:{}\"></div><span class=\"chart-value\">{}</span></div>\n",
+            html_escape(label),
+            pct,
+            chart_hex_color(label, i),
+            n,
+        ));
+    }
+    out.push_str("</div>\n");
+    out
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// A hand-rolled base64 encoder (RFC 4648, with `=` padding) so inlining
+/// images as `data:` URIs doesn't require pulling in a new crate — see
+/// `models/src/dates.rs` for this repo's precedent of hand-rolling small,
+/// self-contained utilities instead of taking a dependency for them.
+fn base64_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = *chunk.get(1).unwrap_or(&0);
+        let b2 = *chunk.get(2).unwrap_or(&0);
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            BASE64_ALPHABET[(((b1 & 0x0F) << 2) | (b2 >> 6)) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            BASE64_ALPHABET[(b2 & 0x3F) as usize] as char
+        } else {
+            '='
+        });
+    }
+    out
+}
+
+/// Guess a `data:` URI MIME type from an image filename's extension.
+fn mime_type_for(path: &str) -> &str {
+    match Path::new(path).extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase().as_str() {
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        _ => "image/jpeg",
+    }
+}
+
+/// `path` comes straight from `![alt](path)` markdown in an untrusted,
+/// scanner-imported finding description — [`rewrite_description_images`]
+/// only rewrites it when it matches a real attached image by basename, so
+/// anything else (including a path an attacker invented outright) reaches
+/// here and [`LatexBackend::image`] unchanged. Reject it outright rather
+/// than letting it escape the images directory or break out of a LaTeX
+/// command argument: no absolute path, no `..` traversal component, and
+/// none of the characters (`{`, `}`, `\`) that are special to the LaTeX
+/// commands [`LatexBackend::image`] interpolates it into.
+fn is_safe_image_path(path: &str) -> bool {
+    !path.is_empty()
+        && !Path::new(path).is_absolute()
+        && !path.split(['/', '\\']).any(|component| component == "..")
+        && !path.chars().any(|c| matches!(c, '{' | '}' | '\\' | '\0'))
+}
+
+/// Read `path` from `images_dir` and return it as an inline `data:` URI, or
+/// `None` if `path` fails [`is_safe_image_path`], escapes `images_dir`
+/// once resolved (defense in depth against a symlink inside it), or can't
+/// be read (e.g. the template references an image that was never
+/// attached to a finding).
+fn image_data_uri(images_dir: &Path, path: &str) -> Option<String> {
+    if !is_safe_image_path(path) {
+        return None;
+    }
+    let full = images_dir.join(path);
+    let canon_dir = images_dir.canonicalize().ok()?;
+    let canon_full = full.canonicalize().ok()?;
+    if !canon_full.starts_with(&canon_dir) {
+        return None;
+    }
+    let bytes = fs::read(&full).ok()?;
+    Some(format!("data:{};base64,{}", mime_type_for(path), base64_encode(&bytes)))
+}
+
+/// Render a slice of [`MdSpan`]s to HTML inline markup.
+fn spans_to_html(spans: &[MdSpan], images_dir: &Path) -> String {
+    let renderer = HtmlBackend::new(images_dir);
+    let mut out = String::new();
+    for s in spans {
+        match s {
+            MdSpan::Plain(t) => out.push_str(&renderer.plain(t)),
+            MdSpan::Bold(t) => out.push_str(&renderer.bold(t)),
+            MdSpan::Italic(t) => out.push_str(&renderer.italic(t)),
+            MdSpan::BoldItalic(t) => out.push_str(&renderer.bold_italic(t)),
+            MdSpan::Code(t) => out.push_str(&renderer.code(t)),
+            MdSpan::Link(display, url) => out.push_str(&renderer.link(display, url)),
+            MdSpan::Image(alt, path) => out.push_str(&renderer.image(alt, path)),
+            MdSpan::Citation(keys) => {
+                out.push_str(CITATION_MARKER);
+                out.push_str(&keys.join(","));
+                out.push(CITATION_MARKER_END);
+            }
+            MdSpan::Reference(key) => {
+                out.push_str(REFERENCE_MARKER);
+                out.push_str(key);
+                out.push(REFERENCE_MARKER_END);
+            }
+            MdSpan::Footnote(body) => {
+                out.push_str(FOOTNOTE_MARKER);
+                out.push_str(body);
+                out.push(FOOTNOTE_MARKER_END);
+            }
+        }
+    }
+    out
+}
+
+/// Render markdown text to HTML markup (block-level).
+fn html_list_open(kind: ListKind, start: u32) -> String {
+    match kind {
+        ListKind::Bullet => "<ul>\n".to_string(),
+        ListKind::Ordered if start != 1 => format!("<ol start=\"{start}\">\n"),
+        ListKind::Ordered => "<ol>\n".to_string(),
+    }
+}
+
+fn html_list_close(kind: ListKind) -> String {
+    match kind {
+        ListKind::Bullet => "</ul>\n".to_string(),
+        ListKind::Ordered => "</ol>\n".to_string(),
+    }
+}
+
+/// CSS `text-align` value for a GFM table column's alignment.
+fn align_css(align: ColAlign) -> &'static str {
+    match align {
+        ColAlign::Left => "left",
+        ColAlign::Center => "center",
+        ColAlign::Right => "right",
+    }
+}
+
+/// Render a GFM table as an HTML `<table>`, with row 0 of `rows` as the
+/// header and per-column alignment from `aligns` applied via inline
+/// `text-align` styles.
+fn md_table_to_html(aligns: &[ColAlign], rows: &[Vec<Vec<MdSpan>>], images_dir: &Path) -> String {
+    let mut out = String::from("<table>\n");
+    for (i, row) in rows.iter().enumerate() {
+        out.push_str("<tr>");
+        let tag = if i == 0 { "th" } else { "td" };
+        for (col, cell) in row.iter().enumerate() {
+            let align = aligns.get(col).copied().unwrap_or(ColAlign::Left);
+            out.push_str(&format!(
+                "<{tag} style=\"text-align:{}\">{}</{tag}>",
+                align_css(align),
+                spans_to_html(cell, images_dir),
+            ));
+        }
+        out.push_str("</tr>\n");
+    }
+    out.push_str("</table>\n");
+    out
+}
+
+fn render_md_blocks_to_html(blocks: &[MdBlock], images_dir: &Path) -> String {
+    let mut out = String::new();
+    let mut lists: Vec<ListKind> = Vec::new();
+
+    for mb in blocks {
+        match mb {
+            MdBlock::Paragraph(spans) => {
+                close_all_lists(&mut lists, &mut out, html_list_close);
+                out.push_str(&format!("<p>{}</p>\n", spans_to_html(spans, images_dir)));
+            }
+            MdBlock::Heading(level, spans) => {
+                close_all_lists(&mut lists, &mut out, html_list_close);
+                let tag = match level {
+                    1 => "h3",
+                    2 => "h4",
+                    _ => "h5",
+                };
+                out.push_str(&format!("<{tag}>{}</{tag}>\n", spans_to_html(spans, images_dir)));
+            }
+            MdBlock::BulletItem(depth, spans) => {
+                sync_list_stack(&mut lists, *depth, ListKind::Bullet, 1, &mut out, html_list_open, html_list_close);
+                out.push_str(&format!("<li>{}</li>\n", spans_to_html(spans, images_dir)));
+            }
+            MdBlock::OrderedItem(n, depth, spans) => {
+                sync_list_stack(&mut lists, *depth, ListKind::Ordered, *n, &mut out, html_list_open, html_list_close);
+                out.push_str(&format!("<li>{}</li>\n", spans_to_html(spans, images_dir)));
+            }
+            MdBlock::TaskItem(depth, checked, spans) => {
+                sync_list_stack(&mut lists, *depth, ListKind::Bullet, 1, &mut out, html_list_open, html_list_close);
+                let checked_attr = if *checked { " checked" } else { "" };
+                out.push_str(&format!(
+                    "<li><input type=\"checkbox\" disabled{checked_attr}> {}</li>\n",
+                    spans_to_html(spans, images_dir),
+                ));
+            }
+            MdBlock::CodeBlock { lang, code } => {
+                close_all_lists(&mut lists, &mut out, html_list_close);
+                out.push_str(&code_block_to_html(lang.as_deref(), code));
+            }
+            MdBlock::BlockQuote(children) => {
+                close_all_lists(&mut lists, &mut out, html_list_close);
+                out.push_str("<blockquote>\n");
+                out.push_str(&render_md_blocks_to_html(children, images_dir));
+                out.push_str("</blockquote>\n");
+            }
+            MdBlock::Table(aligns, rows) => {
+                close_all_lists(&mut lists, &mut out, html_list_close);
+                out.push_str(&md_table_to_html(aligns, rows, images_dir));
+            }
+        }
+    }
+
+    close_all_lists(&mut lists, &mut out, html_list_close);
+    out
+}
+
+fn md_to_html(text: &str, images_dir: &Path) -> String {
+    render_md_blocks_to_html(&parse_markdown(text), images_dir)
+}
+
+/// Render a fenced code block to HTML. A recognized `lang` wraps each
+/// token in a `<span class="tok-*">` (styled via [`html_preamble_css`]'s
+/// `.tok-*` rules, sharing the LaTeX output's palette); anything else
+/// falls back to a plain escaped `<pre><code>` block.
+fn code_block_to_html(lang: Option<&str>, code: &str) -> String {
+    let spec = lang.and_then(lang_spec);
+    let Some(spec) = spec else {
+        return format!("<pre><code>{}</code></pre>\n", html_escape(code));
+    };
+
+    let class = lang.map(|l| format!(" class=\"language-{}\"", html_escape(l))).unwrap_or_default();
+    let mut out = format!("<pre><code{class}>");
+    for (i, line) in code.lines().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        for (kind, text) in tokenize_code_line(line, &spec) {
+            let escaped = html_escape(&text);
+            match kind {
+                CodeTokenKind::Keyword => out.push_str(&format!("<span class=\"tok-kw\">{escaped}</span>")),
+                CodeTokenKind::Str => out.push_str(&format!("<span class=\"tok-str\">{escaped}</span>")),
+                CodeTokenKind::Comment => out.push_str(&format!("<span class=\"tok-cm\">{escaped}</span>")),
+                CodeTokenKind::Plain => out.push_str(&escaped),
+            }
+        }
+    }
+    out.push_str("</code></pre>\n");
+    out
+}
+
+/// Render one IEEE-numeric reference line as an HTML list item, e.g.
+/// `[3] A. Author, "Title," <em>Journal</em>, 2021.`
+fn format_ieee_reference_html(number: usize, entry: &BibEntry) -> String {
+    let authors: Vec<String> = entry.authors.iter().map(|a| ieee_author_name(a)).collect();
+    format!(
+        "<li id=\"ref-{number}\">[{number}] {}, &ldquo;{},&rdquo; <em>{}</em>, {}.</li>\n",
+        html_escape(&authors.join(", ")),
+        html_escape(&entry.title),
+        html_escape(&entry.journal),
+        html_escape(&entry.year),
+    )
+}
+
+/// Render one APA author-year reference line as an HTML list item, e.g.
+/// `Author, A. (2021). Title. <em>Journal</em>.`
+fn format_apa_reference_html(entry: &BibEntry) -> String {
+    let authors: Vec<String> = entry.authors.iter().map(|a| apa_author_name(a)).collect();
+    format!(
+        "<li>{} ({}). {}. <em>{}</em>.</li>\n",
+        html_escape(&authors.join(", ")),
+        html_escape(&entry.year),
+        html_escape(&entry.title),
+        html_escape(&entry.journal),
+    )
+}
+
+/// Render a References section for `refs` (in citation order) under the
+/// given `style`, as an HTML `<ol>`. Empty when `refs` is empty.
+fn render_references_html(refs: &[(String, BibEntry)], style: BibStyle) -> String {
+    if refs.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from("<section class=\"references\"><h2>References</h2><ol>\n");
+    for (i, (_, entry)) in refs.iter().enumerate() {
+        out.push_str(&match style {
+            BibStyle::Ieee => format_ieee_reference_html(i + 1, entry),
+            BibStyle::Apa => format_apa_reference_html(entry),
+        });
+    }
+    out.push_str("</ol></section>\n");
+    out
+}
+
+/// Replace every [`REFERENCE_MARKER`] left by [`spans_to_html`] with a
+/// superscript link back to its `#! references` entry, via `refs`. A key
+/// with no matching `#! ref` directive degrades to its raw (escaped)
+/// text rather than failing the build.
+fn resolve_references_html(text: &str, refs: &mut ReferenceSet) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(REFERENCE_MARKER) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start + REFERENCE_MARKER.len()..];
+        let Some(end) = rest.find(REFERENCE_MARKER_END) else { break };
+        let key = &rest[..end];
+        out.push_str(&match refs.number(key) {
+            Some(n) => format!("<sup><a href=\"#ref-{}\">[{n}]</a></sup>", html_escape(key)),
+            None => html_escape(key),
+        });
+        rest = &rest[end + REFERENCE_MARKER_END.len_utf8()..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Replace every [`FOOTNOTE_MARKER`] left by [`spans_to_html`] with either
+/// an inline superscript aside (default) or, in `footnotes.endnotes`
+/// mode, a numbered `<sup>` link — recording the rendered body in
+/// `footnotes.entries` for [`HtmlBackend::endnotes`] to emit later. See
+/// [`resolve_footnotes_latex`] for the LaTeX counterpart.
+fn resolve_footnotes_html(text: &str, footnotes: &mut Footnotes, images_dir: &Path) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(FOOTNOTE_MARKER) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start + FOOTNOTE_MARKER.len()..];
+        let Some(end) = rest.find(FOOTNOTE_MARKER_END) else { break };
+        let body = spans_to_html(&parse_inline_spans(&rest[..end]), images_dir);
+        if footnotes.endnotes {
+            footnotes.entries.push(body);
+            let n = footnotes.entries.len();
+            out.push_str(&format!("<sup id=\"fnref{n}\"><a href=\"#fn{n}\">{n}</a></sup>"));
+        } else {
+            out.push_str(&format!("<sup class=\"footnote\">{body}</sup>"));
+        }
+        rest = &rest[end + FOOTNOTE_MARKER_END.len_utf8()..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Render the `#! references` section as a numbered `<ol>`, each `<li>`
+/// anchored so inline `[[key]]` citations can link back to it. `refs` is
+/// `(key, text, url)` in first-citation order; empty when no key was
+/// ever cited.
+fn render_reference_list_html(refs: &[(String, String, String)]) -> String {
+    if refs.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from("<section class=\"references\"><h2>References</h2><ol>\n");
+    for (key, text, url) in refs {
+        out.push_str(&format!(
+            "<li id=\"ref-{}\"><a href=\"{}\">{}</a></li>\n",
+            html_escape(key),
+            html_escape(url),
+            html_escape(text),
+        ));
+    }
+    out.push_str("</ol></section>\n");
+    out
+}
+
+/// A sentinel left in the body by [`HtmlBackend::index`] at the point a
+/// `#! index` directive appeared, resolved once every section heading has
+/// been seen (sections can come after the index that lists them).
+const INDEX_MARKER: &str = "\u{E001}TOC\u{E001}";
+
+/// [`ReportBackend`] that lowers the block stream to a self-contained HTML
+/// document: inline `<style>`, base64-inlined images, no external files.
+struct HtmlBackend<'a> {
+    images_dir: &'a Path,
+    /// `(slug, heading text)` for every `#! section` seen, used to resolve
+    /// [`INDEX_MARKER`] once the whole document has been walked.
+    sections: Vec<(String, String)>,
+}
+
+impl<'a> HtmlBackend<'a> {
+    fn new(images_dir: &'a Path) -> Self {
+        Self { images_dir, sections: Vec::new() }
+    }
+}
+
+impl ReportBackend for HtmlBackend<'_> {
+    fn title(&mut self, t: &str) -> String {
+        format!("<header class=\"report-title\"><h1>{}</h1></header>\n", html_escape(t))
+    }
+
+    fn subtitle(&mut self, t: &str) -> String {
+        format!("<p class=\"report-subtitle\">{}</p>\n", html_escape(t))
+    }
+
+    fn section(&mut self, t: &str) -> String {
+        let base_slug = html_slug(t);
+        let mut slug = base_slug.clone();
+        let mut n = 2;
+        while self.sections.iter().any(|(s, _)| *s == slug) {
+            slug = format!("{base_slug}-{n}");
+            n += 1;
+        }
+        self.sections.push((slug.clone(), t.to_string()));
+        format!("<h2 id=\"{}\">{}</h2>\n", slug, html_escape(t))
+    }
+
+    fn finding(&mut self, sev: &str, heading: &str) -> String {
+        format!(
+            "<div class=\"finding {}\">\n  <div class=\"finding-header\"><span class=\"finding-title\">{}</span><span class=\"badge\">{}</span></div>\n",
+            severity_html_class(sev),
+            html_escape(heading),
+            html_escape(sev),
+        )
+    }
+
+    fn meta(&mut self, key: &str, val: &str) -> String {
+        format!(
+            "<p class=\"meta\"><span class=\"meta-key\">{}:</span> {}</p>\n",
+            html_escape(key),
+            html_escape(val),
+        )
+    }
+
+    fn table(&mut self, aligns: &[ColAlign], rows: &[Vec<String>]) -> String {
+        if rows.is_empty() {
+            return String::new();
+        }
+        let cell_attr = |col: usize| match aligns.get(col) {
+            Some(align) => format!(" style=\"text-align:{}\"", align_css(*align)),
+            None => String::new(),
+        };
+        let mut out = String::from("<table>\n");
+        if let Some(header) = rows.first() {
+            out.push_str("<thead><tr>");
+            for (col, cell) in header.iter().enumerate() {
+                out.push_str(&format!("<th{}>{}</th>", cell_attr(col), html_escape(cell)));
+            }
+            out.push_str("</tr></thead>\n<tbody>\n");
+        }
+        for row in rows.iter().skip(1) {
+            out.push_str("<tr>");
+            for (col, cell) in row.iter().enumerate() {
+                out.push_str(&format!("<td{}>{}</td>", cell_attr(col), html_escape(cell)));
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</tbody>\n</table>\n");
+        out
+    }
+
+    fn text(&mut self, t: &str, bib: &mut Bibliography, refs: &mut ReferenceSet, footnotes: &mut Footnotes) -> String {
+        let rendered = resolve_citations(&md_to_html(t, self.images_dir), bib, html_escape);
+        let rendered = resolve_references_html(&rendered, refs);
+        resolve_footnotes_html(&rendered, footnotes, self.images_dir)
+    }
+
+    fn index(&mut self) -> String {
+        INDEX_MARKER.to_string()
+    }
+
+    fn spacer(&mut self, mm: f32) -> String {
+        format!("<div class=\"spacer\" style=\"height:{mm}mm\"></div>\n")
+    }
+
+    fn page_break(&mut self) -> String {
+        "<div class=\"page-break\"></div>\n".to_string()
+    }
+
+    fn hrule(&mut self) -> String {
+        "<hr>\n".to_string()
+    }
+
+    fn latex_or_html_passthrough(&mut self, raw: &str) -> String {
+        // Raw `#! latex` blocks have no meaning outside a LaTeX document;
+        // the HTML backend's counterpart to this escape hatch is simply
+        // not to emit anything rather than leak LaTeX source into the page.
+        let _ = raw;
+        String::new()
+    }
+
+    fn references(&mut self, refs: &[(String, BibEntry)], style: BibStyle) -> String {
+        render_references_html(refs, style)
+    }
+
+    fn reference_list(&mut self, refs: &[(String, String, String)]) -> String {
+        render_reference_list_html(refs)
+    }
+
+    fn endnotes(&mut self, notes: &[String]) -> String {
+        if notes.is_empty() {
+            return String::new();
+        }
+        let mut out = String::from("<section class=\"endnotes\"><h2>Notes</h2><ol>\n");
+        for (i, note) in notes.iter().enumerate() {
+            out.push_str(&format!("<li id=\"fn{}\">{note}</li>\n", i + 1));
+        }
+        out.push_str("</ol></section>\n");
+        out
+    }
+
+    fn chart(&mut self, _kind: ChartKind, data: &[(String, f64)]) -> String {
+        html_chart(data)
+    }
+
+    fn finish(self, body: String, asset: &str) -> String {
+        let toc = if self.sections.is_empty() {
+            String::new()
+        } else {
+            let mut toc = String::from("<nav class=\"toc\"><ul>\n");
+            for (slug, heading) in &self.sections {
+                toc.push_str(&format!("<li><a href=\"#{}\">{}</a></li>\n", slug, html_escape(heading)));
+            }
+            toc.push_str("</ul></nav>\n");
+            toc
+        };
+        let body = body.replace(INDEX_MARKER, &toc);
+
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}\n</style>\n</head>\n<body>\n<main>\n{}\n</main>\n</body>\n</html>\n",
+            html_escape(asset),
+            html_preamble_css(),
+            body,
+        )
+    }
+}
+
+impl SpanRenderer for HtmlBackend<'_> {
+    fn plain(&self, text: &str) -> String {
+        html_escape(text)
+    }
+
+    fn bold(&self, text: &str) -> String {
+        format!("<strong>{}</strong>", html_escape(text))
+    }
+
+    fn italic(&self, text: &str) -> String {
+        format!("<em>{}</em>", html_escape(text))
+    }
+
+    fn bold_italic(&self, text: &str) -> String {
+        format!("<strong><em>{}</em></strong>", html_escape(text))
+    }
+
+    fn code(&self, text: &str) -> String {
+        format!("<code>{}</code>", html_escape(text))
+    }
+
+    fn link(&self, display: &str, url: &str) -> String {
+        format!("<a href=\"{}\">{}</a>", html_escape(url), html_escape(display))
+    }
+
+    fn image(&self, alt: &str, path: &str) -> String {
+        let Some(uri) = image_data_uri(self.images_dir, path) else {
+            return String::new();
+        };
+        let mut out = format!("<figure class=\"report-image\"><img src=\"{}\" alt=\"{}\">", uri, html_escape(alt));
+        if !alt.is_empty() {
+            out.push_str(&format!("<figcaption>{}</figcaption>", html_escape(alt)));
+        }
+        out.push_str("</figure>");
+        out
+    }
+}
+
+/// Inline CSS for the HTML report, sharing the LaTeX preamble's corporate
+/// and severity colour palette (see [`latex_preamble`]'s `Corp*`/`Sev*`
+/// colour definitions) so both output formats look like the same brand.
+fn html_preamble_css() -> &'static str {
+    r#"
+body { font-family: -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; color: #1E293B; max-width: 860px; margin: 0 auto; padding: 2rem; line-height: 1.5; }
+h1, h2, h3, h4, h5 { color: #1E293B; }
+.report-title h1 { font-size: 2rem; border-bottom: 2px solid #1E293B; padding-bottom: 0.5rem; }
+.report-subtitle { color: #64748B; font-size: 1.1rem; }
+.meta-key { color: #64748B; font-weight: bold; }
+hr { border: none; border-top: 1px solid #CBD5E1; margin: 1.5rem 0; }
+.toc ul { list-style: none; padding-left: 0; }
+.toc a { color: #1E293B; text-decoration: none; }
+.toc a:hover { text-decoration: underline; }
+table { border-collapse: collapse; width: 100%; margin: 1rem 0; }
+th, td { border-bottom: 1px solid #CBD5E1; padding: 0.5rem; text-align: left; }
+th { background: #CBD5E1; color: #1E293B; }
+tbody tr:nth-child(even) { background: #F1F5F9; }
+code, pre { font-family: "SFMono-Regular", Consolas, monospace; background: #F1F5F9; }
+pre { padding: 0.75rem; overflow-x: auto; border-radius: 4px; }
+code { padding: 0.1rem 0.3rem; border-radius: 3px; }
+.tok-kw { color: #334155; font-weight: bold; }
+.tok-str { color: #15803D; }
+.tok-cm { color: #64748B; font-style: italic; }
+blockquote { border-left: 3px solid #CBD5E1; margin: 1rem 0; padding: 0.25rem 1rem; color: #334155; }
+.chart { margin: 1rem 0; }
+.chart-row { display: flex; align-items: center; gap: 0.5rem; margin: 0.3rem 0; }
+.chart-label { width: 6rem; text-align: right; color: #64748B; }
+.chart-bar { height: 1.1rem; border-radius: 3px; min-width: 2px; }
+.chart-value { color: #1E293B; font-weight: bold; }
+.finding { border: 1px solid #CBD5E1; border-radius: 6px; padding: 1rem; margin: 1.5rem 0; }
+.finding-header { display: flex; justify-content: space-between; align-items: center; font-size: 1.15rem; font-weight: bold; }
+.badge { color: #fff; border-radius: 4px; padding: 0.15rem 0.6rem; font-size: 0.85rem; }
+.sev-critical .badge, .sev-critical { border-color: #991B1B; }
+.sev-critical .badge { background: #991B1B; }
+.sev-high .badge { background: #C2410C; }
+.sev-high { border-color: #C2410C; }
+.sev-medium .badge { background: #B45309; }
+.sev-medium { border-color: #B45309; }
+.sev-low .badge { background: #15803D; }
+.sev-low { border-color: #15803D; }
+.sev-info .badge { background: #1D4ED8; }
+.sev-info { border-color: #1D4ED8; }
+.sev-default .badge { background: #000; }
+.report-image { text-align: center; margin: 1rem 0; }
+.report-image img { max-width: 100%; border-radius: 4px; }
+.report-image figcaption { color: #64748B; font-style: italic; font-size: 0.9rem; margin-top: 0.3rem; }
+.references ol { padding-left: 1.5rem; }
+.endnotes ol { padding-left: 1.5rem; }
+sup.footnote { color: #64748B; cursor: help; }
+.page-break { page-break-after: always; }
+.spacer { width: 100%; }
+"#
+}
+
+// ───────────────────────── PDF compilation ─────────────────────────
+
+/// Compile the LaTeX source to PDF using the embedded tectonic engine
+/// and write the result to `output_path`.  No external TeX installation
+/// is required.
+fn render_pdf(latex_src: &str, output_path: &str, work_dir: &Path) -> Result<()> {
+    use tectonic::config::PersistentConfig;
+    use tectonic::driver::{OutputFormat, ProcessingSessionBuilder};
+    use tectonic::status::NoopStatusBackend;
+
+    let mut status = NoopStatusBackend::default();
+
+    let config = PersistentConfig::open(false).map_err(|e| {
+        StorageError::PdfError(format!("tectonic configuration error: {e}"))
+    })?;
+
+    let bundle = config.default_bundle(false, &mut status).map_err(|e| {
+        StorageError::PdfError(format!("tectonic bundle error: {e}"))
+    })?;
+
+    let format_cache_path = config.format_cache_path().map_err(|e| {
+        StorageError::PdfError(format!("tectonic format cache error: {e}"))
+    })?;
+
+    let mut sb = ProcessingSessionBuilder::default();
+    sb.bundle(bundle)
+        .primary_input_buffer(latex_src.as_bytes())
+        .tex_input_name("texput.tex")
+        .format_name("latex")
+        .format_cache_path(format_cache_path)
+        .keep_logs(false)
+        .keep_intermediates(false)
+        .print_stdout(false)
+        .output_format(OutputFormat::Pdf)
+        .filesystem_root(work_dir)
+        .do_not_write_output_files();
+
+    let mut sess = sb.create(&mut status).map_err(|e| {
+        StorageError::PdfError(format!("tectonic LaTeX compilation failed: {e}"))
+    })?;
+
+    sess.run(&mut status).map_err(|e| {
+        StorageError::PdfError(format!("tectonic LaTeX compilation failed: {e}"))
+    })?;
+
+    let mut files = sess.into_file_data();
+    let pdf_data = files
+        .remove("texput.pdf")
+        .ok_or_else(|| StorageError::PdfError("tectonic: no PDF output produced".into()))?
+        .data;
+
+    // Ensure output directory exists
+    if let Some(parent) = Path::new(output_path).parent() {
         fs::create_dir_all(parent)?;
     }
-    fs::write(output_path, &pdf_data)?;
+    fs::write(output_path, &pdf_data)?;
+
+    Ok(())
+}
+
+// ───────────────────────── date helper ─────────────────────────
+
+/// Current date as `YYYY/MM/DD`.
+fn current_date() -> String {
+    // Extracted from `date +%Y/%m/%d` logic, no chrono dependency.
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    let days = secs / 86400;
+    let mut y = 1970i32;
+    let mut rem = days;
+
+    loop {
+        let ylen: i64 = if is_leap(y) { 366 } else { 365 };
+        if rem < ylen {
+            break;
+        }
+        rem -= ylen;
+        y += 1;
+    }
+
+    let mut m = 1u32;
+    loop {
+        let mlen = month_days(y, m) as i64;
+        if rem < mlen {
+            break;
+        }
+        rem -= mlen;
+        m += 1;
+    }
+    let d = rem as u32 + 1;
+    format!("{y:04}/{m:02}/{d:02}")
+}
+
+fn is_leap(y: i32) -> bool {
+    y % 4 == 0 && (y % 100 != 0 || y % 400 == 0)
+}
+
+fn month_days(y: i32, m: u32) -> u32 {
+    match m {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => if is_leap(y) { 29 } else { 28 },
+        _ => 30,
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+//  Tests
+// ═══════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ── LaTeX escape ──
+
+    #[test]
+    fn latex_escape_basic() {
+        assert_eq!(latex_escape("hello", false), "hello");
+    }
+
+    #[test]
+    fn latex_escape_special_chars() {
+        assert_eq!(latex_escape("a & b", false), r"a \& b");
+        assert_eq!(latex_escape("100%", false), r"100\%");
+        assert_eq!(latex_escape("$x$", false), r"\$x\$");
+        assert_eq!(latex_escape("item #1", false), r"item \#1");
+        assert_eq!(latex_escape("a_b", false), r"a\_b");
+        assert_eq!(latex_escape("{x}", false), r"\{x\}");
+    }
+
+    #[test]
+    fn latex_escape_tilde_caret_backslash() {
+        assert_eq!(latex_escape("~", false), r"\textasciitilde{}");
+        assert_eq!(latex_escape("^", false), r"\textasciicircum{}");
+        assert_eq!(latex_escape(r"\", false), r"\textbackslash{}");
+    }
+
+    #[test]
+    fn latex_escape_default_passes_unicode_through_verbatim() {
+        // With fontspec handling non-ASCII natively, the default (non-legacy)
+        // mode no longer transliterates — it only escapes metacharacters.
+        assert_eq!(latex_escape("29.99\u{20AC}", false), "29.99\u{20AC}");
+        assert_eq!(latex_escape("\u{00AB}caf\u{00E9}\u{00BB}", false), "\u{00AB}caf\u{00E9}\u{00BB}");
+        // CJK, Cyrillic, and Greek all pass through raw too.
+        assert_eq!(latex_escape("\u{4F60}\u{597D}", false), "\u{4F60}\u{597D}"); // 你好
+        assert_eq!(latex_escape("\u{041F}\u{0440}\u{0438}\u{0432}\u{0435}\u{0442}", false), "\u{041F}\u{0440}\u{0438}\u{0432}\u{0435}\u{0442}"); // Привет
+    }
+
+    #[test]
+    fn latex_escape_legacy_transliterates_unicode_chars() {
+        // Dashes
+        assert_eq!(latex_escape("\u{2013}", true), "--");  // en-dash
+        assert_eq!(latex_escape("\u{2014}", true), "---"); // em-dash
+        // Euro and multiplication (the two characters causing rendering bugs)
+        assert_eq!(latex_escape("29.99\u{20AC}", true), r"29.99\texteuro{}");
+        assert_eq!(latex_escape("1.5\u{00D7}", true), r"1.5$\times$");
+        // Quotes
+        assert_eq!(latex_escape("\u{201C}hello\u{201D}", true), "``hello''");
+    }
+
+    // ── severity colour ──
+
+    #[test]
+    fn severity_latex_color_known() {
+        assert_eq!(severity_latex_color("Critical"), "SevCritical");
+        assert_eq!(severity_latex_color("high"), "SevHigh");
+        assert_eq!(severity_latex_color("MEDIUM"), "SevMedium");
+        assert_eq!(severity_latex_color("Low"), "SevLow");
+        assert_eq!(severity_latex_color("Info"), "SevInfo");
+    }
+
+    #[test]
+    fn severity_latex_color_unknown() {
+        assert_eq!(severity_latex_color("banana"), "black");
+    }
+
+    // ── parse_blocks ──
+
+    #[test]
+    fn parse_blocks_title() {
+        let blocks = parse_blocks("#! title My Report");
+        assert_eq!(blocks, vec![Block::Title("My Report".into())]);
+    }
+
+    #[test]
+    fn parse_blocks_subtitle() {
+        let blocks = parse_blocks("#! subtitle target.corp");
+        assert_eq!(blocks, vec![Block::Subtitle("target.corp".into())]);
+    }
+
+    #[test]
+    fn parse_blocks_section() {
+        let blocks = parse_blocks("#! section Executive Summary");
+        assert_eq!(blocks, vec![Block::Section("Executive Summary".into())]);
+    }
+
+    #[test]
+    fn parse_blocks_finding() {
+        let blocks = parse_blocks("#! finding Critical 1. SQL Injection");
+        assert_eq!(
+            blocks,
+            vec![Block::Finding("Critical".into(), "1. SQL Injection".into())]
+        );
+    }
+
+    #[test]
+    fn parse_blocks_meta() {
+        let blocks = parse_blocks("#! meta Prepared for: ACME Corp");
+        assert_eq!(
+            blocks,
+            vec![Block::Meta("Prepared for".into(), "ACME Corp".into())]
+        );
+    }
+
+    #[test]
+    fn parse_blocks_index() {
+        let blocks = parse_blocks("#! index");
+        assert_eq!(blocks, vec![Block::Index]);
+    }
+
+    #[test]
+    fn parse_blocks_spacer() {
+        let blocks = parse_blocks("#! spacer 8");
+        assert_eq!(blocks, vec![Block::Spacer(8.0)]);
+    }
+
+    #[test]
+    fn parse_blocks_pagebreak() {
+        let blocks = parse_blocks("#! pagebreak");
+        assert_eq!(blocks, vec![Block::PageBreak]);
+    }
+
+    #[test]
+    fn parse_blocks_hr() {
+        let blocks = parse_blocks("#! hr");
+        assert_eq!(blocks, vec![Block::HRule]);
+    }
+
+    #[test]
+    fn parse_blocks_comment_ignored() {
+        let blocks = parse_blocks("#! comment This should not appear");
+        assert!(blocks.is_empty());
+    }
+
+    #[test]
+    fn parse_blocks_latex_inline() {
+        let blocks = parse_blocks("#! latex \\vspace{20mm}");
+        assert_eq!(blocks, vec![Block::Latex("\\vspace{20mm}".into())]);
+    }
+
+    #[test]
+    fn parse_blocks_latex_block() {
+        let input = "#! latex\n\\begin{center}\n\\includegraphics{logo.png}\n\\end{center}\n#! endlatex";
+        let blocks = parse_blocks(input);
+        assert_eq!(blocks, vec![Block::Latex("\\begin{center}\n\\includegraphics{logo.png}\n\\end{center}".into())]);
+    }
+
+    #[test]
+    fn parse_blocks_plain_text() {
+        let blocks = parse_blocks("Hello world.");
+        assert_eq!(blocks, vec![Block::Text("Hello world.".into())]);
+    }
+
+    #[test]
+    fn parse_blocks_table() {
+        let input = "Sev | Count\nCritical | 3\nHigh | 5";
+        let blocks = parse_blocks(input);
+        assert_eq!(
+            blocks,
+            vec![Block::Table(vec![], vec![
+                vec!["Sev".into(), "Count".into()],
+                vec!["Critical".into(), "3".into()],
+                vec!["High".into(), "5".into()],
+            ])]
+        );
+    }
+
+    #[test]
+    fn parse_blocks_mixed_sequence() {
+        let input = "\
+#! title Report
+#! spacer 4
+Some text here.
+#! pagebreak
+#! section Details
+";
+        let blocks = parse_blocks(input);
+        assert_eq!(blocks.len(), 5);
+        assert_eq!(blocks[0], Block::Title("Report".into()));
+        assert_eq!(blocks[1], Block::Spacer(4.0));
+        assert_eq!(blocks[2], Block::Text("Some text here.".into()));
+        assert_eq!(blocks[3], Block::PageBreak);
+        assert_eq!(blocks[4], Block::Section("Details".into()));
+    }
+
+    #[test]
+    fn parse_blocks_table_then_text() {
+        let input = "A | B\n1 | 2\nSome paragraph after table.";
+        let blocks = parse_blocks(input);
+        assert_eq!(blocks.len(), 2);
+        assert!(matches!(&blocks[0], Block::Table(_, _)));
+        assert_eq!(blocks[1], Block::Text("Some paragraph after table.".into()));
+    }
+
+    #[test]
+    fn parse_blocks_table_pipe_delimited_markdown() {
+        // Markdown-style pipe tables with leading/trailing pipes and separator.
+        let input = "| A | B | C |\n|---|---|---|\n| 1 | 2 | 3 |\n| x | y | z |";
+        let blocks = parse_blocks(input);
+        assert_eq!(
+            blocks,
+            vec![Block::Table(vec![], vec![
+                vec!["A".into(), "B".into(), "C".into()],
+                vec!["1".into(), "2".into(), "3".into()],
+                vec!["x".into(), "y".into(), "z".into()],
+            ])]
+        );
+    }
+
+    #[test]
+    fn parse_blocks_table_separator_captures_column_alignment() {
+        // The separator row itself contributes no data row, but its colons
+        // (alignment markers) are captured onto the Block::Table.
+        let input = "| A | B |\n|:---|---:|\n| 1 | 2 |";
+        let blocks = parse_blocks(input);
+        assert_eq!(
+            blocks,
+            vec![Block::Table(vec![ColAlign::Left, ColAlign::Right], vec![
+                vec!["A".into(), "B".into()],
+                vec!["1".into(), "2".into()],
+            ])]
+        );
+    }
+
+    // ── chart directive ──
+
+    #[test]
+    fn parse_blocks_chart_pie_defaults() {
+        let input = "#! chart pie\nCritical | 3\nHigh | 5\nLow | 1";
+        let blocks = parse_blocks(input);
+        assert_eq!(
+            blocks,
+            vec![Block::Chart(
+                ChartKind::Pie,
+                vec![("Critical".into(), 3.0), ("High".into(), 5.0), ("Low".into(), 1.0)],
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_blocks_chart_bar_mode() {
+        let input = "#! chart bar\nOpen | 10\nResolved | 4";
+        let blocks = parse_blocks(input);
+        assert_eq!(
+            blocks,
+            vec![Block::Chart(ChartKind::Bar, vec![("Open".into(), 10.0), ("Resolved".into(), 4.0)])]
+        );
+    }
+
+    #[test]
+    fn parse_blocks_chart_flushes_on_blank_line() {
+        let input = "#! chart pie\nCritical | 3\n\nSome text after.";
+        let blocks = parse_blocks(input);
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Chart(ChartKind::Pie, vec![("Critical".into(), 3.0)]),
+                Block::Text("Some text after.".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn chart_kind_parse_defaults_to_pie() {
+        assert_eq!(ChartKind::parse("pie"), ChartKind::Pie);
+        assert_eq!(ChartKind::parse("bar"), ChartKind::Bar);
+        assert_eq!(ChartKind::parse("BAR"), ChartKind::Bar);
+        assert_eq!(ChartKind::parse("nonsense"), ChartKind::Pie);
+    }
+
+    #[test]
+    fn chart_color_known_severity_and_neutral_fallback() {
+        assert_eq!(chart_color("Critical", 0), "SevCritical");
+        assert_eq!(chart_color("Miscellaneous", 0), "CorpAccent");
+        assert_eq!(chart_color("Miscellaneous", 1), "CorpGray");
+    }
+
+    #[test]
+    fn latex_pie_chart_computes_percentages_and_colors() {
+        let result = latex_pie_chart(&[("Critical".into(), 1.0), ("Low".into(), 3.0)], false);
+        assert!(result.contains(r"\pie[color={SevCritical,SevLow}]"));
+        assert!(result.contains("25.0/Critical (1)"));
+        assert!(result.contains("75.0/Low (3)"));
+    }
+
+    #[test]
+    fn latex_bar_chart_draws_one_rectangle_per_bar() {
+        let result = latex_bar_chart(&[("Open".into(), 10.0), ("Resolved".into(), 5.0)], false);
+        assert_eq!(result.matches(r"\draw[fill=").count(), 2);
+        assert!(result.contains("Open"));
+        assert!(result.contains("Resolved"));
+    }
+
+    #[test]
+    fn html_chart_renders_one_row_per_bar_with_scaled_width() {
+        let result = html_chart(&[("Critical".into(), 2.0), ("Low".into(), 1.0)]);
+        assert!(result.contains("width:100.0%"));
+        assert!(result.contains("width:50.0%"));
+        assert!(result.contains("#991B1B"));
+    }
+
+    // ── parse_inline_spans ──
+
+    #[test]
+    fn spans_plain() {
+        let spans = parse_inline_spans("hello world");
+        assert_eq!(spans, vec![MdSpan::Plain("hello world".into())]);
+    }
+
+    #[test]
+    fn spans_bold() {
+        let spans = parse_inline_spans("a **bold** b");
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[1], MdSpan::Bold("bold".into()));
+    }
+
+    #[test]
+    fn spans_italic() {
+        let spans = parse_inline_spans("a *italic* b");
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[1], MdSpan::Italic("italic".into()));
+    }
+
+    #[test]
+    fn spans_bold_italic() {
+        let spans = parse_inline_spans("***both***");
+        assert_eq!(spans, vec![MdSpan::BoldItalic("both".into())]);
+    }
+
+    #[test]
+    fn spans_code() {
+        let spans = parse_inline_spans("use `foo()` here");
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[1], MdSpan::Code("foo()".into()));
+    }
+
+    #[test]
+    fn spans_link() {
+        let spans = parse_inline_spans("see [docs](https://example.com)");
+        assert_eq!(spans.len(), 2);
+        assert_eq!(
+            spans[1],
+            MdSpan::Link("docs".into(), "https://example.com".into())
+        );
+    }
+
+    #[test]
+    fn spans_image() {
+        let spans = parse_inline_spans("![screenshot](proof.png)");
+        assert_eq!(spans, vec![MdSpan::Image("screenshot".into(), "proof.png".into())]);
+    }
+
+    #[test]
+    fn spans_image_with_text() {
+        let spans = parse_inline_spans("see ![proof](img.jpg) here");
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[0], MdSpan::Plain("see ".into()));
+        assert_eq!(spans[1], MdSpan::Image("proof".into(), "img.jpg".into()));
+        assert_eq!(spans[2], MdSpan::Plain(" here".into()));
+    }
+
+    #[test]
+    fn spans_image_not_confused_with_link() {
+        // Ensure ![...] is parsed as image, not "!" + link
+        let spans = parse_inline_spans("![alt](path.png)");
+        assert_eq!(spans.len(), 1);
+        assert!(matches!(&spans[0], MdSpan::Image(_, _)));
+    }
+
+    #[test]
+    fn spans_mixed() {
+        let spans = parse_inline_spans("**bold** and *italic* and `code`");
+        assert!(spans.len() >= 5);
+        assert_eq!(spans[0], MdSpan::Bold("bold".into()));
+        assert_eq!(spans[2], MdSpan::Italic("italic".into()));
+        assert_eq!(spans[4], MdSpan::Code("code".into()));
+    }
+
+    // ── parse_markdown ──
+
+    #[test]
+    fn md_paragraph() {
+        let blocks = parse_markdown("Hello world.");
+        assert_eq!(blocks.len(), 1);
+        assert!(matches!(&blocks[0], MdBlock::Paragraph(_)));
+    }
+
+    #[test]
+    fn md_heading() {
+        let blocks = parse_markdown("# Title\n## Sub\n### Sub-sub");
+        assert_eq!(blocks.len(), 3);
+        assert!(matches!(&blocks[0], MdBlock::Heading(1, _)));
+        assert!(matches!(&blocks[1], MdBlock::Heading(2, _)));
+        assert!(matches!(&blocks[2], MdBlock::Heading(3, _)));
+    }
+
+    #[test]
+    fn md_bullet_list() {
+        let blocks = parse_markdown("- one\n- two\n- three");
+        assert_eq!(blocks.len(), 3);
+        for b in &blocks {
+            assert!(matches!(b, MdBlock::BulletItem(0, _)));
+        }
+    }
+
+    #[test]
+    fn md_code_block() {
+        let input = "```\nfn main() {}\n```";
+        let blocks = parse_markdown(input);
+        assert_eq!(blocks.len(), 1);
+        assert!(matches!(&blocks[0], MdBlock::CodeBlock { .. }));
+        if let MdBlock::CodeBlock { lang, code } = &blocks[0] {
+            assert_eq!(lang, &None);
+            assert_eq!(code, "fn main() {}");
+        }
+    }
+
+    #[test]
+    fn md_code_block_captures_lang() {
+        let input = "```python\nprint(1)\n```";
+        let blocks = parse_markdown(input);
+        assert_eq!(blocks.len(), 1);
+        if let MdBlock::CodeBlock { lang, code } = &blocks[0] {
+            assert_eq!(lang.as_deref(), Some("python"));
+            assert_eq!(code, "print(1)");
+        } else {
+            panic!("expected CodeBlock");
+        }
+    }
+
+    #[test]
+    fn md_mixed() {
+        let input = "Paragraph.\n\n# Heading\n\n- bullet\n\n```\ncode\n```";
+        let blocks = parse_markdown(input);
+        assert_eq!(blocks.len(), 4);
+        assert!(matches!(&blocks[0], MdBlock::Paragraph(_)));
+        assert!(matches!(&blocks[1], MdBlock::Heading(1, _)));
+        assert!(matches!(&blocks[2], MdBlock::BulletItem(0, _)));
+        assert!(matches!(&blocks[3], MdBlock::CodeBlock { .. }));
+    }
+
+    // ── spans_to_plain ──
+
+    #[test]
+    fn spans_to_plain_basic() {
+        let spans = parse_inline_spans("**bold** and *italic*");
+        let plain = spans_to_plain(&spans);
+        assert_eq!(plain, "bold and italic");
+    }
+
+    #[test]
+    fn spans_to_plain_link() {
+        let spans = vec![MdSpan::Link("click".into(), "https://x.com".into())];
+        assert_eq!(spans_to_plain(&spans), "click");
+    }
+
+    // ── spans_to_latex ──
+
+    #[test]
+    fn spans_to_latex_plain() {
+        let spans = vec![MdSpan::Plain("hello".into())];
+        assert_eq!(spans_to_latex(&spans, false), "hello");
+    }
+
+    #[test]
+    fn spans_to_latex_bold() {
+        let spans = vec![MdSpan::Bold("strong".into())];
+        assert_eq!(spans_to_latex(&spans, false), r"\textbf{strong}");
+    }
+
+    #[test]
+    fn spans_to_latex_italic() {
+        let spans = vec![MdSpan::Italic("em".into())];
+        assert_eq!(spans_to_latex(&spans, false), r"\textit{em}");
+    }
+
+    #[test]
+    fn spans_to_latex_bold_italic() {
+        let spans = vec![MdSpan::BoldItalic("bi".into())];
+        assert_eq!(spans_to_latex(&spans, false), r"\textbf{\textit{bi}}");
+    }
+
+    #[test]
+    fn spans_to_latex_code() {
+        let spans = vec![MdSpan::Code("x()".into())];
+        assert_eq!(spans_to_latex(&spans, false), r"\code{x()}");
+    }
+
+    #[test]
+    fn spans_to_latex_link() {
+        let spans = vec![MdSpan::Link("site".into(), "https://x.com".into())];
+        assert_eq!(
+            spans_to_latex(&spans, false),
+            r"\href{https://x.com}{site}"
+        );
+    }
+
+    #[test]
+    fn spans_to_latex_image() {
+        let spans = vec![MdSpan::Image("proof".into(), "proof.png".into())];
+        let latex = spans_to_latex(&spans, false);
+        assert!(latex.contains(r"\includegraphics"));
+        assert!(latex.contains("proof.png"));
+        assert!(latex.contains("proof")); // alt text
+    }
+
+    #[test]
+    fn spans_to_latex_escapes_special() {
+        let spans = vec![MdSpan::Plain("a & b".into())];
+        assert_eq!(spans_to_latex(&spans, false), r"a \& b");
+    }
+
+    // ── md_to_latex ──
+
+    #[test]
+    fn md_to_latex_paragraph() {
+        let result = md_to_latex("Hello world.", false);
+        assert!(result.contains("Hello world."));
+    }
+
+    #[test]
+    fn md_to_latex_heading() {
+        let result = md_to_latex("# Title", false);
+        assert!(result.contains(r"\subsection*{Title}"));
+    }
+
+    #[test]
+    fn md_to_latex_heading_levels() {
+        let result = md_to_latex("## Sub\n### SubSub", false);
+        assert!(result.contains(r"\subsubsection*{Sub}"));
+        assert!(result.contains(r"\paragraph*{SubSub}"));
+    }
+
+    #[test]
+    fn md_to_latex_bullets() {
+        let result = md_to_latex("- one\n- two", false);
+        assert!(result.contains(r"\begin{itemize}"));
+        assert!(result.contains(r"\item one"));
+        assert!(result.contains(r"\item two"));
+        assert!(result.contains(r"\end{itemize}"));
+    }
+
+    #[test]
+    fn md_to_latex_code_block() {
+        let result = md_to_latex("```\ncode here\n```", false);
+        assert!(result.contains(r"\begin{lstlisting}"));
+        assert!(result.contains("code here"));
+        assert!(result.contains(r"\end{lstlisting}"));
+    }
+
+    #[test]
+    fn md_to_latex_code_block_unknown_lang_falls_back_to_verbatim() {
+        let result = md_to_latex("```brainfuck\n+++.\n```", false);
+        assert!(result.contains(r"\begin{lstlisting}"));
+        assert!(result.contains("+++."));
+    }
+
+    #[test]
+    fn md_to_latex_code_block_python_highlights_keywords() {
+        let result = md_to_latex("```python\ndef run():\n    return 1\n```", false);
+        assert!(result.contains(r"\begin{flushleft}"));
+        assert!(result.contains(r"\textcolor{CorpAccent}{\textbf{def}}"));
+        assert!(result.contains(r"\textcolor{CorpAccent}{\textbf{return}}"));
+    }
+
+    #[test]
+    fn md_to_latex_code_block_bash_highlights_comment_and_string() {
+        let result = md_to_latex("```bash\necho \"hi\" # greet\n```", false);
+        assert!(result.contains(r#"\textcolor{SevLow}{"hi"}"#));
+        assert!(result.contains(r"\textcolor{CorpGray}{\textit{\#~greet}}"));
+    }
+
+    // ── syntax highlighting: tokenize_code_line ──
+
+    #[test]
+    fn tokenize_code_line_python_keyword_and_plain() {
+        let spec = lang_spec("python").unwrap();
+        let tokens = tokenize_code_line("return x", &spec);
+        assert_eq!(
+            tokens,
+            vec![(CodeTokenKind::Keyword, "return".to_string()), (CodeTokenKind::Plain, " x".to_string())]
+        );
+    }
+
+    #[test]
+    fn tokenize_code_line_sql_string_and_keyword() {
+        let spec = lang_spec("sql").unwrap();
+        let tokens = tokenize_code_line("SELECT * FROM users WHERE name = 'bob'", &spec);
+        assert!(tokens.contains(&(CodeTokenKind::Keyword, "SELECT".to_string())));
+        assert!(tokens.contains(&(CodeTokenKind::Keyword, "FROM".to_string())));
+        assert!(tokens.contains(&(CodeTokenKind::Str, "'bob'".to_string())));
+    }
+
+    #[test]
+    fn tokenize_code_line_bash_comment_consumes_rest_of_line() {
+        let spec = lang_spec("bash").unwrap();
+        let tokens = tokenize_code_line("ls # list files", &spec);
+        assert_eq!(tokens.last(), Some(&(CodeTokenKind::Comment, "# list files".to_string())));
+    }
+
+    #[test]
+    fn tokenize_code_line_unterminated_string_runs_to_end_of_line() {
+        let spec = lang_spec("python").unwrap();
+        let tokens = tokenize_code_line("x = \"unterminated", &spec);
+        assert!(tokens.contains(&(CodeTokenKind::Str, "\"unterminated".to_string())));
+    }
+
+    #[test]
+    fn lang_spec_unknown_language_is_none() {
+        assert!(lang_spec("brainfuck").is_none());
+    }
+
+    #[test]
+    fn lang_spec_known_aliases() {
+        assert!(lang_spec("PY").is_some());
+        assert!(lang_spec("sh").is_some());
+        assert!(lang_spec("JSON").is_some());
+        assert!(lang_spec("http").is_some());
+    }
+
+    #[test]
+    fn md_to_latex_inline_formatting() {
+        let result = md_to_latex("Use **bold** and *italic* and `code` together.", false);
+        assert!(result.contains(r"\textbf{bold}"));
+        assert!(result.contains(r"\textit{italic}"));
+        assert!(result.contains(r"\code{code}"));
+    }
+
+    #[test]
+    fn md_to_latex_image() {
+        let result = md_to_latex("See below:\n\n![proof screenshot](proof.png)", false);
+        assert!(result.contains(r"\includegraphics"));
+        assert!(result.contains("proof.png"));
+    }
+
+    // ── richer markdown: ordered lists, nesting, blockquotes, tables ──
+
+    #[test]
+    fn md_ordered_list_items() {
+        let blocks = parse_markdown("1. one\n2. two\n3. three");
+        assert_eq!(blocks.len(), 3);
+        assert!(matches!(&blocks[0], MdBlock::OrderedItem(1, 0, _)));
+        assert!(matches!(&blocks[1], MdBlock::OrderedItem(2, 0, _)));
+        assert!(matches!(&blocks[2], MdBlock::OrderedItem(3, 0, _)));
+    }
+
+    #[test]
+    fn md_nested_bullets_capture_indent() {
+        let blocks = parse_markdown("- top\n  - nested\n    - double nested");
+        assert_eq!(blocks.len(), 3);
+        assert!(matches!(&blocks[0], MdBlock::BulletItem(0, _)));
+        assert!(matches!(&blocks[1], MdBlock::BulletItem(1, _)));
+        assert!(matches!(&blocks[2], MdBlock::BulletItem(2, _)));
+    }
+
+    #[test]
+    fn md_task_list_items_capture_checked_state() {
+        let blocks = parse_markdown("- [ ] todo\n- [x] done\n  - [X] also done, nested");
+        assert_eq!(blocks.len(), 3);
+        assert!(matches!(&blocks[0], MdBlock::TaskItem(0, false, _)));
+        assert!(matches!(&blocks[1], MdBlock::TaskItem(0, true, _)));
+        assert!(matches!(&blocks[2], MdBlock::TaskItem(1, true, _)));
+    }
+
+    #[test]
+    fn md_blockquote_single_level() {
+        let blocks = parse_markdown("> quoted line one\n> quoted line two");
+        assert_eq!(blocks.len(), 1);
+        let MdBlock::BlockQuote(children) = &blocks[0] else { panic!("expected BlockQuote") };
+        assert_eq!(children.len(), 1);
+        assert!(matches!(&children[0], MdBlock::Paragraph(_)));
+    }
+
+    #[test]
+    fn md_blockquote_nested() {
+        let blocks = parse_markdown("> outer\n> > inner");
+        let MdBlock::BlockQuote(children) = &blocks[0] else { panic!("expected BlockQuote") };
+        assert_eq!(children.len(), 2);
+        assert!(matches!(&children[1], MdBlock::BlockQuote(_)));
+    }
+
+    #[test]
+    fn md_blockquote_deep_nesting_does_not_overflow_stack() {
+        // Thousands of leading `>` (e.g. a mangled pasted email quote)
+        // must not recurse unboundedly into parse_markdown.
+        let input = format!("{}deeply quoted", ">".repeat(5000));
+        let blocks = parse_markdown(&input);
+        assert_eq!(blocks.len(), 1);
+
+        // Walk the BlockQuote chain and confirm it stops at the depth cap
+        // rather than nesting 5000 levels deep.
+        let mut depth = 0;
+        let mut current = &blocks[0];
+        while let MdBlock::BlockQuote(children) = current {
+            depth += 1;
+            assert!(depth <= MAX_BLOCKQUOTE_DEPTH + 1, "blockquote nesting exceeded the depth cap");
+            match children.first() {
+                Some(child) => current = child,
+                None => break,
+            }
+        }
+        assert!(depth <= MAX_BLOCKQUOTE_DEPTH + 1);
+    }
+
+    #[test]
+    fn md_gfm_table_parses_header_aligns_and_rows() {
+        let blocks = parse_markdown("| Name | Score |\n|:--|--:|\n| alice | 9 |\n| bob | 7 |");
+        assert_eq!(blocks.len(), 1);
+        let MdBlock::Table(aligns, rows) = &blocks[0] else { panic!("expected Table") };
+        assert_eq!(aligns, &vec![ColAlign::Left, ColAlign::Right]);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(spans_to_plain(&rows[0][0]), "Name");
+        assert_eq!(spans_to_plain(&rows[2][1]), "7");
+    }
+
+    #[test]
+    fn is_table_separator_rejects_prose_with_dashes() {
+        assert!(!is_table_separator("re-export the module - see below"));
+        assert!(is_table_separator("|---|:--:|---:|"));
+    }
+
+    #[test]
+    fn md_to_latex_ordered_list() {
+        let result = md_to_latex("1. first\n2. second", false);
+        assert!(result.contains(r"\begin{enumerate}"));
+        assert!(result.contains(r"\item first"));
+        assert!(result.contains(r"\item second"));
+        assert!(result.contains(r"\end{enumerate}"));
+    }
+
+    #[test]
+    fn md_to_latex_ordered_list_custom_start() {
+        let result = md_to_latex("5. fifth\n6. sixth", false);
+        assert!(result.contains(r"\setcounter{enumi}{4}"));
+    }
+
+    #[test]
+    fn md_to_latex_nested_lists_open_and_close_in_order() {
+        let result = md_to_latex("- top\n  1. nested ordered\n- back to top", false);
+        let begin_itemize = result.find(r"\begin{itemize}").unwrap();
+        let begin_enumerate = result.find(r"\begin{enumerate}").unwrap();
+        let end_enumerate = result.find(r"\end{enumerate}").unwrap();
+        let end_itemize = result.rfind(r"\end{itemize}").unwrap();
+        assert!(begin_itemize < begin_enumerate);
+        assert!(begin_enumerate < end_enumerate);
+        assert!(end_enumerate < end_itemize);
+    }
+
+    #[test]
+    fn md_to_latex_task_list() {
+        let result = md_to_latex("- [ ] open item\n- [x] closed item", false);
+        assert!(result.contains(r"\begin{itemize}"));
+        assert!(result.contains(r"\item $\square$ open item"));
+        assert!(result.contains(r"\item $\boxtimes$ closed item"));
+        assert!(result.contains(r"\end{itemize}"));
+    }
+
+    #[test]
+    fn md_to_latex_blockquote() {
+        let result = md_to_latex("> a quoted remediation note", false);
+        assert!(result.contains(r"\begin{quote}\color{CorpAccent}"));
+        assert!(result.contains("a quoted remediation note"));
+        assert!(result.contains(r"\end{quote}"));
+    }
+
+    #[test]
+    fn md_to_latex_table() {
+        let result = md_to_latex("| A | B |\n|---|---|\n| 1 | 2 |", false);
+        assert!(result.contains(r"\begin{tabular}"));
+        assert!(result.contains(r"\toprule"));
+        assert!(result.contains(r"\midrule"));
+        assert!(result.contains(r"\bottomrule"));
+        assert!(result.contains("A & B"));
+        assert!(result.contains("1 & 2"));
+    }
+
+    // ── rewrite_description_images ──
+
+    #[test]
+    fn rewrite_images_no_images() {
+        let desc = "No images here.";
+        assert_eq!(rewrite_description_images(desc, &[], "slug"), "No images here.");
+    }
+
+    #[test]
+    fn rewrite_images_matching_basename() {
+        let desc = "See ![proof](../img/xss.jpg) for details.";
+        let images = vec!["img/xss.jpg".to_string()];
+        let result = rewrite_description_images(desc, &images, "stored-xss");
+        assert_eq!(result, "See ![proof](stored-xss-xss.jpg) for details.");
+    }
+
+    #[test]
+    fn rewrite_images_no_match() {
+        let desc = "See ![proof](../img/other.jpg) for details.";
+        let images = vec!["img/xss.jpg".to_string()];
+        let result = rewrite_description_images(desc, &images, "stored-xss");
+        // No match: original path is preserved
+        assert_eq!(result, "See ![proof](../img/other.jpg) for details.");
+    }
+
+    #[test]
+    fn rewrite_images_multiple() {
+        let desc = "![a](img/one.png) and ![b](img/two.png)";
+        let images = vec!["img/one.png".to_string(), "img/two.png".to_string()];
+        let result = rewrite_description_images(desc, &images, "vuln");
+        assert!(result.contains("vuln-one.png"));
+        assert!(result.contains("vuln-two.png"));
+    }
+
+    // ── blocks_to_latex ──
+
+    #[test]
+    fn btl_title() {
+        let latex = blocks_to_latex(&[Block::Title("My Report".into())], "test", false);
+        assert!(latex.contains("My Report"));
+    }
+
+    #[test]
+    fn btl_subtitle() {
+        let latex = blocks_to_latex(&[Block::Subtitle("acme.corp".into())], "test", false);
+        assert!(latex.contains("acme.corp"));
+    }
+
+    #[test]
+    fn btl_section() {
+        let latex = blocks_to_latex(&[Block::Section("Details".into())], "test", false);
+        assert!(latex.contains(r"\section{Details}"));
+    }
+
+    #[test]
+    fn btl_finding() {
+        let latex = blocks_to_latex(&[Block::Finding("Critical".into(), "SQLi".into())], "test", false);
+        assert!(latex.contains("SQLi"));
+        assert!(latex.contains("Critical"));
+    }
+
+    #[test]
+    fn btl_meta() {
+        let latex = blocks_to_latex(&[Block::Meta("Asset".into(), "web.corp".into())], "test", false);
+        assert!(latex.contains("Asset"));
+        assert!(latex.contains("web.corp"));
+    }
+
+    #[test]
+    fn btl_table() {
+        let rows = vec![
+            vec!["A".into(), "B".into()],
+            vec!["1".into(), "2".into()],
+        ];
+        let latex = blocks_to_latex(&[Block::Table(vec![], rows)], "test", false);
+        assert!(latex.contains(r"\begin{tabularx}"));
+        assert!(latex.contains("1 & 2"));
+    }
+
+    #[test]
+    fn btl_table_three_cols() {
+        let rows = vec![
+            vec!["A".into(), "B".into(), "C".into()],
+            vec!["1".into(), "2".into(), "3".into()],
+        ];
+        let latex = blocks_to_latex(&[Block::Table(vec![], rows)], "test", false);
+        assert!(latex.contains(r"\begin{tabularx}"));
+        assert!(latex.contains("1 & 2 & 3"));
+    }
+
+    #[test]
+    fn btl_table_aligned_columns_use_per_column_spec() {
+        let rows = vec![
+            vec!["A".into(), "B".into(), "C".into()],
+            vec!["1".into(), "2".into(), "3".into()],
+        ];
+        let aligns = vec![ColAlign::Center, ColAlign::Left, ColAlign::Right];
+        let latex = blocks_to_latex(&[Block::Table(aligns, rows)], "test", false);
+        assert!(latex.contains(r"{c >{\raggedright\arraybackslash}X >{\raggedleft\arraybackslash}X}"));
+    }
+
+    #[test]
+    fn btl_table_alignment_mismatch_falls_back_to_default() {
+        let rows = vec![
+            vec!["A".into(), "B".into(), "C".into()],
+            vec!["1".into(), "2".into(), "3".into()],
+        ];
+        // Only one alignment marker for three columns.
+        let latex = blocks_to_latex(&[Block::Table(vec![ColAlign::Right], rows)], "test", false);
+        assert!(latex.contains(r"{r X X}"));
+    }
+
+    #[test]
+    fn btl_text_markdown() {
+        let latex = blocks_to_latex(&[Block::Text("**bold** text".into())], "test", false);
+        assert!(latex.contains(r"\textbf{bold}"));
+    }
+
+    #[test]
+    fn btl_latex() {
+        let latex = blocks_to_latex(&[Block::Latex("\\begin{center}\n\\includegraphics{proof.png}\n\\end{center}".into())], "test", false);
+        assert!(latex.contains(r"\includegraphics{proof.png}"));
+        assert!(latex.contains(r"\begin{center}"));
+    }
+
+    #[test]
+    fn btl_index() {
+        let latex = blocks_to_latex(&[Block::Index], "test", false);
+        assert!(latex.contains(r"\tableofcontents"));
+    }
+
+    #[test]
+    fn btl_spacer() {
+        let latex = blocks_to_latex(&[Block::Spacer(10.0)], "test", false);
+        assert!(latex.contains(r"\vspace{10mm}"));
+    }
+
+    #[test]
+    fn btl_pagebreak() {
+        let latex = blocks_to_latex(&[Block::PageBreak], "test", false);
+        assert!(latex.contains(r"\clearpage"));
+    }
+
+    #[test]
+    fn btl_hrule() {
+        let latex = blocks_to_latex(&[Block::HRule], "test", false);
+        assert!(latex.contains(r"\rule"));
+    }
+
+    #[test]
+    fn btl_full_document_structure() {
+        let latex = blocks_to_latex(&[Block::Title("T".into())], "test", false);
+        assert!(latex.contains(r"\documentclass"));
+        assert!(latex.contains(r"\begin{document}"));
+        assert!(latex.contains(r"\end{document}"));
+    }
+
+    #[test]
+    fn latex_preamble_default_loads_fontspec_not_inputenc() {
+        let preamble = latex_preamble("test", false, &Theme::default());
+        assert!(preamble.contains(r"\usepackage{fontspec}"));
+        assert!(!preamble.contains(r"\usepackage[utf8]{inputenc}"));
+        assert!(!preamble.contains(r"\usepackage[T1]{fontenc}"));
+    }
 
-    Ok(())
-}
+    #[test]
+    fn latex_preamble_legacy_loads_inputenc_not_fontspec() {
+        let preamble = latex_preamble("test", true, &Theme::default());
+        assert!(preamble.contains(r"\usepackage[utf8]{inputenc}"));
+        assert!(preamble.contains(r"\usepackage[T1]{fontenc}"));
+        assert!(!preamble.contains(r"\usepackage{fontspec}"));
+    }
 
-// ───────────────────────── date helper ─────────────────────────
+    // ── date helpers ──
 
-/// Current date as `YYYY/MM/DD`.
-fn current_date() -> String {
-    // Extracted from `date +%Y/%m/%d` logic, no chrono dependency.
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let secs = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs() as i64;
+    #[test]
+    fn leap_year_detection() {
+        assert!(is_leap(2000));
+        assert!(is_leap(2024));
+        assert!(!is_leap(1900));
+        assert!(!is_leap(2023));
+    }
 
-    let days = secs / 86400;
-    let mut y = 1970i32;
-    let mut rem = days;
+    #[test]
+    fn month_days_normal() {
+        assert_eq!(month_days(2023, 1), 31);
+        assert_eq!(month_days(2023, 2), 28);
+        assert_eq!(month_days(2023, 4), 30);
+    }
 
-    loop {
-        let ylen: i64 = if is_leap(y) { 366 } else { 365 };
-        if rem < ylen {
-            break;
-        }
-        rem -= ylen;
-        y += 1;
+    #[test]
+    fn month_days_leap_feb() {
+        assert_eq!(month_days(2024, 2), 29);
     }
 
-    let mut m = 1u32;
-    loop {
-        let mlen = month_days(y, m) as i64;
-        if rem < mlen {
-            break;
-        }
-        rem -= mlen;
-        m += 1;
+    #[test]
+    fn current_date_format() {
+        let d = current_date();
+        assert_eq!(d.len(), 10);
+        assert_eq!(&d[4..5], "/");
+        assert_eq!(&d[7..8], "/");
     }
-    let d = rem as u32 + 1;
-    format!("{y:04}/{m:02}/{d:02}")
-}
 
-fn is_leap(y: i32) -> bool {
-    y % 4 == 0 && (y % 100 != 0 || y % 400 == 0)
-}
+    // ── helper functions ──
 
-fn month_days(y: i32, m: u32) -> u32 {
-    match m {
-        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-        4 | 6 | 9 | 11 => 30,
-        2 => if is_leap(y) { 29 } else { 28 },
-        _ => 30,
+    #[test]
+    fn try_parse_link_valid() {
+        let chars: Vec<char> = "[docs](https://x.com) rest".chars().collect();
+        let result = try_parse_link(&chars, 0);
+        assert!(result.is_some());
+        let (display, url, end) = result.unwrap();
+        assert_eq!(display, "docs");
+        assert_eq!(url, "https://x.com");
+        assert_eq!(end, 21);
     }
-}
 
-// ═══════════════════════════════════════════════════════════════════════
-//  Tests
-// ═══════════════════════════════════════════════════════════════════════
+    #[test]
+    fn try_parse_link_invalid_no_paren() {
+        let chars: Vec<char> = "[docs] rest".chars().collect();
+        let result = try_parse_link(&chars, 0);
+        assert!(result.is_none());
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn extract_delimited_backtick() {
+        let chars: Vec<char> = "`code` rest".chars().collect();
+        let result = extract_delimited(&chars, 0, '`');
+        assert!(result.is_some());
+        let (content, end) = result.unwrap();
+        assert_eq!(content, "code");
+        assert_eq!(end, 6);
+    }
 
-    // ── LaTeX escape ──
+    #[test]
+    fn extract_delimited_empty_returns_none() {
+        let chars: Vec<char> = "`` rest".chars().collect();
+        let result = extract_delimited(&chars, 0, '`');
+        assert!(result.is_none());
+    }
 
     #[test]
-    fn latex_escape_basic() {
-        assert_eq!(latex_escape("hello"), "hello");
+    fn extract_between_double_star() {
+        let chars: Vec<char> = "bold** rest".chars().collect();
+        let result = extract_between(&chars, 0, "**");
+        assert!(result.is_some());
+        let (content, end) = result.unwrap();
+        assert_eq!(content, "bold");
+        assert_eq!(end, 6);
     }
 
     #[test]
-    fn latex_escape_special_chars() {
-        assert_eq!(latex_escape("a & b"), r"a \& b");
-        assert_eq!(latex_escape("100%"), r"100\%");
-        assert_eq!(latex_escape("$x$"), r"\$x\$");
-        assert_eq!(latex_escape("item #1"), r"item \#1");
-        assert_eq!(latex_escape("a_b"), r"a\_b");
-        assert_eq!(latex_escape("{x}"), r"\{x\}");
+    fn extract_between_no_match() {
+        let chars: Vec<char> = "no end marker".chars().collect();
+        let result = extract_between(&chars, 0, "**");
+        assert!(result.is_none());
     }
 
+    // ── integration: blocks_to_latex with mixed content ──
+
     #[test]
-    fn latex_escape_tilde_caret_backslash() {
-        assert_eq!(latex_escape("~"), r"\textasciitilde{}");
-        assert_eq!(latex_escape("^"), r"\textasciicircum{}");
-        assert_eq!(latex_escape(r"\"), r"\textbackslash{}");
+    fn integration_mixed_blocks() {
+        let blocks = vec![
+            Block::Title("Security Report".into()),
+            Block::Subtitle("acme.corp".into()),
+            Block::PageBreak,
+            Block::Section("Executive Summary".into()),
+            Block::Text("This is a **test** report.".into()),
+            Block::Spacer(4.0),
+            Block::Table(vec![], vec![
+                vec!["Sev".into(), "Count".into()],
+                vec!["Critical".into(), "2".into()],
+            ]),
+            Block::PageBreak,
+            Block::Section("Findings".into()),
+            Block::Finding("Critical".into(), "1. SQL Injection".into()),
+            Block::Meta("Asset".into(), "web.corp".into()),
+            Block::Text("Description with `code` and **bold**.".into()),
+            Block::HRule,
+        ];
+        let latex = blocks_to_latex(&blocks, "test", false);
+
+        // Verify document structure
+        assert!(latex.contains(r"\documentclass"));
+        assert!(latex.contains(r"\begin{document}"));
+        assert!(latex.contains(r"\end{document}"));
+
+        // Verify blocks rendered
+        assert!(latex.contains("Security Report"));
+        assert!(latex.contains("acme.corp"));
+        assert!(latex.contains(r"\clearpage"));
+        assert!(latex.contains(r"\section{Executive Summary}"));
+        assert!(latex.contains(r"\textbf{test}"));
+        assert!(latex.contains(r"\begin{tabularx}"));
+        assert!(latex.contains(r"\section{Findings}"));
+        assert!(latex.contains("SQL Injection"));
+        assert!(latex.contains(r"\code{code}"));
     }
 
     #[test]
-    fn latex_escape_unicode_chars() {
-        // Dashes
-        assert_eq!(latex_escape("\u{2013}"), "--");  // en-dash
-        assert_eq!(latex_escape("\u{2014}"), "---"); // em-dash
-        // Euro and multiplication (the two characters causing rendering bugs)
-        assert_eq!(latex_escape("29.99\u{20AC}"), r"29.99\texteuro{}");
-        assert_eq!(latex_escape("1.5\u{00D7}"), r"1.5$\times$");
-        // Quotes
-        assert_eq!(latex_escape("\u{201C}hello\u{201D}"), "``hello''");
+    fn integration_full_parse_and_render() {
+        let input = "\
+#! title Test Report
+#! subtitle target.local
+#! spacer 8
+#! meta Date: 2025/01/01
+#! pagebreak
+#! section Summary
+This report has **bold** and *italic* content.
+#! spacer 4
+#! table
+Severity | Count
+Critical | 1
+#! pagebreak
+#! section Findings
+#! finding High 1. XSS Attack
+#! meta Severity: High
+#! meta Asset: web.app
+Reflected XSS in the `search` parameter.
+- Step 1: inject payload
+- Step 2: observe alert
+#! hr
+";
+        let blocks = parse_blocks(input);
+        let latex = blocks_to_latex(&blocks, "test", false);
+
+        assert!(latex.contains(r"\documentclass"));
+        assert!(latex.contains("Test Report"));
+        assert!(latex.contains("target.local"));
+        assert!(latex.contains(r"\section{Summary}"));
+        assert!(latex.contains(r"\textbf{bold}"));
+        assert!(latex.contains(r"\textit{italic}"));
+        assert!(latex.contains(r"\begin{tabularx}"));
+        assert!(latex.contains("SevHigh"));
+        assert!(latex.contains("XSS Attack"));
+        assert!(latex.contains(r"\code{search}"));
+        assert!(latex.contains(r"\begin{itemize}"));
+        assert!(latex.contains(r"\item"));
+        assert!(latex.contains(r"\end{itemize}"));
+        // Finding starts on its own page
+        assert!(latex.contains(r"\clearpage"));
     }
 
-    // ── severity colour ──
+    // ── finding page break ──
 
     #[test]
-    fn severity_latex_color_known() {
-        assert_eq!(severity_latex_color("Critical"), "SevCritical");
-        assert_eq!(severity_latex_color("high"), "SevHigh");
-        assert_eq!(severity_latex_color("MEDIUM"), "SevMedium");
-        assert_eq!(severity_latex_color("Low"), "SevLow");
-        assert_eq!(severity_latex_color("Info"), "SevInfo");
+    fn finding_starts_on_new_page() {
+        let blocks = vec![
+            Block::Text("Some text.".into()),
+            Block::Finding("High".into(), "1. Test".into()),
+        ];
+        let latex = blocks_to_latex(&blocks, "test", false);
+        let finding_pos = latex.find("1. Test").unwrap();
+        let clearpage_before = latex[..finding_pos].rfind(r"\clearpage");
+        assert!(clearpage_before.is_some());
     }
 
     #[test]
-    fn severity_latex_color_unknown() {
-        assert_eq!(severity_latex_color("banana"), "black");
+    fn first_finding_after_section_no_clearpage() {
+        let blocks = vec![
+            Block::Section("Detailed Findings".into()),
+            Block::Finding("High".into(), "1. Test".into()),
+        ];
+        let latex = blocks_to_latex(&blocks, "test", false);
+        let section_pos = latex.find(r"\section{Detailed Findings}").unwrap();
+        let finding_pos = latex.find("1. Test").unwrap();
+        let between = &latex[section_pos..finding_pos];
+        assert!(!between.contains(r"\clearpage"));
     }
 
-    // ── parse_blocks ──
+    #[test]
+    fn multiple_findings_each_on_own_page() {
+        let blocks = vec![
+            Block::Finding("Critical".into(), "1. First".into()),
+            Block::Text("Description.".into()),
+            Block::Finding("High".into(), "2. Second".into()),
+        ];
+        let latex = blocks_to_latex(&blocks, "test", false);
+        let clearpage_count = latex.matches(r"\clearpage").count();
+        assert!(clearpage_count >= 2);
+    }
+
+    // ── render_pdf error on invalid LaTeX ──
 
     #[test]
-    fn parse_blocks_title() {
-        let blocks = parse_blocks("#! title My Report");
-        assert_eq!(blocks, vec![Block::Title("My Report".into())]);
+    fn render_pdf_with_empty_latex_handles_error() {
+        // Empty input is not valid LaTeX — tectonic should return an error.
+        let tmp = tempfile::tempdir().unwrap();
+        let result = render_pdf("", "/tmp/pog_test_nonexistent.pdf", tmp.path());
+        assert!(result.is_err());
+    }
+
+    // ── bibliography: parse_bibtex ──
+
+    #[test]
+    fn parse_bibtex_single_entry() {
+        let bib = "\
+@article{smith2021, author = {Smith, John}, title = {On Widgets}, year = {2021}, journal = {Widget Journal}}";
+        let entries = parse_bibtex(bib);
+        let entry = entries.get("smith2021").unwrap();
+        assert_eq!(entry.entry_type, "article");
+        assert_eq!(entry.authors, vec!["Smith, John".to_string()]);
+        assert_eq!(entry.title, "On Widgets");
+        assert_eq!(entry.year, "2021");
+        assert_eq!(entry.journal, "Widget Journal");
     }
 
     #[test]
-    fn parse_blocks_subtitle() {
-        let blocks = parse_blocks("#! subtitle target.corp");
-        assert_eq!(blocks, vec![Block::Subtitle("target.corp".into())]);
+    fn parse_bibtex_multiple_entries_and_authors() {
+        let bib = "\
+@inproceedings{doe2020,
+  author = {Doe, Jane and Roe, Richard},
+  title = {A Study, With a Comma},
+  year = {2020},
+  journal = {Proc. of Stuff}
+}
+
+@misc{nokey2019, author = {Anon}, title = {Untitled}, year = {2019}}";
+        let entries = parse_bibtex(bib);
+        assert_eq!(entries.len(), 2);
+        let doe = entries.get("doe2020").unwrap();
+        assert_eq!(doe.authors, vec!["Doe, Jane".to_string(), "Roe, Richard".to_string()]);
+        assert_eq!(doe.title, "A Study, With a Comma");
+        assert!(entries.contains_key("nokey2019"));
     }
 
     #[test]
-    fn parse_blocks_section() {
-        let blocks = parse_blocks("#! section Executive Summary");
-        assert_eq!(blocks, vec![Block::Section("Executive Summary".into())]);
+    fn parse_bibtex_unknown_fields_ignored() {
+        let bib = "@book{x, author = {A}, note = {ignored}, year = {2000}}";
+        let entries = parse_bibtex(bib);
+        let entry = entries.get("x").unwrap();
+        assert_eq!(entry.year, "2000");
+        assert_eq!(entry.journal, "");
     }
 
+    // ── bibliography: parse_ris ──
+
     #[test]
-    fn parse_blocks_finding() {
-        let blocks = parse_blocks("#! finding Critical 1. SQL Injection");
-        assert_eq!(
-            blocks,
-            vec![Block::Finding("Critical".into(), "1. SQL Injection".into())]
-        );
+    fn parse_ris_single_record_derives_key_from_author_and_year() {
+        let ris = "\
+TY  - JOUR
+AU  - Smith, John
+TI  - On Widgets
+PY  - 2021
+JO  - Widget Journal
+UR  - https://example.com/widgets
+ER  -
+";
+        let entries = parse_ris(ris);
+        let entry = entries.get("smith2021").unwrap();
+        assert_eq!(entry.entry_type, "jour");
+        assert_eq!(entry.authors, vec!["Smith, John".to_string()]);
+        assert_eq!(entry.title, "On Widgets");
+        assert_eq!(entry.year, "2021");
+        assert_eq!(entry.journal, "Widget Journal");
+        assert_eq!(entry.url, "https://example.com/widgets");
     }
 
     #[test]
-    fn parse_blocks_meta() {
-        let blocks = parse_blocks("#! meta Prepared for: ACME Corp");
-        assert_eq!(
-            blocks,
-            vec![Block::Meta("Prepared for".into(), "ACME Corp".into())]
-        );
+    fn parse_ris_multiple_records_and_authors_with_explicit_id() {
+        let ris = "\
+TY  - CONF
+ID  - doe2020
+AU  - Doe, Jane
+AU  - Roe, Richard
+TI  - A Study
+PY  - 2020
+ER  -
+
+TY  - RPRT
+AU  - Anon
+TI  - Untitled
+PY  - 2019
+ER  -
+";
+        let entries = parse_ris(ris);
+        assert_eq!(entries.len(), 2);
+        let doe = entries.get("doe2020").unwrap();
+        assert_eq!(doe.authors, vec!["Doe, Jane".to_string(), "Roe, Richard".to_string()]);
+        assert!(entries.contains_key("anon2019"));
     }
 
+    // ── bibliography: author name formatting ──
+
     #[test]
-    fn parse_blocks_index() {
-        let blocks = parse_blocks("#! index");
-        assert_eq!(blocks, vec![Block::Index]);
+    fn split_author_name_surname_comma_given() {
+        assert_eq!(split_author_name("Smith, John"), ("Smith".to_string(), "J.".to_string()));
     }
 
     #[test]
-    fn parse_blocks_spacer() {
-        let blocks = parse_blocks("#! spacer 8");
-        assert_eq!(blocks, vec![Block::Spacer(8.0)]);
+    fn split_author_name_given_surname() {
+        assert_eq!(split_author_name("John Smith"), ("Smith".to_string(), "J.".to_string()));
     }
 
     #[test]
-    fn parse_blocks_pagebreak() {
-        let blocks = parse_blocks("#! pagebreak");
-        assert_eq!(blocks, vec![Block::PageBreak]);
+    fn author_surname_extracts_last_name() {
+        assert_eq!(author_surname("Smith, John"), "Smith");
     }
 
     #[test]
-    fn parse_blocks_hr() {
-        let blocks = parse_blocks("#! hr");
-        assert_eq!(blocks, vec![Block::HRule]);
+    fn ieee_author_name_format() {
+        assert_eq!(ieee_author_name("Smith, John"), "J. Smith");
     }
 
     #[test]
-    fn parse_blocks_comment_ignored() {
-        let blocks = parse_blocks("#! comment This should not appear");
-        assert!(blocks.is_empty());
+    fn apa_author_name_format() {
+        assert_eq!(apa_author_name("Smith, John"), "Smith, J.");
     }
 
+    // ── bibliography: citation inline parsing ──
+
     #[test]
-    fn parse_blocks_latex_inline() {
-        let blocks = parse_blocks("#! latex \\vspace{20mm}");
-        assert_eq!(blocks, vec![Block::Latex("\\vspace{20mm}".into())]);
+    fn parse_inline_spans_single_citation() {
+        let spans = parse_inline_spans("See [@smith2021] for details.");
+        assert!(spans.iter().any(|s| matches!(s, MdSpan::Citation(keys) if keys == &vec!["smith2021".to_string()])));
     }
 
     #[test]
-    fn parse_blocks_latex_block() {
-        let input = "#! latex\n\\begin{center}\n\\includegraphics{logo.png}\n\\end{center}\n#! endlatex";
-        let blocks = parse_blocks(input);
-        assert_eq!(blocks, vec![Block::Latex("\\begin{center}\n\\includegraphics{logo.png}\n\\end{center}".into())]);
+    fn parse_inline_spans_multi_citation() {
+        let spans = parse_inline_spans("As shown in [@smith2021; @doe2020].");
+        assert!(spans.iter().any(|s| matches!(
+            s,
+            MdSpan::Citation(keys) if keys == &vec!["smith2021".to_string(), "doe2020".to_string()]
+        )));
     }
 
     #[test]
-    fn parse_blocks_plain_text() {
-        let blocks = parse_blocks("Hello world.");
-        assert_eq!(blocks, vec![Block::Text("Hello world.".into())]);
+    fn try_parse_citation_requires_at_sign() {
+        let chars: Vec<char> = "[not a citation] rest".chars().collect();
+        assert!(try_parse_citation(&chars, 0).is_none());
     }
 
+    // ── footnotes: inline and reference-style parsing ──
+
     #[test]
-    fn parse_blocks_table() {
-        let input = "Sev | Count\nCritical | 3\nHigh | 5";
-        let blocks = parse_blocks(input);
-        assert_eq!(
-            blocks,
-            vec![Block::Table(vec![
-                vec!["Sev".into(), "Count".into()],
-                vec!["Critical".into(), "3".into()],
-                vec!["High".into(), "5".into()],
-            ])]
-        );
+    fn parse_inline_spans_inline_footnote() {
+        let spans = parse_inline_spans("Uses curl[^ version 8.4, installed via apt ] under the hood.");
+        assert!(spans.iter().any(|s| matches!(s, MdSpan::Footnote(body) if body == "version 8.4, installed via apt")));
     }
 
     #[test]
-    fn parse_blocks_mixed_sequence() {
-        let input = "\
-#! title Report
-#! spacer 4
-Some text here.
-#! pagebreak
-#! section Details
-";
-        let blocks = parse_blocks(input);
-        assert_eq!(blocks.len(), 5);
-        assert_eq!(blocks[0], Block::Title("Report".into()));
-        assert_eq!(blocks[1], Block::Spacer(4.0));
-        assert_eq!(blocks[2], Block::Text("Some text here.".into()));
-        assert_eq!(blocks[3], Block::PageBreak);
-        assert_eq!(blocks[4], Block::Section("Details".into()));
+    fn strip_footnote_def_parses_id_and_text() {
+        assert_eq!(strip_footnote_def("[^tool]: Nmap 7.94"), Some(("tool", "Nmap 7.94")));
+        assert_eq!(strip_footnote_def("not a def"), None);
+        assert_eq!(strip_footnote_def("[^bad id]: nope"), None);
     }
 
     #[test]
-    fn parse_blocks_table_then_text() {
-        let input = "A | B\n1 | 2\nSome paragraph after table.";
-        let blocks = parse_blocks(input);
-        assert_eq!(blocks.len(), 2);
-        assert!(matches!(&blocks[0], Block::Table(_)));
-        assert_eq!(blocks[1], Block::Text("Some paragraph after table.".into()));
+    fn substitute_footnote_refs_rewrites_reference_style_and_drops_def_line() {
+        let out = substitute_footnote_refs("Ran nmap[^tool].\n\n[^tool]: Nmap 7.94");
+        assert!(out.contains("[^ Nmap 7.94 ]"));
+        assert!(!out.contains("[^tool]:"));
     }
 
     #[test]
-    fn parse_blocks_table_pipe_delimited_markdown() {
-        // Markdown-style pipe tables with leading/trailing pipes and separator.
-        let input = "| A | B | C |\n|---|---|---|\n| 1 | 2 | 3 |\n| x | y | z |";
-        let blocks = parse_blocks(input);
-        assert_eq!(
-            blocks,
-            vec![Block::Table(vec![
-                vec!["A".into(), "B".into(), "C".into()],
-                vec!["1".into(), "2".into(), "3".into()],
-                vec!["x".into(), "y".into(), "z".into()],
-            ])]
-        );
+    fn substitute_footnote_refs_leaves_undefined_ref_untouched() {
+        let out = substitute_footnote_refs("See[^missing] for details.");
+        assert_eq!(out, "See[^missing] for details.");
     }
 
     #[test]
-    fn parse_blocks_table_separator_only_skipped() {
-        // Separator with colons (alignment markers) should also be skipped.
-        let input = "| A | B |\n|:---|---:|\n| 1 | 2 |";
-        let blocks = parse_blocks(input);
-        assert_eq!(
-            blocks,
-            vec![Block::Table(vec![
-                vec!["A".into(), "B".into()],
-                vec!["1".into(), "2".into()],
-            ])]
-        );
+    fn parse_blocks_footnotes_endnotes_directive() {
+        let blocks = parse_blocks("#! footnotes endnotes");
+        assert!(matches!(&blocks[0], Block::FootnoteMode(true)));
     }
 
-    // ── parse_inline_spans ──
-
     #[test]
-    fn spans_plain() {
-        let spans = parse_inline_spans("hello world");
-        assert_eq!(spans, vec![MdSpan::Plain("hello world".into())]);
+    fn parse_blocks_footnotes_inline_directive() {
+        let blocks = parse_blocks("#! footnotes inline");
+        assert!(matches!(&blocks[0], Block::FootnoteMode(false)));
     }
 
     #[test]
-    fn spans_bold() {
-        let spans = parse_inline_spans("a **bold** b");
-        assert_eq!(spans.len(), 3);
-        assert_eq!(spans[1], MdSpan::Bold("bold".into()));
+    fn blocks_to_latex_inline_footnote_renders_as_footnote_command() {
+        let blocks = vec![Block::Text("Uses curl[^ installed via apt ] here.".into())];
+        let latex = blocks_to_latex(&blocks, "test", false);
+        assert!(latex.contains(r"\footnote{installed via apt}"));
     }
 
     #[test]
-    fn spans_italic() {
-        let spans = parse_inline_spans("a *italic* b");
-        assert_eq!(spans.len(), 3);
-        assert_eq!(spans[1], MdSpan::Italic("italic".into()));
+    fn blocks_to_latex_endnote_mode_accumulates_numbered_list() {
+        let blocks = vec![
+            Block::FootnoteMode(true),
+            Block::Text("First[^ one ] and second[^ two ].".into()),
+        ];
+        let latex = blocks_to_latex(&blocks, "test", false);
+        assert!(latex.contains(r"\textsuperscript{1}"));
+        assert!(latex.contains(r"\textsuperscript{2}"));
+        assert!(latex.contains(r"\section*{Notes}"));
+        let notes_pos = latex.find(r"\section*{Notes}").unwrap();
+        assert!(latex[notes_pos..].contains(r"\item one"));
+        assert!(latex[notes_pos..].contains(r"\item two"));
     }
 
     #[test]
-    fn spans_bold_italic() {
-        let spans = parse_inline_spans("***both***");
-        assert_eq!(spans, vec![MdSpan::BoldItalic("both".into())]);
+    fn blocks_to_html_inline_footnote_renders_as_sup() {
+        let tmp = tempfile::tempdir().unwrap();
+        let blocks = vec![Block::Text("Uses curl[^ installed via apt ] here.".into())];
+        let html = blocks_to_html(&blocks, "test", tmp.path());
+        assert!(html.contains("<sup class=\"footnote\">installed via apt</sup>"));
     }
 
     #[test]
-    fn spans_code() {
-        let spans = parse_inline_spans("use `foo()` here");
-        assert_eq!(spans.len(), 3);
-        assert_eq!(spans[1], MdSpan::Code("foo()".into()));
+    fn blocks_to_html_endnote_mode_accumulates_numbered_list() {
+        let tmp = tempfile::tempdir().unwrap();
+        let blocks = vec![
+            Block::FootnoteMode(true),
+            Block::Text("First[^ one ] and second[^ two ].".into()),
+        ];
+        let html = blocks_to_html(&blocks, "test", tmp.path());
+        assert!(html.contains("<sup id=\"fnref1\"><a href=\"#fn1\">1</a></sup>"));
+        assert!(html.contains("<sup id=\"fnref2\"><a href=\"#fn2\">2</a></sup>"));
+        assert!(html.contains("<section class=\"endnotes\">"));
+        assert!(html.contains("<li id=\"fn1\">one</li>"));
+        assert!(html.contains("<li id=\"fn2\">two</li>"));
     }
 
+    // ── bibliography: resolve_citations ──
+
     #[test]
-    fn spans_link() {
-        let spans = parse_inline_spans("see [docs](https://example.com)");
-        assert_eq!(spans.len(), 2);
-        assert_eq!(
-            spans[1],
-            MdSpan::Link("docs".into(), "https://example.com".into())
-        );
+    fn resolve_citations_known_key_ieee_numbering() {
+        let mut bib = Bibliography {
+            entries: parse_bibtex("@article{a, author = {A}, title = {T}, year = {2021}, journal = {J}}"),
+            style: BibStyle::Ieee,
+            order: Vec::new(),
+        };
+        let text = format!("Cited here{}a{}.", CITATION_MARKER, CITATION_MARKER_END);
+        let resolved = resolve_citations(&text, &mut bib, |k| k.to_string());
+        assert_eq!(resolved, "Cited here[1].");
+        assert_eq!(bib.order, vec!["a".to_string()]);
     }
 
     #[test]
-    fn spans_image() {
-        let spans = parse_inline_spans("![screenshot](proof.png)");
-        assert_eq!(spans, vec![MdSpan::Image("screenshot".into(), "proof.png".into())]);
+    fn resolve_citations_unknown_key_keeps_marker_text() {
+        let mut bib = Bibliography::default();
+        let text = format!("Cited{}ghost{}.", CITATION_MARKER, CITATION_MARKER_END);
+        let resolved = resolve_citations(&text, &mut bib, |k| k.to_string());
+        assert_eq!(resolved, "Cited[@ghost].");
     }
 
     #[test]
-    fn spans_image_with_text() {
-        let spans = parse_inline_spans("see ![proof](img.jpg) here");
-        assert_eq!(spans.len(), 3);
-        assert_eq!(spans[0], MdSpan::Plain("see ".into()));
-        assert_eq!(spans[1], MdSpan::Image("proof".into(), "img.jpg".into()));
-        assert_eq!(spans[2], MdSpan::Plain(" here".into()));
+    fn resolve_citations_repeat_key_reuses_number() {
+        let mut bib = Bibliography {
+            entries: parse_bibtex("@article{a, author = {A}, title = {T}, year = {2021}, journal = {J}}"),
+            style: BibStyle::Ieee,
+            order: Vec::new(),
+        };
+        let text = format!(
+            "First{m}a{e}, again{m}a{e}.",
+            m = CITATION_MARKER,
+            e = CITATION_MARKER_END
+        );
+        let resolved = resolve_citations(&text, &mut bib, |k| k.to_string());
+        assert_eq!(resolved, "First[1], again[1].");
     }
 
     #[test]
-    fn spans_image_not_confused_with_link() {
-        // Ensure ![...] is parsed as image, not "!" + link
-        let spans = parse_inline_spans("![alt](path.png)");
-        assert_eq!(spans.len(), 1);
-        assert!(matches!(&spans[0], MdSpan::Image(_, _)));
+    fn resolve_citations_unknown_key_escapes_html() {
+        // `key` is attacker-controlled (anything a `[@...]` citation can
+        // name); the unknown-key fallback must not emit it raw into HTML.
+        let mut bib = Bibliography::default();
+        let text = format!("Cited{}<img src=x onerror=alert(1)>{}.", CITATION_MARKER, CITATION_MARKER_END);
+        let resolved = resolve_citations(&text, &mut bib, html_escape);
+        assert!(!resolved.contains("<img"));
+        assert!(resolved.contains("&lt;img"));
     }
 
     #[test]
-    fn spans_mixed() {
-        let spans = parse_inline_spans("**bold** and *italic* and `code`");
-        assert!(spans.len() >= 5);
-        assert_eq!(spans[0], MdSpan::Bold("bold".into()));
-        assert_eq!(spans[2], MdSpan::Italic("italic".into()));
-        assert_eq!(spans[4], MdSpan::Code("code".into()));
+    fn resolve_references_latex_unknown_key_escapes_tex() {
+        // `key` comes from `try_parse_reference`'s `[[key]]` parsing of
+        // untrusted finding description text; an unknown key's fallback
+        // must not splice it raw into the compiled LaTeX source.
+        let mut refs = ReferenceSet::default();
+        let text = format!("See{}\\input{{/etc/passwd}}{}.", REFERENCE_MARKER, REFERENCE_MARKER_END);
+        let resolved = resolve_references_latex(&text, &mut refs, false);
+        assert!(!resolved.contains("\\input{"));
+        assert!(resolved.contains("\\textbackslash{}input"));
     }
 
-    // ── parse_markdown ──
-
     #[test]
-    fn md_paragraph() {
-        let blocks = parse_markdown("Hello world.");
-        assert_eq!(blocks.len(), 1);
-        assert!(matches!(&blocks[0], MdBlock::Paragraph(_)));
+    fn resolve_references_latex_known_key_escapes_label() {
+        let mut refs = ReferenceSet::default();
+        refs.entries.insert("a}b".to_string(), ("text".to_string(), "url".to_string()));
+        let text = format!("See{}a}}b{}.", REFERENCE_MARKER, REFERENCE_MARKER_END);
+        let resolved = resolve_references_latex(&text, &mut refs, false);
+        assert!(resolved.contains("\\hyperref[ref:a\\}b]{[1]}"));
     }
 
     #[test]
-    fn md_heading() {
-        let blocks = parse_markdown("# Title\n## Sub\n### Sub-sub");
-        assert_eq!(blocks.len(), 3);
-        assert!(matches!(&blocks[0], MdBlock::Heading(1, _)));
-        assert!(matches!(&blocks[1], MdBlock::Heading(2, _)));
-        assert!(matches!(&blocks[2], MdBlock::Heading(3, _)));
+    fn latex_escape_scrubs_forged_citation_marker() {
+        // A finding field that happens to contain a literal marker-shaped
+        // sequence must not survive escaping intact, or a later blind
+        // `resolve_citations` scan over the whole rendered text would
+        // treat it as a genuine citation it never emitted.
+        let forged = format!("{}evil{}", CITATION_MARKER, CITATION_MARKER_END);
+        let escaped = latex_escape(&forged, false);
+        assert!(!escaped.contains(CITATION_MARKER));
     }
 
     #[test]
-    fn md_bullet_list() {
-        let blocks = parse_markdown("- one\n- two\n- three");
-        assert_eq!(blocks.len(), 3);
-        for b in &blocks {
-            assert!(matches!(b, MdBlock::BulletItem(_)));
-        }
+    fn html_escape_scrubs_forged_citation_marker() {
+        let forged = format!("{}<script>evil</script>{}", CITATION_MARKER, CITATION_MARKER_END);
+        let escaped = html_escape(&forged);
+        assert!(!escaped.contains(CITATION_MARKER));
     }
 
+    // ── image path safety ──
+
     #[test]
-    fn md_code_block() {
-        let input = "```\nfn main() {}\n```";
-        let blocks = parse_markdown(input);
-        assert_eq!(blocks.len(), 1);
-        assert!(matches!(&blocks[0], MdBlock::CodeBlock(_)));
-        if let MdBlock::CodeBlock(code) = &blocks[0] {
-            assert_eq!(code, "fn main() {}");
-        }
+    fn is_safe_image_path_rejects_traversal_and_absolute() {
+        assert!(!is_safe_image_path("/etc/shadow"));
+        assert!(!is_safe_image_path("../../etc/shadow"));
+        assert!(!is_safe_image_path("foo/../../bar"));
+        assert!(is_safe_image_path("finding-abc123-screenshot.png"));
     }
 
     #[test]
-    fn md_mixed() {
-        let input = "Paragraph.\n\n# Heading\n\n- bullet\n\n```\ncode\n```";
-        let blocks = parse_markdown(input);
-        assert_eq!(blocks.len(), 4);
-        assert!(matches!(&blocks[0], MdBlock::Paragraph(_)));
-        assert!(matches!(&blocks[1], MdBlock::Heading(1, _)));
-        assert!(matches!(&blocks[2], MdBlock::BulletItem(_)));
-        assert!(matches!(&blocks[3], MdBlock::CodeBlock(_)));
+    fn is_safe_image_path_rejects_latex_metacharacters() {
+        assert!(!is_safe_image_path("evil}{\\input{/etc/passwd"));
+        assert!(!is_safe_image_path("a{b"));
     }
 
-    // ── spans_to_plain ──
+    #[test]
+    fn image_data_uri_refuses_path_traversal() {
+        // An unmatched `![alt](path)` reaches here with an attacker-chosen
+        // path, not one of `rewrite_description_images`'s rewritten names.
+        let tmp = tempfile::tempdir().unwrap();
+        let secret = tmp.path().parent().unwrap().join("pog_test_secret.png");
+        fs::write(&secret, b"not a real image").unwrap();
+        let escape_path = format!("../{}", secret.file_name().unwrap().to_str().unwrap());
+        assert!(image_data_uri(tmp.path(), &escape_path).is_none());
+        let _ = fs::remove_file(&secret);
+    }
 
     #[test]
-    fn spans_to_plain_basic() {
-        let spans = parse_inline_spans("**bold** and *italic*");
-        let plain = spans_to_plain(&spans);
-        assert_eq!(plain, "bold and italic");
+    fn image_data_uri_reads_legitimate_image() {
+        let tmp = tempfile::tempdir().unwrap();
+        fs::write(tmp.path().join("finding-abc-shot.png"), b"fake png bytes").unwrap();
+        let uri = image_data_uri(tmp.path(), "finding-abc-shot.png").unwrap();
+        assert!(uri.starts_with("data:image/png;base64,"));
     }
 
     #[test]
-    fn spans_to_plain_link() {
-        let spans = vec![MdSpan::Link("click".into(), "https://x.com".into())];
-        assert_eq!(spans_to_plain(&spans), "click");
+    fn latex_image_rejects_unsafe_path() {
+        let renderer = LatexBackend::new(false, Theme::default());
+        assert_eq!(renderer.image("alt", "/etc/shadow"), "");
+        assert_eq!(renderer.image("alt", "a}{\\input{x"), "");
     }
 
-    // ── spans_to_latex ──
+    // ── bibliography: reference rendering ──
 
     #[test]
-    fn spans_to_latex_plain() {
-        let spans = vec![MdSpan::Plain("hello".into())];
-        assert_eq!(spans_to_latex(&spans), "hello");
+    fn format_ieee_reference_basic() {
+        let entry = BibEntry {
+            entry_type: "article".into(),
+            authors: vec!["Smith, John".into()],
+            title: "On Widgets".into(),
+            year: "2021".into(),
+            journal: "Widget Journal".into(),
+            url: String::new(),
+        };
+        let line = format_ieee_reference(3, &entry, false);
+        assert!(line.starts_with("[3] J. Smith"));
+        assert!(line.contains("On Widgets"));
+        assert!(line.contains(r"\textit{Widget Journal}"));
     }
 
     #[test]
-    fn spans_to_latex_bold() {
-        let spans = vec![MdSpan::Bold("strong".into())];
-        assert_eq!(spans_to_latex(&spans), r"\textbf{strong}");
+    fn format_apa_reference_basic() {
+        let entry = BibEntry {
+            entry_type: "article".into(),
+            authors: vec!["Smith, John".into()],
+            title: "On Widgets".into(),
+            year: "2021".into(),
+            journal: "Widget Journal".into(),
+            url: String::new(),
+        };
+        let line = format_apa_reference(&entry, false);
+        assert!(line.starts_with("Smith, J. (2021)"));
+        assert!(line.contains("On Widgets"));
     }
 
     #[test]
-    fn spans_to_latex_italic() {
-        let spans = vec![MdSpan::Italic("em".into())];
-        assert_eq!(spans_to_latex(&spans), r"\textit{em}");
+    fn render_references_empty_is_blank() {
+        assert_eq!(render_references(&[], BibStyle::Ieee, false), "");
     }
 
     #[test]
-    fn spans_to_latex_bold_italic() {
-        let spans = vec![MdSpan::BoldItalic("bi".into())];
-        assert_eq!(spans_to_latex(&spans), r"\textbf{\textit{bi}}");
+    fn render_references_nonempty_has_section_header() {
+        let entry = BibEntry {
+            entry_type: "article".into(),
+            authors: vec!["Smith, John".into()],
+            title: "On Widgets".into(),
+            year: "2021".into(),
+            journal: "Widget Journal".into(),
+            url: String::new(),
+        };
+        let out = render_references(&[("smith2021".into(), entry)], BibStyle::Ieee, false);
+        assert!(out.contains(r"\section*{References}"));
+        assert!(out.contains("[1]"));
     }
 
+    // ── bibliography: #! bibliography directive ──
+
     #[test]
-    fn spans_to_latex_code() {
-        let spans = vec![MdSpan::Code("x()".into())];
-        assert_eq!(spans_to_latex(&spans), r"\code{x()}");
+    fn parse_blocks_bibliography_directive_default_style() {
+        let blocks = parse_blocks("#! bibliography refs.bib\nText.");
+        assert!(matches!(&blocks[0], Block::Bibliography(path, BibStyle::Ieee) if path == "refs.bib"));
     }
 
     #[test]
-    fn spans_to_latex_link() {
-        let spans = vec![MdSpan::Link("site".into(), "https://x.com".into())];
-        assert_eq!(
-            spans_to_latex(&spans),
-            r"\href{https://x.com}{site}"
-        );
+    fn parse_blocks_bibliography_directive_apa_style() {
+        let blocks = parse_blocks("#! bibliography refs.bib style=apa\nText.");
+        assert!(matches!(&blocks[0], Block::Bibliography(path, BibStyle::Apa) if path == "refs.bib"));
     }
 
+    // ── bibliography: end-to-end via blocks_to_latex ──
+
     #[test]
-    fn spans_to_latex_image() {
-        let spans = vec![MdSpan::Image("proof".into(), "proof.png".into())];
-        let latex = spans_to_latex(&spans);
-        assert!(latex.contains(r"\includegraphics"));
-        assert!(latex.contains("proof.png"));
-        assert!(latex.contains("proof")); // alt text
+    fn integration_bibliography_auto_references() {
+        let tmp = tempfile::tempdir().unwrap();
+        let bib_path = tmp.path().join("refs.bib");
+        fs::write(
+            &bib_path,
+            "@article{smith2021, author = {Smith, John}, title = {On Widgets}, year = {2021}, journal = {Widget Journal}}",
+        )
+        .unwrap();
+
+        let blocks = vec![
+            Block::Bibliography(bib_path.to_string_lossy().to_string(), BibStyle::Ieee),
+            Block::Text("Widgets are well studied [@smith2021].".into()),
+        ];
+        let latex = blocks_to_latex(&blocks, "test", false);
+
+        assert!(latex.contains("Widgets are well studied [1]."));
+        assert!(latex.contains(r"\section*{References}"));
+        assert!(latex.contains("On Widgets"));
     }
 
     #[test]
-    fn spans_to_latex_escapes_special() {
-        let spans = vec![MdSpan::Plain("a & b".into())];
-        assert_eq!(spans_to_latex(&spans), r"a \& b");
+    fn integration_bibliography_explicit_references_block() {
+        let entry = BibEntry {
+            entry_type: "article".into(),
+            authors: vec!["Smith, John".into()],
+            title: "On Widgets".into(),
+            year: "2021".into(),
+            journal: "Widget Journal".into(),
+            url: String::new(),
+        };
+        let blocks = vec![Block::References(vec![("smith2021".into(), entry)], BibStyle::Apa)];
+        let latex = blocks_to_latex(&blocks, "test", false);
+        assert!(latex.contains(r"\section*{References}"));
+        assert!(latex.contains("Smith, J. (2021)"));
     }
 
-    // ── md_to_latex ──
+    // ── references: #! ref / [[key]] ──
 
     #[test]
-    fn md_to_latex_paragraph() {
-        let result = md_to_latex("Hello world.");
-        assert!(result.contains("Hello world."));
+    fn parse_blocks_ref_directive() {
+        let blocks = parse_blocks("#! ref CVE-2024-1234 | NGINX buffer overflow | https://example.com/cve\nText.");
+        assert!(matches!(
+            &blocks[0],
+            Block::Reference(key, text, url)
+                if key == "CVE-2024-1234" && text == "NGINX buffer overflow" && url == "https://example.com/cve"
+        ));
     }
 
     #[test]
-    fn md_to_latex_heading() {
-        let result = md_to_latex("# Title");
-        assert!(result.contains(r"\subsection*{Title}"));
+    fn parse_blocks_references_directive() {
+        let blocks = parse_blocks("#! references\n");
+        assert!(matches!(&blocks[0], Block::ReferenceList));
     }
 
     #[test]
-    fn md_to_latex_heading_levels() {
-        let result = md_to_latex("## Sub\n### SubSub");
-        assert!(result.contains(r"\subsubsection*{Sub}"));
-        assert!(result.contains(r"\paragraph*{SubSub}"));
+    fn parse_inline_spans_reference() {
+        let spans = parse_inline_spans("seen in [[CVE-2024-1234]] already");
+        assert!(spans.iter().any(|s| matches!(s, MdSpan::Reference(key) if key == "CVE-2024-1234")));
     }
 
     #[test]
-    fn md_to_latex_bullets() {
-        let result = md_to_latex("- one\n- two");
-        assert!(result.contains(r"\begin{itemize}"));
-        assert!(result.contains(r"\item one"));
-        assert!(result.contains(r"\item two"));
-        assert!(result.contains(r"\end{itemize}"));
+    fn integration_reference_numbered_in_first_cited_order() {
+        let blocks = vec![
+            Block::Reference("CVE-1".into(), "First bug".into(), "https://example.com/1".into()),
+            Block::Reference("CVE-2".into(), "Second bug".into(), "https://example.com/2".into()),
+            Block::Text("Found [[CVE-2]] then [[CVE-1]] then [[CVE-2]] again.".into()),
+            Block::ReferenceList,
+        ];
+        let latex = blocks_to_latex(&blocks, "test", false);
+
+        assert!(latex.contains(r"\hyperref[ref:CVE-2]{[1]}"));
+        assert!(latex.contains(r"\hyperref[ref:CVE-1]{[2]}"));
+        assert_eq!(latex.matches(r"\hyperref[ref:CVE-2]{[1]}").count(), 2);
+        assert!(latex.contains(r"\item\label{ref:CVE-2} \href{https://example.com/2}{Second bug}"));
+        assert!(latex.contains(r"\item\label{ref:CVE-1} \href{https://example.com/1}{First bug}"));
     }
 
     #[test]
-    fn md_to_latex_code_block() {
-        let result = md_to_latex("```\ncode here\n```");
-        assert!(result.contains(r"\begin{lstlisting}"));
-        assert!(result.contains("code here"));
-        assert!(result.contains(r"\end{lstlisting}"));
+    fn integration_reference_unknown_key_degrades_to_raw_key() {
+        let blocks = vec![Block::Text("See [[CVE-9999]] for details.".into())];
+        let latex = blocks_to_latex(&blocks, "test", false);
+        assert!(latex.contains("See CVE-9999 for details."));
+        assert!(!latex.contains(r"\hyperref"));
     }
 
+    // ── report theme: #! theme / Theme ──
+
     #[test]
-    fn md_to_latex_inline_formatting() {
-        let result = md_to_latex("Use **bold** and *italic* and `code` together.");
-        assert!(result.contains(r"\textbf{bold}"));
-        assert!(result.contains(r"\textit{italic}"));
-        assert!(result.contains(r"\code{code}"));
+    fn parse_blocks_theme_directive() {
+        let blocks = parse_blocks("#! theme dark\nText.");
+        assert!(matches!(&blocks[0], Block::Theme(name) if name == "dark"));
     }
 
     #[test]
-    fn md_to_latex_image() {
-        let result = md_to_latex("See below:\n\n![proof screenshot](proof.png)");
-        assert!(result.contains(r"\includegraphics"));
-        assert!(result.contains("proof.png"));
+    fn theme_named_default_matches_default() {
+        assert_eq!(Theme::named("default"), Theme::default());
+        assert_eq!(Theme::named(""), Theme::default());
     }
 
-    // ── rewrite_description_images ──
-
-    #[test]
-    fn rewrite_images_no_images() {
-        let desc = "No images here.";
-        assert_eq!(rewrite_description_images(desc, &[], "slug"), "No images here.");
+    #[test]
+    fn theme_named_dark_overrides_palette_only() {
+        let dark = Theme::named("dark");
+        assert_eq!(dark.dark, "0F172A");
+        assert_eq!(dark.font, Theme::default().font);
     }
 
     #[test]
-    fn rewrite_images_matching_basename() {
-        let desc = "See ![proof](../img/xss.jpg) for details.";
-        let images = vec!["img/xss.jpg".to_string()];
-        let result = rewrite_description_images(desc, &images, "stored-xss");
-        assert_eq!(result, "See ![proof](stored-xss-xss.jpg) for details.");
+    fn theme_named_unknown_path_falls_back_to_default() {
+        let theme = Theme::named("/no/such/theme.toml");
+        assert_eq!(theme, Theme::default());
     }
 
     #[test]
-    fn rewrite_images_no_match() {
-        let desc = "See ![proof](../img/other.jpg) for details.";
-        let images = vec!["img/xss.jpg".to_string()];
-        let result = rewrite_description_images(desc, &images, "stored-xss");
-        // No match: original path is preserved
-        assert_eq!(result, "See ![proof](../img/other.jpg) for details.");
+    fn integration_theme_directive_recolors_preamble() {
+        let blocks = vec![
+            Block::Theme("dark".into()),
+            Block::Title("T".into()),
+        ];
+        let latex = blocks_to_latex(&blocks, "test", false);
+        assert!(latex.contains(r"\definecolor{CorpDark}{HTML}{0F172A}"));
+        assert!(!latex.contains(r"\definecolor{CorpDark}{HTML}{1E293B}"));
     }
 
     #[test]
-    fn rewrite_images_multiple() {
-        let desc = "![a](img/one.png) and ![b](img/two.png)";
-        let images = vec!["img/one.png".to_string(), "img/two.png".to_string()];
-        let result = rewrite_description_images(desc, &images, "vuln");
-        assert!(result.contains("vuln-one.png"));
-        assert!(result.contains("vuln-two.png"));
+    fn integration_no_theme_directive_uses_default_palette() {
+        let latex = blocks_to_latex(&[Block::Title("T".into())], "test", false);
+        assert!(latex.contains(r"\definecolor{CorpDark}{HTML}{1E293B}"));
     }
 
-    // ── blocks_to_latex ──
+    // ── HTML backend ──
 
     #[test]
-    fn btl_title() {
-        let latex = blocks_to_latex(&[Block::Title("My Report".into())], "test");
-        assert!(latex.contains("My Report"));
+    fn html_escape_special_chars() {
+        assert_eq!(html_escape(r#"<a> & "b" 'c'"#), "&lt;a&gt; &amp; &quot;b&quot; &#39;c&#39;");
     }
 
     #[test]
-    fn btl_subtitle() {
-        let latex = blocks_to_latex(&[Block::Subtitle("acme.corp".into())], "test");
-        assert!(latex.contains("acme.corp"));
+    fn html_slug_basic() {
+        assert_eq!(html_slug("Network Findings"), "network-findings");
     }
 
     #[test]
-    fn btl_section() {
-        let latex = blocks_to_latex(&[Block::Section("Details".into())], "test");
-        assert!(latex.contains(r"\section{Details}"));
+    fn html_slug_collapses_punctuation() {
+        assert_eq!(html_slug("  Stage 1: Recon!! "), "stage-1-recon");
     }
 
     #[test]
-    fn btl_finding() {
-        let latex = blocks_to_latex(&[Block::Finding("Critical".into(), "SQLi".into())], "test");
-        assert!(latex.contains("SQLi"));
-        assert!(latex.contains("Critical"));
+    fn severity_html_class_known() {
+        assert_eq!(severity_html_class("Critical"), "sev-critical");
+        assert_eq!(severity_html_class("high"), "sev-high");
+        assert_eq!(severity_html_class("MEDIUM"), "sev-medium");
+        assert_eq!(severity_html_class("Low"), "sev-low");
+        assert_eq!(severity_html_class("Info"), "sev-info");
     }
 
     #[test]
-    fn btl_meta() {
-        let latex = blocks_to_latex(&[Block::Meta("Asset".into(), "web.corp".into())], "test");
-        assert!(latex.contains("Asset"));
-        assert!(latex.contains("web.corp"));
+    fn severity_html_class_unknown() {
+        assert_eq!(severity_html_class("banana"), "sev-default");
     }
 
     #[test]
-    fn btl_table() {
-        let rows = vec![
-            vec!["A".into(), "B".into()],
-            vec!["1".into(), "2".into()],
-        ];
-        let latex = blocks_to_latex(&[Block::Table(rows)], "test");
-        assert!(latex.contains(r"\begin{tabularx}"));
-        assert!(latex.contains("1 & 2"));
+    fn base64_encode_known_vectors() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
     }
 
     #[test]
-    fn btl_table_three_cols() {
-        let rows = vec![
-            vec!["A".into(), "B".into(), "C".into()],
-            vec!["1".into(), "2".into(), "3".into()],
-        ];
-        let latex = blocks_to_latex(&[Block::Table(rows)], "test");
-        assert!(latex.contains(r"\begin{tabularx}"));
-        assert!(latex.contains("1 & 2 & 3"));
+    fn mime_type_for_known_extensions() {
+        assert_eq!(mime_type_for("shot.png"), "image/png");
+        assert_eq!(mime_type_for("shot.JPG"), "image/jpeg");
+        assert_eq!(mime_type_for("shot.gif"), "image/gif");
+        assert_eq!(mime_type_for("shot.svg"), "image/svg+xml");
+        assert_eq!(mime_type_for("shot.weird"), "image/jpeg");
     }
 
     #[test]
-    fn btl_text_markdown() {
-        let latex = blocks_to_latex(&[Block::Text("**bold** text".into())], "test");
-        assert!(latex.contains(r"\textbf{bold}"));
+    fn spans_to_html_plain_and_bold() {
+        let tmp = tempfile::tempdir().unwrap();
+        let spans = vec![MdSpan::Plain("a & b ".into()), MdSpan::Bold("bold".into())];
+        assert_eq!(spans_to_html(&spans, tmp.path()), "a &amp; b <strong>bold</strong>");
     }
 
     #[test]
-    fn btl_latex() {
-        let latex = blocks_to_latex(&[Block::Latex("\\begin{center}\n\\includegraphics{proof.png}\n\\end{center}".into())], "test");
-        assert!(latex.contains(r"\includegraphics{proof.png}"));
-        assert!(latex.contains(r"\begin{center}"));
+    fn spans_to_html_link() {
+        let tmp = tempfile::tempdir().unwrap();
+        let spans = vec![MdSpan::Link("click".into(), "https://example.com".into())];
+        assert_eq!(spans_to_html(&spans, tmp.path()), "<a href=\"https://example.com\">click</a>");
     }
 
     #[test]
-    fn btl_index() {
-        let latex = blocks_to_latex(&[Block::Index], "test");
-        assert!(latex.contains(r"\tableofcontents"));
+    fn spans_to_html_image_missing_file_is_blank() {
+        let tmp = tempfile::tempdir().unwrap();
+        let spans = vec![MdSpan::Image("alt".into(), "missing.png".into())];
+        assert_eq!(spans_to_html(&spans, tmp.path()), "");
     }
 
     #[test]
-    fn btl_spacer() {
-        let latex = blocks_to_latex(&[Block::Spacer(10.0)], "test");
-        assert!(latex.contains(r"\vspace{10mm}"));
+    fn spans_to_html_image_inlines_base64() {
+        let tmp = tempfile::tempdir().unwrap();
+        fs::write(tmp.path().join("shot.png"), b"not-really-a-png").unwrap();
+        let spans = vec![MdSpan::Image("Proof".into(), "shot.png".into())];
+        let html = spans_to_html(&spans, tmp.path());
+        assert!(html.contains("data:image/png;base64,"));
+        assert!(html.contains("<figcaption>Proof</figcaption>"));
     }
 
     #[test]
-    fn btl_pagebreak() {
-        let latex = blocks_to_latex(&[Block::PageBreak], "test");
-        assert!(latex.contains(r"\clearpage"));
+    fn md_to_html_paragraph_and_heading() {
+        let tmp = tempfile::tempdir().unwrap();
+        let html = md_to_html("# Title\n\nBody text.", tmp.path());
+        assert!(html.contains("<h3>Title</h3>"));
+        assert!(html.contains("<p>Body text.</p>"));
     }
 
     #[test]
-    fn btl_hrule() {
-        let latex = blocks_to_latex(&[Block::HRule], "test");
-        assert!(latex.contains(r"\rule"));
+    fn md_to_html_bullets_wrap_in_list() {
+        let tmp = tempfile::tempdir().unwrap();
+        let html = md_to_html("- one\n- two", tmp.path());
+        assert_eq!(html, "<ul>\n<li>one</li>\n<li>two</li>\n</ul>\n");
     }
 
     #[test]
-    fn btl_full_document_structure() {
-        let latex = blocks_to_latex(&[Block::Title("T".into())], "test");
-        assert!(latex.contains(r"\documentclass"));
-        assert!(latex.contains(r"\begin{document}"));
-        assert!(latex.contains(r"\end{document}"));
+    fn md_to_html_ordered_list() {
+        let tmp = tempfile::tempdir().unwrap();
+        let html = md_to_html("1. one\n2. two", tmp.path());
+        assert_eq!(html, "<ol>\n<li>one</li>\n<li>two</li>\n</ol>\n");
     }
 
-    // ── date helpers ──
-
     #[test]
-    fn leap_year_detection() {
-        assert!(is_leap(2000));
-        assert!(is_leap(2024));
-        assert!(!is_leap(1900));
-        assert!(!is_leap(2023));
+    fn md_to_html_ordered_list_custom_start() {
+        let tmp = tempfile::tempdir().unwrap();
+        let html = md_to_html("5. five\n6. six", tmp.path());
+        assert!(html.contains("<ol start=\"5\">"));
     }
 
     #[test]
-    fn month_days_normal() {
-        assert_eq!(month_days(2023, 1), 31);
-        assert_eq!(month_days(2023, 2), 28);
-        assert_eq!(month_days(2023, 4), 30);
+    fn md_to_html_nested_lists_open_and_close_in_order() {
+        let tmp = tempfile::tempdir().unwrap();
+        let html = md_to_html("- top\n  1. nested ordered\n- back to top", tmp.path());
+        let ul_open = html.find("<ul>").unwrap();
+        let ol_open = html.find("<ol>").unwrap();
+        let ol_close = html.find("</ol>").unwrap();
+        let ul_close = html.rfind("</ul>").unwrap();
+        assert!(ul_open < ol_open);
+        assert!(ol_open < ol_close);
+        assert!(ol_close < ul_close);
     }
 
     #[test]
-    fn month_days_leap_feb() {
-        assert_eq!(month_days(2024, 2), 29);
+    fn md_to_html_task_list() {
+        let tmp = tempfile::tempdir().unwrap();
+        let html = md_to_html("- [ ] open item\n- [x] closed item", tmp.path());
+        assert!(html.contains("<input type=\"checkbox\" disabled> open item"));
+        assert!(html.contains("<input type=\"checkbox\" disabled checked> closed item"));
     }
 
     #[test]
-    fn current_date_format() {
-        let d = current_date();
-        assert_eq!(d.len(), 10);
-        assert_eq!(&d[4..5], "/");
-        assert_eq!(&d[7..8], "/");
+    fn md_to_html_blockquote() {
+        let tmp = tempfile::tempdir().unwrap();
+        let html = md_to_html("> a quoted remediation note", tmp.path());
+        assert!(html.contains("<blockquote>"));
+        assert!(html.contains("a quoted remediation note"));
+        assert!(html.contains("</blockquote>"));
     }
 
-    // ── helper functions ──
+    #[test]
+    fn md_to_html_table() {
+        let tmp = tempfile::tempdir().unwrap();
+        let html = md_to_html("| A | B |\n|---|---|\n| 1 | 2 |", tmp.path());
+        assert!(html.contains("<table>"));
+        assert!(html.contains("<th style=\"text-align:left\">A</th>"));
+        assert!(html.contains("<td style=\"text-align:left\">1</td>"));
+    }
 
     #[test]
-    fn try_parse_link_valid() {
-        let chars: Vec<char> = "[docs](https://x.com) rest".chars().collect();
-        let result = try_parse_link(&chars, 0);
-        assert!(result.is_some());
-        let (display, url, end) = result.unwrap();
-        assert_eq!(display, "docs");
-        assert_eq!(url, "https://x.com");
-        assert_eq!(end, 21);
+    fn md_to_html_code_block() {
+        let tmp = tempfile::tempdir().unwrap();
+        let html = md_to_html("```\nlet x = 1;\n```", tmp.path());
+        assert_eq!(html, "<pre><code>let x = 1;</code></pre>\n");
     }
 
     #[test]
-    fn try_parse_link_invalid_no_paren() {
-        let chars: Vec<char> = "[docs] rest".chars().collect();
-        let result = try_parse_link(&chars, 0);
-        assert!(result.is_none());
+    fn md_to_html_code_block_highlights_known_lang() {
+        let tmp = tempfile::tempdir().unwrap();
+        let html = md_to_html("```json\n{\"a\": true}\n```", tmp.path());
+        assert!(html.contains("class=\"language-json\""));
+        assert!(html.contains("<span class=\"tok-kw\">true</span>"));
     }
 
     #[test]
-    fn extract_delimited_backtick() {
-        let chars: Vec<char> = "`code` rest".chars().collect();
-        let result = extract_delimited(&chars, 0, '`');
-        assert!(result.is_some());
-        let (content, end) = result.unwrap();
-        assert_eq!(content, "code");
-        assert_eq!(end, 6);
+    fn code_block_to_html_unknown_lang_falls_back_to_plain() {
+        let html = code_block_to_html(Some("brainfuck"), "+++.");
+        assert_eq!(html, "<pre><code>+++.</code></pre>\n");
     }
 
     #[test]
-    fn extract_delimited_empty_returns_none() {
-        let chars: Vec<char> = "`` rest".chars().collect();
-        let result = extract_delimited(&chars, 0, '`');
-        assert!(result.is_none());
+    fn format_ieee_reference_html_basic() {
+        let entry = BibEntry {
+            entry_type: "article".into(),
+            authors: vec!["Smith, John".into()],
+            title: "On Widgets".into(),
+            year: "2021".into(),
+            journal: "Widget Journal".into(),
+            url: String::new(),
+        };
+        let html = format_ieee_reference_html(3, &entry);
+        assert!(html.contains("id=\"ref-3\""));
+        assert!(html.contains("[3] J. Smith"));
+        assert!(html.contains("<em>Widget Journal</em>"));
     }
 
     #[test]
-    fn extract_between_double_star() {
-        let chars: Vec<char> = "bold** rest".chars().collect();
-        let result = extract_between(&chars, 0, "**");
-        assert!(result.is_some());
-        let (content, end) = result.unwrap();
-        assert_eq!(content, "bold");
-        assert_eq!(end, 6);
+    fn format_apa_reference_html_basic() {
+        let entry = BibEntry {
+            entry_type: "article".into(),
+            authors: vec!["Smith, John".into()],
+            title: "On Widgets".into(),
+            year: "2021".into(),
+            journal: "Widget Journal".into(),
+            url: String::new(),
+        };
+        let html = format_apa_reference_html(&entry);
+        assert!(html.contains("Smith, J. (2021). On Widgets."));
     }
 
     #[test]
-    fn extract_between_no_match() {
-        let chars: Vec<char> = "no end marker".chars().collect();
-        let result = extract_between(&chars, 0, "**");
-        assert!(result.is_none());
+    fn render_references_html_empty_is_blank() {
+        assert_eq!(render_references_html(&[], BibStyle::Ieee), "");
     }
 
-    // ── integration: blocks_to_latex with mixed content ──
+    #[test]
+    fn render_references_html_nonempty_has_section() {
+        let entry = BibEntry::default();
+        let html = render_references_html(&[("k".into(), entry)], BibStyle::Ieee);
+        assert!(html.contains("<section class=\"references\">"));
+        assert!(html.contains("<ol>"));
+    }
 
     #[test]
-    fn integration_mixed_blocks() {
+    fn blocks_to_html_title_and_section_structure() {
+        let tmp = tempfile::tempdir().unwrap();
         let blocks = vec![
             Block::Title("Security Report".into()),
-            Block::Subtitle("acme.corp".into()),
-            Block::PageBreak,
-            Block::Section("Executive Summary".into()),
-            Block::Text("This is a **test** report.".into()),
-            Block::Spacer(4.0),
-            Block::Table(vec![
-                vec!["Sev".into(), "Count".into()],
-                vec!["Critical".into(), "2".into()],
-            ]),
-            Block::PageBreak,
             Block::Section("Findings".into()),
-            Block::Finding("Critical".into(), "1. SQL Injection".into()),
-            Block::Meta("Asset".into(), "web.corp".into()),
-            Block::Text("Description with `code` and **bold**.".into()),
-            Block::HRule,
         ];
-        let latex = blocks_to_latex(&blocks, "test");
+        let html = blocks_to_html(&blocks, "web.corp", tmp.path());
+        assert!(html.contains("<!DOCTYPE html>"));
+        assert!(html.contains("<title>web.corp</title>"));
+        assert!(html.contains("<h1>Security Report</h1>"));
+        assert!(html.contains("id=\"findings\""));
+    }
 
-        // Verify document structure
-        assert!(latex.contains(r"\documentclass"));
-        assert!(latex.contains(r"\begin{document}"));
-        assert!(latex.contains(r"\end{document}"));
+    #[test]
+    fn blocks_to_html_index_links_to_sections() {
+        let tmp = tempfile::tempdir().unwrap();
+        let blocks = vec![
+            Block::Index,
+            Block::Section("Recon".into()),
+        ];
+        let html = blocks_to_html(&blocks, "test", tmp.path());
+        assert!(html.contains("<nav class=\"toc\">"));
+        assert!(html.contains("href=\"#recon\">Recon</a>"));
+    }
 
-        // Verify blocks rendered
-        assert!(latex.contains("Security Report"));
-        assert!(latex.contains("acme.corp"));
-        assert!(latex.contains(r"\clearpage"));
-        assert!(latex.contains(r"\section{Executive Summary}"));
-        assert!(latex.contains(r"\textbf{test}"));
-        assert!(latex.contains(r"\begin{tabularx}"));
-        assert!(latex.contains(r"\section{Findings}"));
-        assert!(latex.contains("SQL Injection"));
-        assert!(latex.contains(r"\code{code}"));
+    #[test]
+    fn blocks_to_html_index_disambiguates_duplicate_section_names() {
+        let tmp = tempfile::tempdir().unwrap();
+        let blocks = vec![
+            Block::Index,
+            Block::Section("Recon".into()),
+            Block::Section("Recon".into()),
+        ];
+        let html = blocks_to_html(&blocks, "test", tmp.path());
+        assert!(html.contains("id=\"recon\""));
+        assert!(html.contains("id=\"recon-2\""));
+        assert!(html.contains("href=\"#recon\">Recon</a>"));
+        assert!(html.contains("href=\"#recon-2\">Recon</a>"));
     }
 
     #[test]
-    fn integration_full_parse_and_render() {
-        let input = "\
-#! title Test Report
-#! subtitle target.local
-#! spacer 8
-#! meta Date: 2025/01/01
-#! pagebreak
-#! section Summary
-This report has **bold** and *italic* content.
-#! spacer 4
-#! table
-Severity | Count
-Critical | 1
-#! pagebreak
-#! section Findings
-#! finding High 1. XSS Attack
-#! meta Severity: High
-#! meta Asset: web.app
-Reflected XSS in the `search` parameter.
-- Step 1: inject payload
-- Step 2: observe alert
-#! hr
-";
-        let blocks = parse_blocks(input);
-        let latex = blocks_to_latex(&blocks, "test");
+    fn blocks_to_html_finding_has_severity_class_and_badge() {
+        let tmp = tempfile::tempdir().unwrap();
+        let blocks = vec![Block::Finding("Critical".into(), "SQLi".into())];
+        let html = blocks_to_html(&blocks, "test", tmp.path());
+        assert!(html.contains("class=\"finding sev-critical\""));
+        assert!(html.contains("SQLi"));
+        assert!(html.contains("<span class=\"badge\">Critical</span>"));
+    }
 
-        assert!(latex.contains(r"\documentclass"));
-        assert!(latex.contains("Test Report"));
-        assert!(latex.contains("target.local"));
-        assert!(latex.contains(r"\section{Summary}"));
-        assert!(latex.contains(r"\textbf{bold}"));
-        assert!(latex.contains(r"\textit{italic}"));
-        assert!(latex.contains(r"\begin{tabularx}"));
-        assert!(latex.contains("SevHigh"));
-        assert!(latex.contains("XSS Attack"));
-        assert!(latex.contains(r"\code{search}"));
-        assert!(latex.contains(r"\begin{itemize}"));
-        assert!(latex.contains(r"\item"));
-        assert!(latex.contains(r"\end{itemize}"));
-        // Finding starts on its own page
-        assert!(latex.contains(r"\clearpage"));
+    #[test]
+    fn blocks_to_html_table_renders_header_and_rows() {
+        let tmp = tempfile::tempdir().unwrap();
+        let blocks = vec![Block::Table(vec![], vec![
+            vec!["Name".into(), "Severity".into()],
+            vec!["SQLi".into(), "Critical".into()],
+        ])];
+        let html = blocks_to_html(&blocks, "test", tmp.path());
+        assert!(html.contains("<th>Name</th>"));
+        assert!(html.contains("<td>SQLi</td>"));
     }
 
-    // ── finding page break ──
+    #[test]
+    fn blocks_to_html_table_applies_column_alignment() {
+        let tmp = tempfile::tempdir().unwrap();
+        let blocks = vec![Block::Table(vec![ColAlign::Center, ColAlign::Right], vec![
+            vec!["Name".into(), "Score".into()],
+            vec!["alice".into(), "9".into()],
+        ])];
+        let html = blocks_to_html(&blocks, "test", tmp.path());
+        assert!(html.contains("<th style=\"text-align:center\">Name</th>"));
+        assert!(html.contains("<td style=\"text-align:right\">9</td>"));
+    }
 
     #[test]
-    fn finding_starts_on_new_page() {
-        let blocks = vec![
-            Block::Text("Some text.".into()),
-            Block::Finding("High".into(), "1. Test".into()),
-        ];
-        let latex = blocks_to_latex(&blocks, "test");
-        let finding_pos = latex.find("1. Test").unwrap();
-        let clearpage_before = latex[..finding_pos].rfind(r"\clearpage");
-        assert!(clearpage_before.is_some());
+    fn blocks_to_html_latex_block_is_dropped() {
+        let tmp = tempfile::tempdir().unwrap();
+        let blocks = vec![Block::Latex(r"\vspace{4mm}".into())];
+        let html = blocks_to_html(&blocks, "test", tmp.path());
+        assert!(!html.contains(r"\vspace"));
     }
 
     #[test]
-    fn first_finding_after_section_no_clearpage() {
-        let blocks = vec![
-            Block::Section("Detailed Findings".into()),
-            Block::Finding("High".into(), "1. Test".into()),
-        ];
-        let latex = blocks_to_latex(&blocks, "test");
-        let section_pos = latex.find(r"\section{Detailed Findings}").unwrap();
-        let finding_pos = latex.find("1. Test").unwrap();
-        let between = &latex[section_pos..finding_pos];
-        assert!(!between.contains(r"\clearpage"));
+    fn blocks_to_html_page_break_and_hrule() {
+        let tmp = tempfile::tempdir().unwrap();
+        let blocks = vec![Block::PageBreak, Block::HRule];
+        let html = blocks_to_html(&blocks, "test", tmp.path());
+        assert!(html.contains("<div class=\"page-break\"></div>"));
+        assert!(html.contains("<hr>"));
     }
 
     #[test]
-    fn multiple_findings_each_on_own_page() {
+    fn integration_html_bibliography_auto_references() {
+        let tmp = tempfile::tempdir().unwrap();
+        let bib_path = tmp.path().join("refs.bib");
+        fs::write(
+            &bib_path,
+            r#"@article{smith2021,
+                author = {Smith, John},
+                title = {On Widgets},
+                year = {2021},
+                journal = {Widget Journal},
+            }"#,
+        ).unwrap();
         let blocks = vec![
-            Block::Finding("Critical".into(), "1. First".into()),
-            Block::Text("Description.".into()),
-            Block::Finding("High".into(), "2. Second".into()),
+            Block::Bibliography(bib_path.to_string_lossy().into_owned(), BibStyle::Ieee),
+            Block::Text("See [@smith2021] for details.".into()),
         ];
-        let latex = blocks_to_latex(&blocks, "test");
-        let clearpage_count = latex.matches(r"\clearpage").count();
-        assert!(clearpage_count >= 2);
+        let html = blocks_to_html(&blocks, "test", tmp.path());
+        assert!(html.contains("See [1] for details."));
+        assert!(html.contains("<section class=\"references\">"));
+        assert!(html.contains("[1] J. Smith"));
     }
 
-    // ── render_pdf error on invalid LaTeX ──
-
     #[test]
-    fn render_pdf_with_empty_latex_handles_error() {
-        // Empty input is not valid LaTeX — tectonic should return an error.
+    fn integration_html_reference_list() {
         let tmp = tempfile::tempdir().unwrap();
-        let result = render_pdf("", "/tmp/pog_test_nonexistent.pdf", tmp.path());
-        assert!(result.is_err());
+        let blocks = vec![
+            Block::Reference("CVE-1".into(), "First bug".into(), "https://example.com/1".into()),
+            Block::Text("Found [[CVE-1]].".into()),
+            Block::ReferenceList,
+        ];
+        let html = blocks_to_html(&blocks, "test", tmp.path());
+        assert!(html.contains("Found <sup><a href=\"#ref-CVE-1\">[1]</a></sup>."));
+        assert!(html.contains("<li id=\"ref-CVE-1\"><a href=\"https://example.com/1\">First bug</a></li>"));
     }
 }
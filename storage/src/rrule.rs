@@ -0,0 +1,209 @@
+// storage/src/rrule.rs — a small RRULE subset for `--repeat`-driven report
+// and export generation.
+//
+// This is intentionally not a full RFC 5545 implementation: it only
+// understands the handful of fields `pog report --repeat` and `pog export
+// --repeat` need (`FREQ`, `INTERVAL`, `COUNT`, `UNTIL`). Day arithmetic
+// reuses `models::dates`, the same ordinal/month math the TUI's Graph tab
+// uses to bucket findings by day/week/month.
+
+use models::dates::{add_months, day_ordinal, format_ymd};
+
+use crate::error::{Result, StorageError};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Freq {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// A parsed `FREQ=...;INTERVAL=n;COUNT=n;UNTIL=YYYYMMDD` recurrence rule.
+pub struct Rrule {
+    freq: Freq,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<(i32, u32, u32)>,
+}
+
+impl Rrule {
+    /// Parse a `;`-separated `KEY=VALUE` recurrence string, e.g.
+    /// `"FREQ=WEEKLY;INTERVAL=2;COUNT=6"` or `"FREQ=MONTHLY;UNTIL=20241231"`.
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut freq = None;
+        let mut interval = 1u32;
+        let mut count = None;
+        let mut until = None;
+
+        for part in s.split(';') {
+            let part = part.trim();
+            if part.is_empty() { continue; }
+            let (key, value) = part.split_once('=').ok_or_else(|| {
+                StorageError::RruleError(format!("malformed RRULE part: {part}"))
+            })?;
+
+            match key.to_uppercase().as_str() {
+                "FREQ" => {
+                    freq = Some(match value.to_uppercase().as_str() {
+                        "DAILY" => Freq::Daily,
+                        "WEEKLY" => Freq::Weekly,
+                        "MONTHLY" => Freq::Monthly,
+                        other => {
+                            return Err(StorageError::RruleError(format!(
+                                "unsupported FREQ: {other} (expected DAILY, WEEKLY, or MONTHLY)"
+                            )));
+                        }
+                    });
+                }
+                "INTERVAL" => {
+                    interval = value.parse().map_err(|_| {
+                        StorageError::RruleError(format!("invalid INTERVAL: {value}"))
+                    })?;
+                }
+                "COUNT" => {
+                    count = Some(value.parse().map_err(|_| {
+                        StorageError::RruleError(format!("invalid COUNT: {value}"))
+                    })?);
+                }
+                "UNTIL" => {
+                    until = Some(parse_until(value).ok_or_else(|| {
+                        StorageError::RruleError(format!("invalid UNTIL (expected YYYYMMDD): {value}"))
+                    })?);
+                }
+                other => {
+                    return Err(StorageError::RruleError(format!("unsupported RRULE field: {other}")));
+                }
+            }
+        }
+
+        let freq = freq.ok_or_else(|| StorageError::RruleError("RRULE is missing FREQ".to_string()))?;
+        if interval == 0 {
+            return Err(StorageError::RruleError("INTERVAL must be at least 1".to_string()));
+        }
+
+        Ok(Self { freq, interval, count, until })
+    }
+
+    fn advance(&self, (y, m, d): (i32, u32, u32)) -> (i32, u32, u32) {
+        match self.freq {
+            Freq::Daily => models::dates::civil_from_ordinal(day_ordinal(y, m, d) + self.interval as i32),
+            Freq::Weekly => models::dates::civil_from_ordinal(day_ordinal(y, m, d) + self.interval as i32 * 7),
+            Freq::Monthly => add_months(y, m, d, self.interval),
+        }
+    }
+
+    /// Occurrence dates starting at `from` (inclusive), stopping once a
+    /// date would exceed `to` or `UNTIL` (whichever is earlier), or once
+    /// `COUNT` occurrences have been produced.
+    fn occurrences(&self, from: (i32, u32, u32), to: (i32, u32, u32)) -> Vec<(i32, u32, u32)> {
+        let to_ord = day_ordinal(to.0, to.1, to.2);
+        let until_ord = self.until.map(|(y, m, d)| day_ordinal(y, m, d));
+        let limit_ord = match until_ord {
+            Some(u) => u.min(to_ord),
+            None => to_ord,
+        };
+
+        let mut result = Vec::new();
+        let mut current = from;
+        loop {
+            let cur_ord = day_ordinal(current.0, current.1, current.2);
+            if cur_ord > limit_ord { break; }
+            if let Some(count) = self.count {
+                if result.len() as u32 >= count { break; }
+            }
+            result.push(current);
+            current = self.advance(current);
+        }
+        result
+    }
+
+    /// Split `[from, to]` into one inclusive `"YYYY/MM/DD"` window per
+    /// occurrence: each window runs up to the day before the next
+    /// occurrence, and the final window is clamped to `to`.
+    pub fn windows(&self, from: &str, to: &str) -> Result<Vec<(String, String)>> {
+        let from_ymd = models::dates::parse_ymd(from)
+            .ok_or_else(|| StorageError::RruleError(format!("invalid --from date: {from}")))?;
+        let to_ymd = models::dates::parse_ymd(to)
+            .ok_or_else(|| StorageError::RruleError(format!("invalid --to date: {to}")))?;
+
+        let occurrences = self.occurrences(from_ymd, to_ymd);
+        let to_ord = day_ordinal(to_ymd.0, to_ymd.1, to_ymd.2);
+
+        let windows = occurrences
+            .iter()
+            .enumerate()
+            .map(|(i, &(sy, sm, sd))| {
+                let end = match occurrences.get(i + 1) {
+                    Some(&(ny, nm, nd)) => {
+                        let end_ord = (day_ordinal(ny, nm, nd) - 1).min(to_ord);
+                        models::dates::civil_from_ordinal(end_ord)
+                    }
+                    None => to_ymd,
+                };
+                (format_ymd(sy, sm, sd), format_ymd(end.0, end.1, end.2))
+            })
+            .collect();
+
+        Ok(windows)
+    }
+}
+
+/// Parse an `UNTIL=YYYYMMDD` value (no separators, per RFC 5545).
+fn parse_until(value: &str) -> Option<(i32, u32, u32)> {
+    if value.len() < 8 { return None; }
+    let y = value[0..4].parse().ok()?;
+    let m = value[4..6].parse().ok()?;
+    let d = value[6..8].parse().ok()?;
+    Some((y, m, d))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rejects_missing_freq() {
+        assert!(Rrule::parse("INTERVAL=2").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_field() {
+        assert!(Rrule::parse("FREQ=DAILY;BYDAY=MO").is_err());
+    }
+
+    #[test]
+    fn test_weekly_windows_split_into_one_week_chunks() {
+        let rule = Rrule::parse("FREQ=WEEKLY").unwrap();
+        let windows = rule.windows("2025/01/01", "2025/01/21").unwrap();
+        assert_eq!(
+            windows,
+            vec![
+                ("2025/01/01".to_string(), "2025/01/07".to_string()),
+                ("2025/01/08".to_string(), "2025/01/14".to_string()),
+                ("2025/01/15".to_string(), "2025/01/21".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_count_limits_occurrences() {
+        let rule = Rrule::parse("FREQ=DAILY;COUNT=2").unwrap();
+        let windows = rule.windows("2025/01/01", "2025/01/31").unwrap();
+        assert_eq!(windows.len(), 2);
+    }
+
+    #[test]
+    fn test_until_clamps_before_to() {
+        let rule = Rrule::parse("FREQ=MONTHLY;UNTIL=20250201").unwrap();
+        let windows = rule.windows("2025/01/01", "2025/12/31").unwrap();
+        assert_eq!(windows.last().unwrap().1, "2025/12/31");
+        assert!(windows.len() <= 2);
+    }
+
+    #[test]
+    fn test_final_window_always_reaches_to() {
+        let rule = Rrule::parse("FREQ=DAILY;INTERVAL=3").unwrap();
+        let windows = rule.windows("2025/01/01", "2025/01/10").unwrap();
+        assert_eq!(windows.last().unwrap().1, "2025/01/10");
+    }
+}
@@ -0,0 +1,267 @@
+// storage/src/sanitize.rs — allowlist-based HTML sanitizer for imported
+// finding content.
+//
+// Findings imported via `import_finding`/`import_scan` often carry pasted
+// vendor-advisory or PoC-tool markup straight into `Finding::description`,
+// which later flows into `crate::report`'s template pipeline. Rather than
+// trust that content at render time, it's stripped down to a safe
+// allowlist here, at the import boundary.
+
+use std::collections::HashSet;
+
+/// Tags whose *contents* (not just the tags themselves) are dropped
+/// entirely — a `<script>` body is attacker payload, not safe text.
+const DROP_CONTENTS: &[&str] = &["script", "style", "iframe", "object", "embed", "form"];
+
+/// Allowlist of tags, attributes, and URL schemes used by [`sanitize_html`].
+/// Exposed so callers generating client-facing HTML reports can tighten or
+/// loosen it instead of being stuck with the built-in default.
+#[derive(Clone, Debug)]
+pub struct SanitizeConfig {
+    allowed_tags: HashSet<String>,
+    /// Attributes allowed on a specific tag, e.g. `href` on `<a>`.
+    tag_attrs: Vec<(String, HashSet<String>)>,
+    /// URL schemes allowed in `href`/`src` attribute values. A value with
+    /// no scheme at all (a relative path or anchor) is always allowed.
+    allowed_schemes: HashSet<String>,
+}
+
+impl Default for SanitizeConfig {
+    fn default() -> Self {
+        let allowed_tags = [
+            "h1", "h2", "h3", "h4", "h5", "h6", "p", "br", "hr",
+            "ul", "ol", "li", "strong", "em", "b", "i", "u",
+            "code", "pre", "blockquote",
+            "a", "img",
+            "table", "thead", "tbody", "tr", "th", "td",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        let tag_attrs = vec![
+            ("a".to_string(), ["href", "title"].into_iter().map(String::from).collect()),
+            ("img".to_string(), ["src", "alt", "title"].into_iter().map(String::from).collect()),
+        ];
+
+        let allowed_schemes = ["http", "https", "mailto"].into_iter().map(String::from).collect();
+
+        Self { allowed_tags, tag_attrs, allowed_schemes }
+    }
+}
+
+impl SanitizeConfig {
+    pub fn with_allowed_tag(mut self, tag: impl Into<String>) -> Self {
+        self.allowed_tags.insert(tag.into().to_lowercase());
+        self
+    }
+
+    pub fn without_allowed_tag(mut self, tag: &str) -> Self {
+        self.allowed_tags.remove(&tag.to_lowercase());
+        self
+    }
+
+    pub fn with_allowed_scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.allowed_schemes.insert(scheme.into().to_lowercase());
+        self
+    }
+}
+
+/// Strip `<script>`/event-handler/`javascript:`/`data:` payloads and any
+/// tag or attribute not on `config`'s allowlist, leaving safe formatting
+/// (headings, lists, code blocks, links, images with vetted schemes) and
+/// any surrounding markdown untouched.
+///
+/// This is a targeted allowlist filter over `<...>` tags, not a full HTML
+/// parser — malformed or unclosed markup degrades to having its tags
+/// stripped rather than panicking.
+pub fn sanitize_html(text: &str, config: &SanitizeConfig) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+
+    while i < text.len() {
+        match text[i..].find('<') {
+            None => {
+                out.push_str(&text[i..]);
+                break;
+            }
+            Some(rel) => {
+                out.push_str(&text[i..i + rel]);
+                i += rel;
+            }
+        }
+
+        let Some(end_rel) = text[i..].find('>') else {
+            // Unterminated `<...`: drop the stray bracket, keep the rest as text.
+            out.push_str(&text[i + 1..]);
+            break;
+        };
+        let end = i + end_rel;
+        let tag_src = &text[i + 1..end];
+        i = end + 1;
+
+        let is_closing = tag_src.starts_with('/');
+        let name_src = tag_src.trim_start_matches('/');
+        let name_end = name_src.find(|c: char| c.is_whitespace() || c == '/').unwrap_or(name_src.len());
+        let name = name_src[..name_end].to_lowercase();
+
+        if DROP_CONTENTS.contains(&name.as_str()) {
+            if !is_closing {
+                let closing = format!("</{name}>");
+                if let Some(pos) = text[i..].to_lowercase().find(&closing) {
+                    i += pos + closing.len();
+                }
+            }
+            continue;
+        }
+
+        if !config.allowed_tags.contains(&name) {
+            continue; // strip the tag; any text content around it is kept as-is
+        }
+
+        if is_closing {
+            out.push_str(&format!("</{name}>"));
+            continue;
+        }
+
+        let self_closing = tag_src.trim_end().ends_with('/');
+        let attrs = sanitize_attrs(name_src, &name, config);
+
+        out.push('<');
+        out.push_str(&name);
+        if !attrs.is_empty() {
+            out.push(' ');
+            out.push_str(&attrs);
+        }
+        if self_closing {
+            out.push_str(" /");
+        }
+        out.push('>');
+    }
+
+    out
+}
+
+/// Build the sanitized attribute string for an opening tag. `name_src` is
+/// the tag source starting at the tag name (e.g. `img src="x.png" /`).
+fn sanitize_attrs(name_src: &str, tag_name: &str, config: &SanitizeConfig) -> String {
+    let name_end = name_src.find(|c: char| c.is_whitespace()).unwrap_or(name_src.len());
+    let rest = name_src[name_end..].trim_end_matches('/').trim();
+    if rest.is_empty() {
+        return String::new();
+    }
+
+    let allowed_for_tag: HashSet<&str> = config
+        .tag_attrs
+        .iter()
+        .find(|(t, _)| t == tag_name)
+        .map(|(_, attrs)| attrs.iter().map(String::as_str).collect())
+        .unwrap_or_default();
+
+    let mut kept = Vec::new();
+    let mut remaining = rest;
+    while let Some((attr_name, value, rest)) = next_attr(remaining) {
+        remaining = rest;
+        let attr_name = attr_name.to_lowercase();
+
+        if attr_name.starts_with("on") {
+            continue; // event handlers are never allowed, regardless of tag
+        }
+        if !allowed_for_tag.contains(attr_name.as_str()) {
+            continue;
+        }
+        if (attr_name == "href" || attr_name == "src") && !scheme_allowed(&value, &config.allowed_schemes) {
+            continue;
+        }
+
+        kept.push(format!("{attr_name}=\"{}\"", escape_attr_value(&value)));
+    }
+
+    kept.join(" ")
+}
+
+/// Parse the next `name="value"` / `name='value'` / bare `name` pair from
+/// `s`, returning `(name, value, rest)`. Bare attributes get an empty value.
+fn next_attr(s: &str) -> Option<(&str, String, &str)> {
+    let s = s.trim_start();
+    if s.is_empty() {
+        return None;
+    }
+
+    let name_end = s.find(|c: char| c.is_whitespace() || c == '=').unwrap_or(s.len());
+    let name = &s[..name_end];
+    let rest = s[name_end..].trim_start();
+
+    let Some(after_eq) = rest.strip_prefix('=') else {
+        return Some((name, String::new(), rest));
+    };
+    let after_eq = after_eq.trim_start();
+
+    match after_eq.chars().next() {
+        Some(q @ ('"' | '\'')) => match after_eq[1..].find(q) {
+            Some(close) => Some((name, after_eq[1..1 + close].to_string(), &after_eq[1 + close + 1..])),
+            None => Some((name, after_eq[1..].to_string(), "")),
+        },
+        _ => {
+            let value_end = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+            Some((name, after_eq[..value_end].to_string(), &after_eq[value_end..]))
+        }
+    }
+}
+
+/// Whether `url` is safe to keep on an `href`/`src` attribute: either no
+/// scheme at all (a relative path or anchor) or one of `allowed`
+/// (notably excluding `javascript:` and `data:`).
+fn scheme_allowed(url: &str, allowed: &HashSet<String>) -> bool {
+    let url = url.trim();
+    match url.find(':') {
+        None => true,
+        Some(pos) if url[..pos].contains('/') => true, // a ':' inside a path segment, not a scheme
+        Some(pos) => allowed.contains(&url[..pos].to_lowercase()),
+    }
+}
+
+fn escape_attr_value(v: &str) -> String {
+    v.replace('&', "&amp;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_script_tags_and_contents() {
+        let input = "before <script>alert(document.cookie)</script> after";
+        assert_eq!(sanitize_html(input, &SanitizeConfig::default()), "before  after");
+    }
+
+    #[test]
+    fn strips_event_handlers() {
+        let input = r#"<img src="x.png" onerror="alert(1)">"#;
+        assert_eq!(sanitize_html(input, &SanitizeConfig::default()), r#"<img src="x.png">"#);
+    }
+
+    #[test]
+    fn strips_javascript_and_data_url_schemes() {
+        let input = r#"<a href="javascript:alert(1)">click</a> <img src="data:text/html,oops">"#;
+        assert_eq!(sanitize_html(input, &SanitizeConfig::default()), "<a>click</a> <img>");
+    }
+
+    #[test]
+    fn keeps_safe_formatting() {
+        let input = r#"<h2>Summary</h2><p>See <a href="https://example.com">advisory</a>.</p>"#;
+        assert_eq!(sanitize_html(input, &SanitizeConfig::default()), input);
+    }
+
+    #[test]
+    fn drops_disallowed_tags_but_keeps_text() {
+        let input = "<marquee>important</marquee>";
+        assert_eq!(sanitize_html(input, &SanitizeConfig::default()), "important");
+    }
+
+    #[test]
+    fn custom_config_can_tighten_the_allowlist() {
+        let config = SanitizeConfig::default().without_allowed_tag("img");
+        assert_eq!(sanitize_html(r#"<img src="x.png">text"#, &config), "text");
+    }
+}
@@ -0,0 +1,145 @@
+//! SARIF 2.1.0 export of findings, for uploading `pog` results to GitHub/
+//! GitLab code-scanning pipelines.
+//!
+//! This is the inverse of [`crate::adapters::SarifAdapter`] — instead of
+//! reading `ruleId`/`level`/`message.text`/a location URI out of someone
+//! else's SARIF log, it builds one from a client's [`Finding`]s.
+
+use models::{Finding, Severity};
+use serde_json::json;
+
+use crate::error::{Result, StorageError};
+
+/// Serialize `findings` as a SARIF 2.1.0 JSON log with a single `run`.
+///
+/// Each distinct finding `slug` is registered once as a `reportingDescriptor`
+/// rule (with its title and a severity tag); each finding becomes one
+/// `result` referencing that rule.
+pub fn findings_to_sarif(findings: &[Finding]) -> Result<String> {
+    let mut rules = Vec::new();
+    let mut seen_slugs = std::collections::HashSet::new();
+    let mut results = Vec::new();
+
+    for finding in findings {
+        if seen_slugs.insert(finding.slug.clone()) {
+            rules.push(json!({
+                "id": finding.slug,
+                "name": finding.title,
+                "shortDescription": { "text": finding.title },
+                "properties": { "tags": [finding.severity.as_str()] },
+            }));
+        }
+
+        results.push(json!({
+            "ruleId": finding.slug,
+            "level": severity_to_sarif_level(finding.severity),
+            "message": { "text": finding.description },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": location_uri(finding) }
+                }
+            }],
+        }));
+    }
+
+    let log = json!({
+        "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "pog",
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }],
+    });
+
+    serde_json::to_string_pretty(&log)
+        .map_err(|e| StorageError::SarifError(format!("failed to serialize SARIF log: {e}")))
+}
+
+/// Map a finding's severity onto SARIF's three result levels.
+fn severity_to_sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical | Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low | Severity::Info => "note",
+    }
+}
+
+/// Prefer the finding's own location; fall back to the asset name so every
+/// result still has an artifact URI.
+fn location_uri(finding: &Finding) -> &str {
+    if finding.location.is_empty() {
+        &finding.asset
+    } else {
+        &finding.location
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use models::Status;
+
+    fn sample_finding() -> Finding {
+        Finding::new(
+            "SQL Injection",
+            Severity::Critical,
+            "web_app",
+            "2026/01/15",
+            "https://example.com/api/users?id=1",
+            "User input concatenated directly into SQL query.",
+            Status::Open,
+        )
+    }
+
+    #[test]
+    fn test_output_is_valid_json_with_expected_shape() {
+        let sarif = findings_to_sarif(&[sample_finding()]).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+        assert_eq!(parsed["version"], "2.1.0");
+        assert_eq!(parsed["runs"][0]["tool"]["driver"]["name"], "pog");
+        assert_eq!(parsed["runs"][0]["results"][0]["ruleId"], "sql-injection");
+        assert_eq!(parsed["runs"][0]["results"][0]["level"], "error");
+    }
+
+    #[test]
+    fn test_severity_to_level_mapping() {
+        assert_eq!(severity_to_sarif_level(Severity::Critical), "error");
+        assert_eq!(severity_to_sarif_level(Severity::High), "error");
+        assert_eq!(severity_to_sarif_level(Severity::Medium), "warning");
+        assert_eq!(severity_to_sarif_level(Severity::Low), "note");
+        assert_eq!(severity_to_sarif_level(Severity::Info), "note");
+    }
+
+    #[test]
+    fn test_duplicate_slugs_register_one_rule() {
+        let findings = vec![sample_finding(), sample_finding()];
+        let sarif = findings_to_sarif(&findings).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+        let rules = parsed["runs"][0]["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 1);
+        let results = parsed["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn test_empty_location_falls_back_to_asset() {
+        let finding = Finding::new(
+            "Missing HSTS",
+            Severity::Info,
+            "nexus_portal",
+            "2026/01/01",
+            "",
+            "Strict-Transport-Security header not set.",
+            Status::Open,
+        );
+        let sarif = findings_to_sarif(&[finding]).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+        let uri = &parsed["runs"][0]["results"][0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"];
+        assert_eq!(uri, "nexus_portal");
+    }
+}
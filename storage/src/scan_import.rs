@@ -0,0 +1,332 @@
+//! Import findings straight from third-party scanner output (Nessus/Burp
+//! Suite exports, or a generic JSON shape), for teams that already ran an
+//! automated tool and don't want to re-type its results as native findings.
+//!
+//! Unlike [`crate::adapters`] (which plugs into `pog import-findings` and
+//! always succeeds or fails as a whole file), a scanner export routinely
+//! has a handful of malformed/incomplete records in an otherwise-good
+//! file. [`import_scan`] takes a `warn` callback so the caller can report
+//! each skipped record (e.g. with `error!`/`info!`) without one bad entry
+//! aborting the rest of the import. No `pog` sub-command calls it.
+
+use std::fs;
+use std::path::Path;
+
+use models::{Finding, Severity, Status};
+
+use crate::error::{Result, StorageError};
+use crate::import::normalise_asset;
+use crate::sanitize::{sanitize_html, SanitizeConfig};
+
+/// Which third-party format a scanner export is in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScanFormat {
+    /// Array of `{title, severity, host, url, description, cve}` objects.
+    Json,
+    /// Nessus `.nessus` XML (`ReportHost`/`ReportItem` elements).
+    Nessus,
+    /// Burp Suite XML (`issue` elements).
+    Burp,
+}
+
+impl std::str::FromStr for ScanFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(ScanFormat::Json),
+            "nessus" => Ok(ScanFormat::Nessus),
+            "burp" => Ok(ScanFormat::Burp),
+            other => Err(format!("unknown scan format: {other}")),
+        }
+    }
+}
+
+/// Parse a scanner export at `path`, skipping (and reporting via `warn`)
+/// any record that's missing a required field instead of failing the
+/// whole import.
+pub fn import_scan(path: &Path, format: ScanFormat, mut warn: impl FnMut(&str)) -> Result<Vec<Finding>> {
+    let raw = fs::read_to_string(path)?;
+    match format {
+        ScanFormat::Json => parse_json(&raw, &mut warn),
+        ScanFormat::Nessus => parse_nessus(&raw, &mut warn),
+        ScanFormat::Burp => parse_burp(&raw, &mut warn),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Generic JSON: array of {title, severity, host, url, description, cve}
+// ---------------------------------------------------------------------------
+
+fn parse_json(raw: &str, warn: &mut impl FnMut(&str)) -> Result<Vec<Finding>> {
+    let root: serde_json::Value = serde_json::from_str(raw)
+        .map_err(|e| StorageError::ParseError(format!("invalid scan JSON: {e}")))?;
+    let items = root
+        .as_array()
+        .ok_or_else(|| StorageError::ParseError("expected a JSON array of records".to_string()))?;
+
+    let mut findings = Vec::with_capacity(items.len());
+    for (i, item) in items.iter().enumerate() {
+        let title = match item.get("title").and_then(|v| v.as_str()) {
+            Some(t) if !t.is_empty() => t.to_string(),
+            _ => {
+                warn(&StorageError::ParseError(format!("record {i}: missing title")).to_string());
+                continue;
+            }
+        };
+
+        let severity = item
+            .get("severity")
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(Severity::Info);
+
+        let host = item.get("host").and_then(|v| v.as_str()).unwrap_or_default();
+        let url = item.get("url").and_then(|v| v.as_str()).unwrap_or_default();
+        let mut description = item.get("description").and_then(|v| v.as_str()).unwrap_or_default().to_string();
+        if let Some(cve) = item.get("cve").and_then(|v| v.as_str()) {
+            description = format!("{description} (CVE: {cve})");
+        }
+        let description = sanitize_html(&description, &SanitizeConfig::default());
+
+        findings.push(Finding::new(
+            title,
+            severity,
+            normalise_asset(host),
+            String::new(),
+            url.to_string(),
+            description,
+            Status::Open,
+        ));
+    }
+    Ok(findings)
+}
+
+// ---------------------------------------------------------------------------
+// Nessus XML: <ReportHost name="..."><ReportItem severity="0-4" pluginName="...">
+// ---------------------------------------------------------------------------
+
+/// Nessus severities are `0`–`4`; map onto pog's five-level scale directly.
+fn nessus_severity(raw: &str) -> Severity {
+    match raw {
+        "4" => Severity::Critical,
+        "3" => Severity::High,
+        "2" => Severity::Medium,
+        "1" => Severity::Low,
+        _ => Severity::Info,
+    }
+}
+
+fn parse_nessus(raw: &str, warn: &mut impl FnMut(&str)) -> Result<Vec<Finding>> {
+    let mut findings = Vec::new();
+
+    for (host_i, host_block) in xml_elements(raw, "ReportHost").into_iter().enumerate() {
+        let host = xml_attr(&host_block, "ReportHost", "name").unwrap_or_else(|| "unknown".to_string());
+
+        for (item_i, item) in xml_elements(&host_block, "ReportItem").into_iter().enumerate() {
+            let title = match xml_attr(&item, "ReportItem", "pluginName") {
+                Some(t) if !t.is_empty() => t,
+                _ => {
+                    warn(&StorageError::ParseError(format!(
+                        "ReportHost {host_i} item {item_i}: missing pluginName"
+                    )).to_string());
+                    continue;
+                }
+            };
+
+            let severity = xml_attr(&item, "ReportItem", "severity")
+                .map(|s| nessus_severity(&s))
+                .unwrap_or(Severity::Info);
+            let description = xml_tag_text(&item, "description").unwrap_or_default();
+            let description = sanitize_html(&description, &SanitizeConfig::default());
+
+            findings.push(Finding::new(
+                title,
+                severity,
+                normalise_asset(&host),
+                String::new(),
+                host.clone(),
+                description,
+                Status::Open,
+            ));
+        }
+    }
+
+    Ok(findings)
+}
+
+// ---------------------------------------------------------------------------
+// Burp Suite XML: <issue><name>...</name><host>...</host><severity>...</severity>
+// ---------------------------------------------------------------------------
+
+fn burp_severity(raw: &str) -> Severity {
+    raw.parse().unwrap_or(Severity::Info)
+}
+
+fn parse_burp(raw: &str, warn: &mut impl FnMut(&str)) -> Result<Vec<Finding>> {
+    let mut findings = Vec::new();
+
+    for (i, issue) in xml_elements(raw, "issue").into_iter().enumerate() {
+        let title = match xml_tag_text(&issue, "name") {
+            Some(t) if !t.is_empty() => t,
+            _ => {
+                warn(&StorageError::ParseError(format!("issue {i}: missing name")).to_string());
+                continue;
+            }
+        };
+
+        let severity = xml_tag_text(&issue, "severity").map(|s| burp_severity(&s)).unwrap_or(Severity::Info);
+        let host = xml_tag_text(&issue, "host").unwrap_or_default();
+        let path = xml_tag_text(&issue, "path").unwrap_or_default();
+        let description = xml_tag_text(&issue, "issueBackground")
+            .or_else(|| xml_tag_text(&issue, "issueDetail"))
+            .unwrap_or_default();
+        let description = sanitize_html(&description, &SanitizeConfig::default());
+
+        findings.push(Finding::new(
+            title,
+            severity,
+            normalise_asset(&host),
+            String::new(),
+            format!("{host}{path}"),
+            description,
+            Status::Open,
+        ));
+    }
+
+    Ok(findings)
+}
+
+// ---------------------------------------------------------------------------
+// Minimal, intentionally lenient XML scraping
+// ---------------------------------------------------------------------------
+//
+// Scanner exports are large and deeply nested; rather than pull in a full
+// XML parser, these helpers just locate `<tag ...>...</tag>` blocks by
+// string search, the same lenient-parsing tradeoff `CsvAdapter` makes for
+// quoted commas. They don't handle self-closing tags, CDATA, or nested
+// tags of the same name.
+
+/// Split `xml` into the raw contents of every top-level `<tag ...>...</tag>`
+/// block (attributes included, for [`xml_attr`] to read back out).
+fn xml_elements(xml: &str, tag: &str) -> Vec<String> {
+    let open_prefix = format!("<{tag}");
+    let close_tag = format!("</{tag}>");
+    let mut out = Vec::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find(&open_prefix) {
+        let after_start = &rest[start..];
+        let Some(close_rel) = after_start.find(&close_tag) else { break };
+        let block_end = close_rel + close_tag.len();
+        out.push(after_start[..block_end].to_string());
+        rest = &after_start[block_end..];
+    }
+    out
+}
+
+/// Read attribute `name` off a `<tag attr="value" ...>` opening tag.
+fn xml_attr(block: &str, tag: &str, name: &str) -> Option<String> {
+    let open_prefix = format!("<{tag}");
+    let start = block.find(&open_prefix)?;
+    let tag_end = block[start..].find('>')? + start;
+    let opening = &block[start..tag_end];
+
+    let needle = format!("{name}=\"");
+    let attr_start = opening.find(&needle)? + needle.len();
+    let attr_end = opening[attr_start..].find('"')? + attr_start;
+    Some(opening[attr_start..attr_end].trim().to_string())
+}
+
+/// Read the text content of a `<tag>...</tag>` element anywhere in `xml`.
+fn xml_tag_text(xml: &str, tag: &str) -> Option<String> {
+    let open_prefix = format!("<{tag}");
+    let start = xml.find(&open_prefix)?;
+    let tag_open_end = xml[start..].find('>')? + start + 1;
+    let close_tag = format!("</{tag}>");
+    let end = xml[tag_open_end..].find(&close_tag)? + tag_open_end;
+    Some(xml[tag_open_end..end].trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_json_skips_missing_title() {
+        let mut warnings = Vec::new();
+        let raw = r#"[
+            {"title": "Log4Shell", "severity": "critical", "host": "10.0.0.1", "url": "/jndi", "description": "RCE", "cve": "CVE-2021-44228"},
+            {"severity": "high", "description": "no title here"}
+        ]"#;
+        let findings = parse_json(raw, &mut |w| warnings.push(w.to_string())).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].title, "Log4Shell");
+        assert_eq!(findings[0].severity, Severity::Critical);
+        assert!(findings[0].description.contains("CVE-2021-44228"));
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_json_treats_informational_and_none_as_info() {
+        let raw = r#"[
+            {"title": "A", "severity": "informational", "host": "h", "url": "", "description": ""},
+            {"title": "B", "severity": "none", "host": "h", "url": "", "description": ""}
+        ]"#;
+        let findings = parse_json(raw, &mut |_| {}).unwrap();
+        assert_eq!(findings[0].severity, Severity::Info);
+        assert_eq!(findings[1].severity, Severity::Info);
+    }
+
+    #[test]
+    fn test_parse_nessus_extracts_report_items() {
+        let raw = r#"
+            <NessusClientData_v2>
+              <Report>
+                <ReportHost name="10.0.0.5">
+                  <ReportItem pluginID="1" pluginName="Outdated TLS" severity="2">
+                    <description>Server supports TLS 1.0.</description>
+                  </ReportItem>
+                  <ReportItem pluginID="2" severity="1">
+                    <description>missing plugin name</description>
+                  </ReportItem>
+                </ReportHost>
+              </Report>
+            </NessusClientData_v2>
+        "#;
+        let mut warnings = Vec::new();
+        let findings = parse_nessus(raw, &mut |w| warnings.push(w.to_string())).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].title, "Outdated TLS");
+        assert_eq!(findings[0].severity, Severity::Medium);
+        assert_eq!(findings[0].asset, "10_0_0_5");
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_burp_extracts_issues() {
+        let raw = r#"
+            <issues>
+              <issue>
+                <name>Cross-Site Scripting</name>
+                <host>https://example.com</host>
+                <path>/search</path>
+                <severity>High</severity>
+                <issueBackground>Reflected XSS in query parameter.</issueBackground>
+              </issue>
+            </issues>
+        "#;
+        let findings = parse_burp(raw, &mut |_| {}).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].title, "Cross-Site Scripting");
+        assert_eq!(findings[0].severity, Severity::High);
+        assert!(findings[0].location.contains("/search"));
+    }
+
+    #[test]
+    fn test_scan_format_from_str() {
+        assert_eq!("json".parse::<ScanFormat>().unwrap(), ScanFormat::Json);
+        assert_eq!("NESSUS".parse::<ScanFormat>().unwrap(), ScanFormat::Nessus);
+        assert!("bogus".parse::<ScanFormat>().is_err());
+    }
+}
@@ -0,0 +1,281 @@
+//! Full-text search over findings, backed by SQLite FTS5.
+//!
+//! A `finding_fts` virtual table mirrors the free-text `Finding` columns
+//! (title, description, location, asset, slug), keyed by `slug` (the only
+//! globally-unique identifier — `hex_id` repeats across assets). It's kept
+//! in sync by `crate::db::Database`'s write paths (`insert_finding`,
+//! `upsert_finding`) the same way `crate::embedding` keeps vectors in sync,
+//! except indexing here isn't optional — a finding that isn't searchable
+//! defeats the point.
+//!
+//! [`search`] ranks matches by BM25 and highlights matched terms in each
+//! result's description with `[...]` markers (the TUI strips or styles
+//! these) by substituting in the FTS5 `snippet()` output.
+
+use std::cmp::Ordering;
+
+use rusqlite::{params, Connection};
+
+use models::{Finding, Severity, Status};
+
+use crate::error::Result;
+
+/// Create the `finding_fts` virtual table if it doesn't already exist.
+pub(crate) fn migrate(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE VIRTUAL TABLE IF NOT EXISTS finding_fts USING fts5(
+            slug UNINDEXED,
+            title,
+            description,
+            location,
+            asset
+        );"
+    )?;
+    Ok(())
+}
+
+/// Replace the indexed row for `slug` (delete-then-insert, since FTS5 has
+/// no native upsert). `slug` is taken as a separate argument rather than
+/// `finding.slug`, mirroring `Database::insert_finding`/`upsert_finding`'s
+/// own explicit `slug` parameter (it's assigned by the caller, not always
+/// already set on `finding`).
+pub(crate) fn index(conn: &Connection, finding: &Finding, slug: &str) -> Result<()> {
+    conn.execute("DELETE FROM finding_fts WHERE slug = ?1", params![slug])?;
+    conn.execute(
+        "INSERT INTO finding_fts (slug, title, description, location, asset)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![slug, finding.title, finding.description, finding.location, finding.asset],
+    )?;
+    Ok(())
+}
+
+/// Cap on results returned by [`search`] — a search box, not a report.
+const MAX_RESULTS: usize = 50;
+
+/// A `severity:` field filter's comparison, e.g. `severity:>=High`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SeverityFilter {
+    Eq(Severity),
+    AtLeast(Severity),
+    AtMost(Severity),
+}
+
+impl SeverityFilter {
+    fn matches(&self, severity: Severity) -> bool {
+        match self {
+            SeverityFilter::Eq(s) => severity == *s,
+            SeverityFilter::AtLeast(s) => severity.cmp(s) != Ordering::Less,
+            SeverityFilter::AtMost(s) => severity.cmp(s) != Ordering::Greater,
+        }
+    }
+}
+
+/// Parse `query` into recognized `field:value` filters and the remaining
+/// free-text terms, e.g. `"log4j asset:orion_gateway severity:>=High
+/// status:Open"` → asset/severity/status filters and the term `"log4j"`.
+struct ParsedQuery {
+    asset: Option<String>,
+    severity: Option<SeverityFilter>,
+    status: Option<Status>,
+    terms: Vec<String>,
+}
+
+fn parse_query(query: &str) -> ParsedQuery {
+    let mut parsed = ParsedQuery { asset: None, severity: None, status: None, terms: Vec::new() };
+    for token in query.split_whitespace() {
+        if let Some(value) = token.strip_prefix("asset:") {
+            parsed.asset = Some(value.to_lowercase());
+        } else if let Some(value) = token.strip_prefix("severity:") {
+            parsed.severity = parse_severity_filter(value);
+        } else if let Some(value) = token.strip_prefix("status:") {
+            parsed.status = value.parse().ok();
+        } else {
+            parsed.terms.push(token.to_string());
+        }
+    }
+    parsed
+}
+
+/// Parse a `severity:` filter value, with an optional `>=`/`<=` comparator
+/// prefix (e.g. `>=High`, `<=Low`, or a bare `Critical` for exact match).
+fn parse_severity_filter(value: &str) -> Option<SeverityFilter> {
+    if let Some(rest) = value.strip_prefix(">=") {
+        Some(SeverityFilter::AtLeast(rest.parse().ok()?))
+    } else if let Some(rest) = value.strip_prefix("<=") {
+        Some(SeverityFilter::AtMost(rest.parse().ok()?))
+    } else {
+        Some(SeverityFilter::Eq(value.parse().ok()?))
+    }
+}
+
+/// Build an FTS5 `MATCH` expression from free-text terms. Each term is
+/// quoted as a literal phrase with a trailing `*` for prefix matching, so
+/// user input can't smuggle in FTS5 operators (`AND`, `NOT`, column
+/// filters, `NEAR`, …) — only plain prefix search.
+fn build_match_query(terms: &[String]) -> String {
+    terms
+        .iter()
+        .map(|term| format!("\"{}\"*", term.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Search findings by free text, ranked by BM25 relevance. Supports
+/// `asset:<name>`, `status:<status>`, and `severity:<level>` (optionally
+/// prefixed with `>=`/`<=` for a threshold instead of an exact match) field
+/// filters anywhere in `query`; everything else is treated as
+/// prefix-matched free text. Returns up to [`MAX_RESULTS`] `(Finding,
+/// score)` pairs — higher score is more relevant — with matched terms in
+/// each finding's description wrapped in `[...]` markers.
+pub(crate) fn search(conn: &Connection, query: &str) -> Result<Vec<(Finding, f64)>> {
+    let parsed = parse_query(query);
+
+    let mut clauses = Vec::new();
+    let mut values: Vec<String> = Vec::new();
+
+    // `bm25()`/`snippet()` are only meaningful alongside a MATCH clause, so
+    // a filter-only query (no free text) skips the FTS join entirely and
+    // ranks everything at 0.0.
+    if !parsed.terms.is_empty() {
+        clauses.push("finding_fts MATCH ?".to_string());
+        values.push(build_match_query(&parsed.terms));
+    }
+    if let Some(asset) = &parsed.asset {
+        clauses.push("f.asset = ?".to_string());
+        values.push(asset.clone());
+    }
+    if let Some(status) = &parsed.status {
+        clauses.push("f.status = ?".to_string());
+        values.push(status.as_str().to_string());
+    }
+
+    let where_clause = if clauses.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", clauses.join(" AND "))
+    };
+
+    // `severity:>=X`/`<=X` isn't a value SQLite can compare as text, so it's
+    // applied as a post-filter below; fetch a wider pool up front so the
+    // post-filter still has `MAX_RESULTS` worth of candidates to work with.
+    let fetch_limit = if parsed.severity.is_some() { MAX_RESULTS * 5 } else { MAX_RESULTS };
+
+    let sql = if parsed.terms.is_empty() {
+        format!(
+            "SELECT f.id, f.hex_id, f.slug, f.title, f.severity, f.asset, f.date, f.location,
+                    f.description, f.status, f.cvss_vector, 0.0 AS rank, f.description AS snippet
+             FROM findings f{where_clause}
+             ORDER BY f.asset, f.hex_id
+             LIMIT {fetch_limit}"
+        )
+    } else {
+        format!(
+            "SELECT f.id, f.hex_id, f.slug, f.title, f.severity, f.asset, f.date, f.location,
+                    f.description, f.status, f.cvss_vector,
+                    bm25(finding_fts) AS rank,
+                    snippet(finding_fts, 2, '[', ']', '…', 12) AS snippet
+             FROM finding_fts
+             JOIN findings f ON f.slug = finding_fts.slug{where_clause}
+             ORDER BY rank
+             LIMIT {fetch_limit}"
+        )
+    };
+
+    let mut stmt = conn.prepare(&sql)?;
+    let param_refs: Vec<&dyn rusqlite::types::ToSql> =
+        values.iter().map(|v| v as &dyn rusqlite::types::ToSql).collect();
+
+    let rows = stmt.query_map(param_refs.as_slice(), |row| {
+        let severity: String = row.get(4)?;
+        let status: String = row.get(9)?;
+        let snippet: String = row.get(12)?;
+        let finding = Finding {
+            id: row.get(0)?,
+            hex_id: row.get(1)?,
+            slug: row.get(2)?,
+            title: row.get(3)?,
+            severity: severity.parse().unwrap_or(Severity::Info),
+            asset: row.get(5)?,
+            date: row.get(6)?,
+            location: row.get(7)?,
+            description: snippet,
+            status: status.parse().unwrap_or(Status::Open),
+            images: Vec::new(),
+            cvss_vector: row.get(10)?,
+            snippet: None,
+            tags: Vec::new(),
+            references: Vec::new(),
+            cwe: None,
+            extra: std::collections::HashMap::new(),
+        };
+        let rank: f64 = row.get(11)?;
+        // BM25 in SQLite is a *cost* (lower is better); negate it so a
+        // higher returned score means a better match, matching the sense
+        // of `crate::db::Database::semantic_search`'s cosine similarity.
+        Ok((finding, -rank))
+    })?;
+
+    let mut results = Vec::new();
+    for row in rows {
+        results.push(row?);
+    }
+
+    if let Some(filter) = &parsed.severity {
+        results.retain(|(finding, _)| filter.matches(finding.severity));
+    }
+    results.truncate(MAX_RESULTS);
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_query_splits_filters_from_terms() {
+        let parsed = parse_query("log4j asset:orion_gateway severity:critical status:Open proto");
+        assert_eq!(parsed.asset.as_deref(), Some("orion_gateway"));
+        assert_eq!(parsed.severity, Some(SeverityFilter::Eq(Severity::Critical)));
+        assert_eq!(parsed.status, Some(Status::Open));
+        assert_eq!(parsed.terms, vec!["log4j".to_string(), "proto".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_query_ignores_unknown_severity() {
+        let parsed = parse_query("severity:bogus");
+        assert_eq!(parsed.severity, None);
+    }
+
+    #[test]
+    fn test_parse_query_severity_threshold_operators() {
+        assert_eq!(parse_query("severity:>=High").severity, Some(SeverityFilter::AtLeast(Severity::High)));
+        assert_eq!(parse_query("severity:<=Low").severity, Some(SeverityFilter::AtMost(Severity::Low)));
+    }
+
+    #[test]
+    fn test_severity_filter_matches_threshold() {
+        let at_least_high = SeverityFilter::AtLeast(Severity::High);
+        assert!(at_least_high.matches(Severity::Critical));
+        assert!(at_least_high.matches(Severity::High));
+        assert!(!at_least_high.matches(Severity::Medium));
+
+        let at_most_low = SeverityFilter::AtMost(Severity::Low);
+        assert!(at_most_low.matches(Severity::Info));
+        assert!(!at_most_low.matches(Severity::Medium));
+    }
+
+    #[test]
+    fn test_build_match_query_quotes_terms_as_prefix_phrases() {
+        assert_eq!(build_match_query(&["log4j".to_string()]), "\"log4j\"*");
+        assert_eq!(
+            build_match_query(&["log4j".to_string(), "proto".to_string()]),
+            "\"log4j\"* \"proto\"*"
+        );
+    }
+
+    #[test]
+    fn test_build_match_query_escapes_embedded_quotes() {
+        assert_eq!(build_match_query(&["a\"b".to_string()]), "\"a\"\"b\"*");
+    }
+}
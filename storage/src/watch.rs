@@ -0,0 +1,58 @@
+//! Filesystem watcher for the findings directory.
+//!
+//! [`watch_dir`] spawns a background thread that watches a directory tree
+//! recursively and coalesces bursts of events (e.g. a bulk import copying
+//! many files) into a single debounced [`ChangeEvent`] per ~200ms window, so
+//! a long-running TUI can re-query the database once per burst instead of
+//! once per file.
+
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::error::{Result, StorageError};
+
+/// Debounce window: events arriving within this long of each other are
+/// coalesced into a single [`ChangeEvent`].
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// A single coalesced change notification — at least one filesystem event
+/// occurred somewhere under the watched directory since the last signal.
+pub struct ChangeEvent;
+
+/// Watch `dir` recursively and stream debounced [`ChangeEvent`]s on the
+/// returned channel. The watcher (and its background thread) stay alive for
+/// as long as the receiver is held; dropping it stops the watcher.
+pub fn watch_dir(dir: &Path) -> Result<Receiver<ChangeEvent>> {
+    let (raw_tx, raw_rx) = mpsc::channel::<()>();
+    let mut watcher: RecommendedWatcher =
+        notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if res.is_ok() {
+                let _ = raw_tx.send(());
+            }
+        })
+        .map_err(|e| StorageError::WatchError(e.to_string()))?;
+
+    watcher
+        .watch(dir, RecursiveMode::Recursive)
+        .map_err(|e| StorageError::WatchError(e.to_string()))?;
+
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let _watcher = watcher; // keep alive for the life of this thread
+
+        while raw_rx.recv().is_ok() {
+            // Drain anything else that arrives within the debounce window so
+            // a bulk import collapses into a single refresh signal.
+            while raw_rx.recv_timeout(DEBOUNCE).is_ok() {}
+
+            if tx.send(ChangeEvent).is_err() {
+                break; // receiver dropped, nothing left to notify
+            }
+        }
+    });
+
+    Ok(rx)
+}
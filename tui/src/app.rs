@@ -1,166 +1,20 @@
-use ratatui::{layout::Rect, style::Color, Frame};
-use crossterm::event::KeyCode;
+use std::path::Path;
 
-use crate::tabs::{graph::GraphTab, search::SearchTab, placeholder::PlaceholderTab};
+use ratatui::{layout::Rect, Frame};
+use crossterm::event::{KeyCode, KeyModifiers};
 
-#[derive(Clone)]
-pub struct SeverityBar {
-    pub label: String,
-    pub value: u64,
-    pub color: Color,
-}
-
-impl SeverityBar {
-    pub fn new(label: impl Into<String>, value: u64, color: Color) -> Self {
-        Self { label: label.into(), value, color }
-    }
-
-    pub fn critical(value: u64) -> Self { Self::new("Critical", value, Color::Red) }
-    pub fn high(value: u64) -> Self { Self::new("High", value, Color::LightRed) }
-    pub fn medium(value: u64) -> Self { Self::new("Medium", value, Color::Yellow) }
-    pub fn low(value: u64) -> Self { Self::new("Low", value, Color::Green) }
-    pub fn info(value: u64) -> Self { Self::new("Info", value, Color::Blue) }
-}
-
-#[derive(Clone)]
-pub struct GraphData {
-    pub title: String,
-    pub bars: Vec<SeverityBar>,
-}
-
-impl GraphData {
-    pub fn new(title: impl Into<String>) -> Self {
-        Self { title: title.into(), bars: Vec::new() }
-    }
+use models::{Asset, Finding, GraphData};
+use storage::ReportDocument;
 
-    pub fn with_bar(mut self, bar: SeverityBar) -> Self {
-        self.bars.push(bar);
-        self
-    }
-
-    pub fn with_bars(mut self, bars: Vec<SeverityBar>) -> Self {
-        self.bars = bars;
-        self
-    }
-
-    pub fn default_severity() -> Self {
-        Self::new("Severity Distribution")
-            .with_bar(SeverityBar::critical(3))
-            .with_bar(SeverityBar::high(7))
-            .with_bar(SeverityBar::medium(12))
-            .with_bar(SeverityBar::low(5))
-            .with_bar(SeverityBar::info(2))
-    }
-}
-
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub enum Severity {
-    Critical,
-    High,
-    Medium,
-    Low,
-    Info,
-}
-
-impl Severity {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Severity::Critical => "Critical",
-            Severity::High => "High",
-            Severity::Medium => "Medium",
-            Severity::Low => "Low",
-            Severity::Info => "Info",
-        }
-    }
-
-    pub fn color(&self) -> Color {
-        match self {
-            Severity::Critical => Color::Red,
-            Severity::High => Color::LightRed,
-            Severity::Medium => Color::Yellow,
-            Severity::Low => Color::Green,
-            Severity::Info => Color::Blue,
-        }
-    }
-}
-
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub enum Status {
-    Open,
-    InProgress,
-    Resolved,
-    FalsePositive,
-}
-
-impl Status {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Status::Open => "Open",
-            Status::InProgress => "In Progress",
-            Status::Resolved => "Resolved",
-            Status::FalsePositive => "False Positive",
-        }
-    }
-
-    pub fn color(&self) -> Color {
-        match self {
-            Status::Open => Color::Red,
-            Status::InProgress => Color::Yellow,
-            Status::Resolved => Color::Green,
-            Status::FalsePositive => Color::Gray,
-        }
-    }
-}
-
-#[derive(Clone)]
-pub struct Finding {
-    pub title: String,
-    pub severity: Severity,
-    pub location: String,
-    pub description: String,
-    pub status: Status,
-}
-
-impl Finding {
-    pub fn new(
-        title: impl Into<String>,
-        severity: Severity,
-        location: impl Into<String>,
-        description: impl Into<String>,
-        status: Status,
-    ) -> Self {
-        Self {
-            title: title.into(),
-            severity,
-            location: location.into(),
-            description: description.into(),
-            status,
-        }
-    }
-
-    pub fn default_findings() -> Vec<Finding> {
-        vec![
-            Finding::new("SQL Injection", Severity::Critical, "https://example.com/api/users?id=1", "User input is directly concatenated into SQL query without sanitization.", Status::Open),
-            Finding::new("Cross-Site Scripting (XSS)", Severity::High, "https://example.com/search", "Reflected XSS vulnerability in search parameter.", Status::InProgress),
-            Finding::new("Buffer Overflow", Severity::Critical, "https://example.com/upload", "Stack buffer overflow in file upload handler.", Status::Open),
-            Finding::new("Authentication Bypass", Severity::Critical, "https://example.com/admin", "Admin panel accessible without authentication.", Status::Resolved),
-            Finding::new("Remote Code Execution", Severity::Critical, "https://example.com/eval", "User input passed to eval() function.", Status::Open),
-            Finding::new("Privilege Escalation", Severity::High, "https://example.com/api/role", "Users can modify their own role parameter.", Status::InProgress),
-            Finding::new("Information Disclosure", Severity::Medium, "https://example.com/.git", "Git repository exposed to public.", Status::Open),
-            Finding::new("Denial of Service", Severity::Medium, "https://example.com/api/export", "No rate limiting on resource-intensive endpoint.", Status::FalsePositive),
-            Finding::new("Insecure Deserialization", Severity::High, "https://example.com/api/session", "Untrusted data deserialized without validation.", Status::Open),
-            Finding::new("Path Traversal", Severity::Medium, "https://example.com/files", "File path parameter allows directory traversal.", Status::Open),
-            Finding::new("CSRF Token Missing", Severity::Medium, "https://example.com/settings", "Form submission lacks CSRF protection.", Status::Open),
-            Finding::new("Weak Password Policy", Severity::Low, "https://example.com/register", "No minimum password length requirement.", Status::Resolved),
-            Finding::new("HTTP Only Flag Missing", Severity::Info, "https://example.com", "Session cookie missing HttpOnly flag.", Status::Open),
-        ]
-    }
-}
+use crate::command::{parse_command, Command};
+use crate::keybindings::{AppAction, AppKeyBindings};
+use crate::tabs::{calendar::CalendarTab, graph::GraphTab, search::SearchTab, placeholder::PlaceholderTab, Tab};
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum TabKind {
     Graph,
     Search,
+    Calendar,
     Placeholder,
 }
 
@@ -168,106 +22,327 @@ pub struct App {
     current_tab: TabKind,
     pub graph_tab: GraphTab,
     pub search_tab: SearchTab,
+    pub calendar_tab: CalendarTab,
     pub placeholder_tab: PlaceholderTab,
+    assets: Vec<Asset>,
+    /// Transient message shown in the tab bar (e.g. "findings reloaded"
+    /// after a watcher-triggered refresh). Cleared on the next key press.
+    pub status_message: Option<String>,
+    /// `Some(buffer)` while the `:`-command line is open; `None` otherwise.
+    pub command_input: Option<String>,
+    /// Global key bindings for the `View` event loop (quit, suspend,
+    /// refresh, movement, enter) — see [`crate::run_app`].
+    pub keybindings: AppKeyBindings,
+    /// Set by [`App::handle_key`] when [`AppAction::Quit`] fires; consulted
+    /// and cleared by [`crate::run_app`], which owns the terminal.
+    pub should_quit: bool,
+    /// Set by [`App::handle_key`] when [`AppAction::Suspend`] fires;
+    /// consulted and cleared by [`crate::run_app`], which owns the
+    /// terminal and can leave/restore raw mode around the suspend.
+    pub should_suspend: bool,
 }
 
 impl App {
-    pub fn new(graph_data: GraphData, findings: Vec<Finding>) -> Self {
+    pub fn new(pog: storage::PogDir, graph_data: GraphData, findings: Vec<Finding>, assets: Vec<Asset>) -> Self {
         Self {
             current_tab: TabKind::Graph,
-            graph_tab: GraphTab::new(graph_data),
-            search_tab: SearchTab::new(findings),
+            graph_tab: GraphTab::new(graph_data, findings.clone()),
+            search_tab: SearchTab::new(findings.clone(), pog),
+            calendar_tab: CalendarTab::new(findings),
             placeholder_tab: PlaceholderTab::new(),
+            assets,
+            status_message: None,
+            command_input: None,
+            keybindings: AppKeyBindings::load(AppKeyBindings::default_config_path().as_deref()),
+            should_quit: false,
+            should_suspend: false,
         }
     }
 
+    /// Build an `App` from a JSON report previously written by
+    /// [`App::save`], recomputing the severity-distribution `GraphData`
+    /// from the loaded findings rather than trusting stale saved bars.
+    pub fn load(path: &Path, pog: storage::PogDir, assets: Vec<Asset>) -> Result<Self, storage::StorageError> {
+        let (title, findings) = ReportDocument::load(path)?;
+        let graph_data = GraphData::from_findings(title, &findings);
+        Ok(Self::new(pog, graph_data, findings, assets))
+    }
+
+    /// Write the current findings (including any local edits, e.g. bulk
+    /// status changes) to `path` as a JSON report.
+    pub fn save(&self, path: &Path) -> Result<(), storage::StorageError> {
+        ReportDocument::save(path, self.graph_tab.title(), self.search_tab.items())
+    }
+
+    /// The global action bound to `c`, if any, per the loaded
+    /// [`AppKeyBindings`].
+    pub fn app_action_for(&self, c: char) -> Option<AppAction> {
+        self.keybindings.action_for(c)
+    }
+
     pub fn tab_titles(&self) -> Vec<&'static str> {
-        vec!["Graph", "Search", "Placeholder"]
+        vec!["Graph", "Search", "Calendar", "Placeholder"]
     }
 
     pub fn current_tab_index(&self) -> usize {
         match self.current_tab {
             TabKind::Graph => 0,
             TabKind::Search => 1,
-            TabKind::Placeholder => 2,
+            TabKind::Calendar => 2,
+            TabKind::Placeholder => 3,
         }
     }
 
     pub fn select_tab(&mut self, index: usize) {
-        self.search_tab.unfocus();
+        self.search_tab.on_blur();
         self.current_tab = match index {
             0 => TabKind::Graph,
             1 => TabKind::Search,
+            2 => TabKind::Calendar,
             _ => TabKind::Placeholder,
         };
     }
 
     pub fn next_tab(&mut self) {
-        self.search_tab.unfocus();
+        self.search_tab.on_blur();
         self.current_tab = match self.current_tab {
             TabKind::Graph => TabKind::Search,
-            TabKind::Search => TabKind::Placeholder,
+            TabKind::Search => TabKind::Calendar,
+            TabKind::Calendar => TabKind::Placeholder,
             TabKind::Placeholder => TabKind::Graph,
         };
     }
 
     pub fn render_current_tab(&mut self, f: &mut Frame, area: Rect) {
         match self.current_tab {
-            TabKind::Graph => self.graph_tab.render(f, area),
-            TabKind::Search => self.search_tab.render(f, area),
-            TabKind::Placeholder => self.placeholder_tab.render(f, area),
+            TabKind::Graph => {
+                self.graph_tab.compute_layout(area);
+                self.graph_tab.render(f, area);
+            }
+            TabKind::Search => {
+                self.search_tab.compute_layout(area);
+                self.search_tab.render(f, area);
+            }
+            TabKind::Calendar => {
+                self.calendar_tab.compute_layout(area);
+                self.calendar_tab.render(f, area);
+            }
+            TabKind::Placeholder => {
+                self.placeholder_tab.compute_layout(area);
+                self.placeholder_tab.render(f, area);
+            }
         }
     }
 
-    pub fn handle_key(&mut self, key: KeyCode) -> bool {
-        if self.current_tab == TabKind::Search {
-            if self.search_tab.is_focused() || self.search_tab.is_dropdown_open() {
-                return self.search_tab.handle_key(key);
-            }
+    pub fn handle_key(&mut self, key: KeyCode, modifiers: KeyModifiers, pog: &storage::PogDir) -> bool {
+        self.status_message = None;
+
+        if let Some(buffer) = self.command_input.as_mut() {
+            return match key {
+                KeyCode::Esc => {
+                    self.command_input = None;
+                    true
+                }
+                KeyCode::Enter => {
+                    let line = self.command_input.take().unwrap_or_default();
+                    self.run_command_line(&line, pog);
+                    true
+                }
+                KeyCode::Backspace => {
+                    buffer.pop();
+                    true
+                }
+                KeyCode::Char(c) => {
+                    buffer.push(c);
+                    true
+                }
+                _ => true,
+            };
+        }
+
+        if self.dispatch_to_tab(key, modifiers) {
+            return true;
         }
 
         match key {
+            KeyCode::Char(':') => {
+                self.command_input = Some(String::new());
+                true
+            }
             KeyCode::Char('t') | KeyCode::Tab => {
                 self.next_tab();
                 true
             }
-            KeyCode::Char('s') if self.current_tab == TabKind::Search => {
-                self.search_tab.focus_search();
-                true
+            KeyCode::Char(c) => match self.app_action_for(c) {
+                Some(AppAction::Quit) => {
+                    self.should_quit = true;
+                    true
+                }
+                Some(AppAction::Suspend) => {
+                    self.should_suspend = true;
+                    true
+                }
+                Some(AppAction::Refresh) => {
+                    let _ = self.replace_data(pog);
+                    true
+                }
+                Some(AppAction::MoveUp) => self.dispatch_to_tab(KeyCode::Up, modifiers),
+                Some(AppAction::MoveDown) => self.dispatch_to_tab(KeyCode::Down, modifiers),
+                Some(AppAction::MoveLeft) => self.dispatch_to_tab(KeyCode::Left, modifiers),
+                Some(AppAction::MoveRight) => self.dispatch_to_tab(KeyCode::Right, modifiers),
+                Some(AppAction::Enter) => {
+                    if self.open_selected_asset() {
+                        true
+                    } else {
+                        self.dispatch_to_tab(KeyCode::Enter, modifiers)
+                    }
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Open the currently-selected finding's asset in a browser, reporting
+    /// the outcome via `status_message`. Only meaningful on the Search tab
+    /// (the only tab with a notion of "the selected finding"). Returns
+    /// `false` on any other tab, or when no selected finding has a known
+    /// asset, so [`App::handle_key`] falls back to the tab's own `Enter`
+    /// handling.
+    fn open_selected_asset(&mut self) -> bool {
+        let TabKind::Search = self.current_tab else { return false };
+        let Some(asset_name) = self.search_tab.selected_asset_name() else { return false };
+        let Some(asset) = self.assets.iter().find(|a| a.name == asset_name) else { return false };
+
+        match crate::open::open_url(&asset.dns_or_ip) {
+            crate::open::OpenOutcome::Opened => {
+                self.status_message = Some(format!("opened {}", asset.dns_or_ip));
             }
-            KeyCode::Char('f') if self.current_tab == TabKind::Search => {
-                self.search_tab.toggle_dropdown();
-                true
+            crate::open::OpenOutcome::CopiedToClipboard => {
+                self.status_message = Some(format!("no browser available — copied {} to clipboard", asset.dns_or_ip));
             }
-            KeyCode::Down if self.current_tab == TabKind::Search => {
-                self.search_tab.list_next();
-                true
+            crate::open::OpenOutcome::Failed => {
+                self.status_message = Some(format!("error: couldn't open or copy {}", asset.dns_or_ip));
             }
-            KeyCode::Up if self.current_tab == TabKind::Search => {
-                self.search_tab.list_previous();
-                true
+        }
+        true
+    }
+
+    /// Forward `key` to whichever tab currently has focus. Shared by the
+    /// primary tab dispatch in [`App::handle_key`] and by the global
+    /// movement/enter bindings, which redispatch as the equivalent
+    /// structural key (e.g. `MoveUp` → `KeyCode::Up`).
+    fn dispatch_to_tab(&mut self, key: KeyCode, modifiers: KeyModifiers) -> bool {
+        match self.current_tab {
+            TabKind::Graph => self.graph_tab.handle_key(key, modifiers),
+            TabKind::Search => self.search_tab.handle_key(key, modifiers),
+            TabKind::Calendar => self.calendar_tab.handle_key(key, modifiers),
+            TabKind::Placeholder => self.placeholder_tab.handle_key(key, modifiers),
+        }
+    }
+
+    /// Parse and run a submitted `:`-command line, reporting the outcome via
+    /// `status_message` (shared with the watcher-refresh banner in the tab
+    /// bar).
+    fn run_command_line(&mut self, line: &str, pog: &storage::PogDir) {
+        match parse_command(line) {
+            Ok(cmd) => self.execute_command(cmd, pog),
+            Err(e) => self.status_message = Some(format!("error: {e}")),
+        }
+    }
+
+    fn execute_command(&mut self, cmd: Command, pog: &storage::PogDir) {
+        match cmd {
+            Command::Status { asset, id, status } => {
+                let result = pog
+                    .open_db()
+                    .and_then(|db| db.update_finding_status(&asset, &id, status.as_str()));
+                match result {
+                    Ok(title) => {
+                        self.status_message = Some(format!("{title} [{id}] ({asset}) → {status}"));
+                        let _ = self.replace_data(pog);
+                    }
+                    Err(e) => self.status_message = Some(format!("error: {e}")),
+                }
+            }
+            Command::Filter(severities) => {
+                self.search_tab.set_command_severities(severities);
+                self.current_tab = TabKind::Search;
+                self.status_message = Some("filter applied".to_string());
+            }
+            Command::GotoAsset(name) => {
+                if self.search_tab.set_asset_filter_by_name(&name) {
+                    self.current_tab = TabKind::Search;
+                    self.status_message = Some(format!("showing asset {name}"));
+                } else {
+                    self.status_message = Some(format!("error: unknown asset: {name}"));
+                }
+            }
+            Command::Export(path) => {
+                let result = pog
+                    .open_db()
+                    .and_then(|db| db.export_csv(None, None, None))
+                    .map_err(|e| e.to_string())
+                    .and_then(|csv| std::fs::write(&path, csv).map_err(|e| e.to_string()));
+                match result {
+                    Ok(()) => self.status_message = Some(format!("exported findings to {path}")),
+                    Err(e) => self.status_message = Some(format!("error: {e}")),
+                }
+            }
+            Command::Clear => {
+                self.search_tab.clear_filters();
+                self.status_message = Some("filters cleared".to_string());
             }
-            _ => false,
         }
     }
 
     pub fn handle_click(&mut self, col: u16, row: u16) {
         match self.current_tab {
+            TabKind::Graph => self.graph_tab.handle_click(col, row),
             TabKind::Search => self.search_tab.handle_click(col, row),
+            TabKind::Calendar => self.calendar_tab.handle_click(col, row),
             TabKind::Placeholder => self.placeholder_tab.handle_click(col, row),
-            _ => {}
         }
     }
 
     pub fn handle_scroll_down(&mut self) {
-        if self.current_tab == TabKind::Search {
-            self.search_tab.list_next();
+        match self.current_tab {
+            TabKind::Graph => self.graph_tab.handle_scroll_down(),
+            TabKind::Search => self.search_tab.handle_scroll_down(),
+            TabKind::Calendar => self.calendar_tab.handle_scroll_down(),
+            TabKind::Placeholder => self.placeholder_tab.handle_scroll_down(),
         }
     }
 
     pub fn handle_scroll_up(&mut self) {
-        if self.current_tab == TabKind::Search {
-            self.search_tab.list_previous();
+        match self.current_tab {
+            TabKind::Graph => self.graph_tab.handle_scroll_up(),
+            TabKind::Search => self.search_tab.handle_scroll_up(),
+            TabKind::Calendar => self.calendar_tab.handle_scroll_up(),
+            TabKind::Placeholder => self.placeholder_tab.handle_scroll_up(),
         }
     }
-}
\ No newline at end of file
+
+    /// Re-query `pog`'s database and rebuild the graph/search data in
+    /// place. The active tab, `GraphTab`'s severity toggles and the
+    /// `SearchTab`'s scroll position/selection are left untouched — only
+    /// the underlying data is swapped out. Called after a local mutation
+    /// (e.g. `:status`) and from [`crate::run_with_data`]'s watcher loop
+    /// when the findings directory changes on disk.
+    pub fn replace_data(&mut self, pog: &storage::PogDir) -> Result<(), storage::StorageError> {
+        let db = pog.open_db()?;
+        let findings = db.all_findings()?;
+        self.assets = db.all_assets()?;
+
+        self.graph_tab.set_data(build_graph_data(&findings), findings.clone());
+        self.calendar_tab.set_data(findings.clone());
+        self.search_tab.set_items(findings);
+        self.status_message = Some("findings reloaded".to_string());
+        Ok(())
+    }
+}
+
+/// Build a `GraphData` from the severity distribution of `findings`.
+fn build_graph_data(findings: &[Finding]) -> GraphData {
+    GraphData::from_findings("Severity Distribution", findings)
+}
@@ -0,0 +1,82 @@
+//! Parser for the `:`-prefixed command line (see [`crate::app::App`]).
+//!
+//! This mirrors the CLI's own sub-commands (`status`, `export`) plus a
+//! couple of TUI-only verbs (`filter`, `goto`, `clear`) for jumping around
+//! without leaving the terminal UI.
+
+use models::{Severity, Status};
+
+/// A parsed command-line entry.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// `status <asset> <id> <status>` — update a finding's status in place.
+    Status { asset: String, id: String, status: Status },
+    /// `filter <severity[,severity...]>` — OR-filter the Search tab across
+    /// one or more severities, e.g. `filter critical,high`.
+    Filter(Vec<Severity>),
+    /// `goto asset <name>` — jump to the Search tab scoped to one asset.
+    GotoAsset(String),
+    /// `export <path>` — export all findings to CSV.
+    Export(String),
+    /// `clear` — reset every active Search tab filter.
+    Clear,
+}
+
+/// An error produced while parsing or validating a command line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandLineError(pub String);
+
+impl std::fmt::Display for CommandLineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Parse a `:`-command line (without the leading `:`) into a [`Command`].
+pub fn parse_command(line: &str) -> Result<Command, CommandLineError> {
+    let mut parts = line.split_whitespace();
+    let verb = parts
+        .next()
+        .ok_or_else(|| CommandLineError("empty command".to_string()))?;
+
+    match verb {
+        "status" => {
+            let asset = expect_arg(&mut parts, "status <asset> <id> <status>")?;
+            let id = expect_arg(&mut parts, "status <asset> <id> <status>")?;
+            let status_str = expect_arg(&mut parts, "status <asset> <id> <status>")?;
+            let status = status_str.parse::<Status>().map_err(CommandLineError)?;
+            Ok(Command::Status { asset: asset.to_string(), id: id.to_string(), status })
+        }
+        "filter" => {
+            let list = expect_arg(&mut parts, "filter <severity[,severity...]>")?;
+            let severities = list
+                .split(',')
+                .map(|s| s.parse::<Severity>().map_err(CommandLineError))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Command::Filter(severities))
+        }
+        "goto" => {
+            let target = expect_arg(&mut parts, "goto asset <name>")?;
+            match target {
+                "asset" => {
+                    let name = expect_arg(&mut parts, "goto asset <name>")?;
+                    Ok(Command::GotoAsset(name.to_string()))
+                }
+                other => Err(CommandLineError(format!("unknown goto target: {other}"))),
+            }
+        }
+        "export" => {
+            let path = expect_arg(&mut parts, "export <path>")?;
+            Ok(Command::Export(path.to_string()))
+        }
+        "clear" => Ok(Command::Clear),
+        other => Err(CommandLineError(format!("unknown command: {other}"))),
+    }
+}
+
+fn expect_arg<'a>(
+    parts: &mut std::str::SplitWhitespace<'a>,
+    usage: &str,
+) -> Result<&'a str, CommandLineError> {
+    parts.next().ok_or_else(|| CommandLineError(format!("usage: {usage}")))
+}
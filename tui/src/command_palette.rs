@@ -0,0 +1,163 @@
+//! Reusable command-palette overlay.
+//!
+//! A [`CommandPalette<A>`] is a fuzzy-filterable modal list of actions,
+//! rendered the same way `Tab` implementations already render their own
+//! dropdown menus (`Clear` + a floating `List`). It doesn't know anything
+//! about what the actions *do* — callers parameterize it with their own
+//! action enum and dispatch the value [`CommandPalette::select`] returns.
+//! Any `Tab` can own one; see `tabs::assets::AssetAction` for the reference
+//! wiring (global `:` toggle, Up/Down/Enter navigation, fuzzy filtering).
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::fuzzy::fuzzy_match;
+
+/// One entry in the palette: a label shown to the user and the action
+/// dispatched when it's selected.
+pub struct CommandEntry<A> {
+    pub label: &'static str,
+    pub action: A,
+}
+
+impl<A> CommandEntry<A> {
+    pub fn new(label: &'static str, action: A) -> Self {
+        Self { label, action }
+    }
+}
+
+/// A modal, fuzzy-filterable list of actions.
+pub struct CommandPalette<A> {
+    entries: Vec<CommandEntry<A>>,
+    open: bool,
+    input: String,
+    /// Indices into `entries`, in display order.
+    filtered: Vec<usize>,
+    list_state: ListState,
+}
+
+impl<A: Copy> CommandPalette<A> {
+    pub fn new(entries: Vec<CommandEntry<A>>) -> Self {
+        let filtered = (0..entries.len()).collect();
+        let mut list_state = ListState::default();
+        if !entries.is_empty() {
+            list_state.select(Some(0));
+        }
+        Self { entries, open: false, input: String::new(), filtered, list_state }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Open the palette with a blank query showing every entry.
+    pub fn open(&mut self) {
+        self.open = true;
+        self.input.clear();
+        self.refilter();
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        self.input.push(c);
+        self.refilter();
+    }
+
+    pub fn pop_char(&mut self) {
+        self.input.pop();
+        self.refilter();
+    }
+
+    pub fn next(&mut self) {
+        if self.filtered.is_empty() { return; }
+        let i = match self.list_state.selected() {
+            Some(i) => (i + 1) % self.filtered.len(),
+            None => 0,
+        };
+        self.list_state.select(Some(i));
+    }
+
+    pub fn previous(&mut self) {
+        if self.filtered.is_empty() { return; }
+        let i = match self.list_state.selected() {
+            Some(i) => if i == 0 { self.filtered.len() - 1 } else { i - 1 },
+            None => 0,
+        };
+        self.list_state.select(Some(i));
+    }
+
+    /// Close the palette and return the currently selected action, if any.
+    pub fn select(&mut self) -> Option<A> {
+        let action = self.list_state
+            .selected()
+            .and_then(|i| self.filtered.get(i))
+            .and_then(|&idx| self.entries.get(idx))
+            .map(|e| e.action);
+        self.close();
+        action
+    }
+
+    fn refilter(&mut self) {
+        let query = self.input.trim();
+
+        let mut scored: Vec<(usize, i32)> = self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, e)| {
+                if query.is_empty() {
+                    Some((i, 0))
+                } else {
+                    fuzzy_match(query, e.label).map(|m| (i, m.score))
+                }
+            })
+            .collect();
+
+        if !query.is_empty() {
+            scored.sort_by(|a, b| b.1.cmp(&a.1));
+        }
+
+        self.filtered = scored.into_iter().map(|(i, _)| i).collect();
+        self.list_state.select(if self.filtered.is_empty() { None } else { Some(0) });
+    }
+
+    /// Render the palette as a centered overlay within `area` (the tab's
+    /// full render area — the palette sizes and positions itself within it).
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let width = area.width.saturating_mul(3) / 4;
+        let height = (self.filtered.len() as u16 + 3).min(area.height.saturating_sub(2)).max(5);
+        let x = area.x + area.width.saturating_sub(width) / 2;
+        let y = area.y + area.height.saturating_sub(height) / 3;
+        let palette_area = Rect { x, y, width, height };
+
+        f.render_widget(Clear, palette_area);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(palette_area);
+
+        let input = Paragraph::new(format!("{}▌", self.input))
+            .block(Block::default().borders(Borders::ALL).title(" Command Palette (Esc to close) "))
+            .style(Style::default().fg(Color::White));
+        f.render_widget(input, rows[0]);
+
+        let items: Vec<ListItem> = self.filtered
+            .iter()
+            .filter_map(|&i| self.entries.get(i))
+            .map(|e| ListItem::new(e.label))
+            .collect();
+
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL))
+            .highlight_style(Style::default().fg(Color::White).bg(Color::Blue).add_modifier(Modifier::BOLD))
+            .highlight_symbol("▶ ");
+        f.render_stateful_widget(list, rows[1], &mut self.list_state);
+    }
+}
@@ -0,0 +1,188 @@
+//! Fuzzy subsequence matching shared by the TUI's searchable lists.
+//!
+//! [`fuzzy_match`] walks a query left to right and tries to consume its
+//! characters, in order, against a candidate string. It scores the match so
+//! results can be ranked instead of merely filtered, and reports which byte
+//! indices of the candidate were matched so callers can highlight them.
+
+/// Score and matched byte-index positions for a query against one candidate.
+pub struct FuzzyMatch {
+    pub score: i32,
+    pub indices: Vec<usize>,
+}
+
+/// Try to match `query` as an in-order subsequence of `candidate`, ignoring
+/// case. See [`fuzzy_match_with_case`] for a case-sensitive variant.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    fuzzy_match_with_case(query, candidate, false)
+}
+
+/// Try to match `query` as an in-order subsequence of `candidate`.
+///
+/// Returns `None` when not every query character could be consumed.
+/// Otherwise returns the total score and the byte indices in `candidate`
+/// that were matched, in ascending order. When `case_sensitive` is false,
+/// both strings are lowercased before comparison (characters are still
+/// reported at their original byte offsets in `candidate`).
+pub fn fuzzy_match_with_case(query: &str, candidate: &str, case_sensitive: bool) -> Option<FuzzyMatch> {
+    if query.is_empty() {
+        return Some(FuzzyMatch { score: 0, indices: Vec::new() });
+    }
+
+    if case_sensitive {
+        let query_chars: Vec<char> = query.chars().collect();
+        return fuzzy_match_ascii_fallback(&query_chars, candidate, true);
+    }
+
+    let query_lower: Vec<char> = query.to_lowercase().chars().collect();
+    let cand_chars: Vec<(usize, char)> = candidate.char_indices().collect();
+    let cand_lower: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    // `to_lowercase` can change char counts for a handful of codepoints;
+    // candidates here are asset/finding metadata (ASCII in practice), so the
+    // scan below indexes both by char position and only falls back to a
+    // per-char ASCII-lowercase scan in the exotic cases where the lengths
+    // differ.
+    if cand_lower.len() != cand_chars.len() {
+        return fuzzy_match_ascii_fallback(&query_lower, candidate, false);
+    }
+
+    let mut qi = 0;
+    let mut indices = Vec::new();
+    let mut score = 0i32;
+    let mut prev_matched_pos: Option<usize> = None;
+
+    for (pos, &(byte_idx, ch)) in cand_chars.iter().enumerate() {
+        if qi >= query_lower.len() {
+            break;
+        }
+        if cand_lower[pos] != query_lower[qi] {
+            continue;
+        }
+
+        score += 1; // base point per matched char
+
+        if let Some(prev) = prev_matched_pos {
+            if pos == prev + 1 {
+                score += 8; // consecutive-run bonus
+            } else {
+                score -= (pos - prev - 1) as i32; // gap penalty
+            }
+        }
+
+        let at_boundary = pos == 0
+            || is_separator(cand_chars[pos - 1].1)
+            || (cand_chars[pos - 1].1.is_lowercase() && ch.is_uppercase());
+        if at_boundary {
+            score += 5;
+        }
+
+        // Bonus for matches near the start of the string.
+        score += (10 - (pos as i32).min(10)) / 2;
+
+        indices.push(byte_idx);
+        prev_matched_pos = Some(pos);
+        qi += 1;
+    }
+
+    if qi < query_lower.len() {
+        return None;
+    }
+
+    Some(FuzzyMatch { score, indices })
+}
+
+fn fuzzy_match_ascii_fallback(query: &[char], candidate: &str, case_sensitive: bool) -> Option<FuzzyMatch> {
+    let mut qi = 0;
+    let mut indices = Vec::new();
+    let mut score = 0i32;
+    let mut prev: Option<usize> = None;
+
+    for (pos, ch) in candidate.char_indices() {
+        if qi >= query.len() {
+            break;
+        }
+        let matches = if case_sensitive { ch == query[qi] } else { ch.to_ascii_lowercase() == query[qi] };
+        if !matches {
+            continue;
+        }
+        score += 1;
+        if let Some(p) = prev {
+            if pos == p + 1 { score += 8; }
+        }
+        indices.push(pos);
+        prev = Some(pos);
+        qi += 1;
+    }
+
+    if qi < query.len() {
+        return None;
+    }
+    Some(FuzzyMatch { score, indices })
+}
+
+/// Score `query` against `candidate` without the matched byte indices; see
+/// [`fuzzy_match`] for the scoring rules. `None` means `query`'s characters
+/// don't appear as an in-order subsequence of `candidate`.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    fuzzy_match(query, candidate).map(|m| m.score)
+}
+
+fn is_separator(c: char) -> bool {
+    matches!(c, '.' | '-' | '_' | ' ' | '/')
+}
+
+/// Find `query` in `candidate` as a whole word: the match must be bounded by
+/// separators or the start/end of the string on both sides. Returns the
+/// matched byte range when found.
+pub fn whole_word_match(query: &str, candidate: &str, case_sensitive: bool) -> Option<std::ops::Range<usize>> {
+    if query.is_empty() {
+        return None;
+    }
+
+    let (needle, haystack) = if case_sensitive {
+        (query.to_string(), candidate.to_string())
+    } else {
+        (query.to_lowercase(), candidate.to_lowercase())
+    };
+
+    let mut start = 0;
+    while let Some(found) = haystack[start..].find(&needle) {
+        let match_start = start + found;
+        let match_end = match_start + needle.len();
+
+        let before_ok = candidate[..match_start].chars().next_back().is_none_or(is_separator);
+        let after_ok = candidate[match_end..].chars().next().is_none_or(is_separator);
+        if before_ok && after_ok {
+            return Some(match_start..match_end);
+        }
+
+        start = match_start + needle.len().max(1);
+        if start > haystack.len() {
+            break;
+        }
+    }
+
+    None
+}
+
+/// Rank candidate indices by descending fuzzy score. Empty query returns
+/// `0..len` unchanged (original order preserved).
+pub fn rank(query: &str, candidates: &[&str]) -> Vec<(usize, FuzzyMatch)> {
+    if query.is_empty() {
+        return candidates
+            .iter()
+            .enumerate()
+            .map(|(i, _)| (i, FuzzyMatch { score: 0, indices: Vec::new() }))
+            .collect();
+    }
+
+    let mut ranked: Vec<(usize, FuzzyMatch)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(i, c)| fuzzy_match(query, c).map(|m| (i, m)))
+        .collect();
+
+    ranked.sort_by(|a, b| b.1.score.cmp(&a.1.score));
+    ranked
+}
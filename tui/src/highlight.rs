@@ -0,0 +1,248 @@
+//! Lightweight, dependency-free syntax highlighting for the finding detail
+//! pane.
+//!
+//! This isn't a general-purpose tokenizer — it's a line-based lexer that
+//! recognizes just enough structure (comments, strings, keywords, markdown
+//! markup) to make evidence files readable at a glance. [`highlight`]
+//! converts the result directly into ratatui [`Line`]s so callers don't
+//! need an intermediate token representation.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+/// Language detected from a finding's primary content file, used to pick a
+/// highlighting ruleset. Falls back to [`Language::PlainText`] for
+/// extensions we don't recognize.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    Markdown,
+    Rust,
+    Python,
+    JavaScript,
+    C,
+    Shell,
+    PlainText,
+}
+
+impl Language {
+    /// Detect a language from a file extension (case-insensitive).
+    pub fn detect(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase().as_str() {
+            "md" | "markdown" => Language::Markdown,
+            "rs" => Language::Rust,
+            "py" => Language::Python,
+            "js" | "ts" | "jsx" | "tsx" => Language::JavaScript,
+            "c" | "h" | "cpp" | "hpp" | "cc" => Language::C,
+            "sh" | "bash" => Language::Shell,
+            _ => Language::PlainText,
+        }
+    }
+
+    fn line_comment(&self) -> Option<&'static str> {
+        match self {
+            Language::Rust | Language::JavaScript | Language::C => Some("//"),
+            Language::Python | Language::Shell => Some("#"),
+            _ => None,
+        }
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            Language::Rust => &[
+                "fn", "let", "mut", "pub", "struct", "enum", "impl", "trait", "use", "mod",
+                "match", "if", "else", "for", "while", "loop", "return", "self", "Self",
+                "const", "static", "async", "await", "move", "ref", "dyn", "where", "as",
+            ],
+            Language::Python => &[
+                "def", "class", "import", "from", "return", "if", "elif", "else", "for",
+                "while", "with", "as", "try", "except", "finally", "lambda", "yield", "self",
+                "None", "True", "False",
+            ],
+            Language::JavaScript => &[
+                "function", "const", "let", "var", "return", "if", "else", "for", "while",
+                "class", "import", "export", "from", "async", "await", "new", "this",
+                "null", "undefined", "true", "false",
+            ],
+            Language::C => &[
+                "int", "char", "void", "struct", "return", "if", "else", "for", "while",
+                "const", "static", "typedef", "unsigned", "sizeof", "include",
+            ],
+            Language::Shell => &["if", "then", "else", "fi", "for", "do", "done", "while", "echo", "export"],
+            Language::Markdown | Language::PlainText => &[],
+        }
+    }
+}
+
+/// Cache of already-highlighted finding bodies, keyed by a caller-chosen ID
+/// (e.g. `"<asset>/<hex_id>"`), so scrolling the detail pane doesn't
+/// re-tokenize the same text on every frame.
+#[derive(Default)]
+pub struct HighlightCache {
+    entries: HashMap<String, Vec<Line<'static>>>,
+}
+
+impl HighlightCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the highlighted lines for `key`, computing and caching them
+    /// on first access. Callers should pick a `key` that changes whenever
+    /// the underlying `text`/`lang` would (e.g. the finding's hex ID).
+    pub fn get_or_highlight(&mut self, key: &str, text: &str, lang: Language) -> &[Line<'static>] {
+        if !self.entries.contains_key(key) {
+            self.entries.insert(key.to_string(), highlight(text, lang));
+        }
+        self.entries.get(key).expect("just inserted")
+    }
+
+    /// Drop every cached entry except `key` (e.g. call this before
+    /// rendering a newly-selected finding to bound memory growth).
+    pub fn retain_only(&mut self, key: &str) {
+        self.entries.retain(|k, _| k == key);
+    }
+}
+
+/// Tokenize `text` per `lang`'s ruleset and render it as styled
+/// ratatui [`Line`]s.
+pub fn highlight(text: &str, lang: Language) -> Vec<Line<'static>> {
+    match lang {
+        Language::Markdown => highlight_markdown(text),
+        Language::PlainText => text.lines().map(|l| Line::from(l.to_string())).collect(),
+        _ => highlight_code(text, lang),
+    }
+}
+
+fn highlight_markdown(text: &str) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut in_fence = false;
+
+    for raw in text.lines() {
+        let trimmed = raw.trim_start();
+
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+            lines.push(Line::styled(raw.to_string(), Style::default().fg(Color::DarkGray)));
+            continue;
+        }
+
+        if in_fence {
+            lines.push(Line::styled(raw.to_string(), Style::default().fg(Color::Green)));
+            continue;
+        }
+
+        if let Some(rest) = trimmed.strip_prefix("### ") {
+            lines.push(Line::styled(rest.to_string(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)));
+        } else if let Some(rest) = trimmed.strip_prefix("## ") {
+            lines.push(Line::styled(rest.to_string(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)));
+        } else if let Some(rest) = trimmed.strip_prefix("# ") {
+            lines.push(Line::styled(rest.to_string(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)));
+        } else if trimmed.starts_with("- ") || trimmed.starts_with("* ") {
+            lines.push(Line::styled(raw.to_string(), Style::default().fg(Color::Yellow)));
+        } else {
+            lines.push(Line::from(highlight_inline_code_spans(raw)));
+        }
+    }
+
+    lines
+}
+
+/// Highlight backtick-delimited inline code spans within a single markdown
+/// line, leaving the rest as plain text.
+fn highlight_inline_code_spans(line: &str) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut rest = line;
+    let code_style = Style::default().fg(Color::Green);
+
+    while let Some(start) = rest.find('`') {
+        if start > 0 {
+            spans.push(Span::raw(rest[..start].to_string()));
+        }
+        let after = &rest[start + 1..];
+        match after.find('`') {
+            Some(end) => {
+                spans.push(Span::styled(after[..end].to_string(), code_style));
+                rest = &after[end + 1..];
+            }
+            None => {
+                spans.push(Span::raw(format!("`{after}")));
+                rest = "";
+                break;
+            }
+        }
+    }
+    if !rest.is_empty() {
+        spans.push(Span::raw(rest.to_string()));
+    }
+    if spans.is_empty() {
+        spans.push(Span::raw(String::new()));
+    }
+    spans
+}
+
+fn highlight_code(text: &str, lang: Language) -> Vec<Line<'static>> {
+    let comment_marker = lang.line_comment();
+    let keywords = lang.keywords();
+
+    text.lines()
+        .map(|raw| {
+            if let Some(marker) = comment_marker
+                && let Some(pos) = raw.find(marker)
+            {
+                let mut spans = highlight_code_line(&raw[..pos], keywords);
+                spans.push(Span::styled(raw[pos..].to_string(), Style::default().fg(Color::DarkGray)));
+                return Line::from(spans);
+            }
+            Line::from(highlight_code_line(raw, keywords))
+        })
+        .collect()
+}
+
+/// Split `line` on whitespace/punctuation boundaries, styling string
+/// literals and recognized keywords; everything else is rendered plain.
+fn highlight_code_line(line: &str, keywords: &[&str]) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut chars = line.char_indices().peekable();
+    let mut word_start = 0usize;
+
+    let flush_word = |spans: &mut Vec<Span<'static>>, word: &str, keywords: &[&str]| {
+        if word.is_empty() {
+            return;
+        }
+        if keywords.contains(&word) {
+            spans.push(Span::styled(word.to_string(), Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD)));
+        } else {
+            spans.push(Span::raw(word.to_string()));
+        }
+    };
+
+    while let Some((i, c)) = chars.next() {
+        if c == '"' {
+            flush_word(&mut spans, &line[word_start..i], keywords);
+            let start = i;
+            let mut end = line.len();
+            while let Some(&(j, cc)) = chars.peek() {
+                chars.next();
+                if cc == '"' {
+                    end = j + 1;
+                    break;
+                }
+            }
+            spans.push(Span::styled(line[start..end].to_string(), Style::default().fg(Color::Green)));
+            word_start = end;
+        } else if !c.is_alphanumeric() && c != '_' {
+            flush_word(&mut spans, &line[word_start..i], keywords);
+            spans.push(Span::raw(c.to_string()));
+            word_start = i + c.len_utf8();
+        }
+    }
+    flush_word(&mut spans, &line[word_start..], keywords);
+
+    if spans.is_empty() {
+        spans.push(Span::raw(String::new()));
+    }
+    spans
+}
@@ -0,0 +1,257 @@
+//! Configurable key bindings for tab-level shortcuts.
+//!
+//! Like [`crate::theme`], a [`KeyBindings`] is loaded once (from a user
+//! config file, if present) and consulted by `handle_key` instead of
+//! matching literal `KeyCode::Char(...)` patterns. Only single-character
+//! shortcuts are remappable; structural keys (arrows, Enter, Esc) stay
+//! hardcoded since they don't carry tab-specific meaning.
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+/// A tab-level action that can be bound to a key.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Action {
+    FocusSearch,
+    ToggleSeverityFilter,
+    ToggleAssetFilter,
+    ToggleStatusFilter,
+    ToggleSemanticMode,
+    ToggleDetailFocus,
+    ToggleMark,
+    InvertSelection,
+    ToggleBulkStatus,
+    CycleSortField,
+    ToggleSortOrder,
+}
+
+/// On-disk key binding overrides. Every key is optional.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct KeyBindingsConfig {
+    pub focus_search: Option<char>,
+    pub toggle_severity_filter: Option<char>,
+    pub toggle_asset_filter: Option<char>,
+    pub toggle_status_filter: Option<char>,
+    pub toggle_semantic_mode: Option<char>,
+    pub toggle_detail_focus: Option<char>,
+    pub toggle_mark: Option<char>,
+    pub invert_selection: Option<char>,
+    pub toggle_bulk_status: Option<char>,
+    pub cycle_sort_field: Option<char>,
+    pub toggle_sort_order: Option<char>,
+}
+
+/// The resolved key bindings used by a tab's `handle_key`.
+#[derive(Clone, Copy, Debug)]
+pub struct KeyBindings {
+    pub focus_search: char,
+    pub toggle_severity_filter: char,
+    pub toggle_asset_filter: char,
+    pub toggle_status_filter: char,
+    pub toggle_semantic_mode: char,
+    pub toggle_detail_focus: char,
+    pub toggle_mark: char,
+    pub invert_selection: char,
+    pub toggle_bulk_status: char,
+    pub cycle_sort_field: char,
+    pub toggle_sort_order: char,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            focus_search: 's',
+            toggle_severity_filter: 'f',
+            toggle_asset_filter: 'a',
+            toggle_status_filter: 't',
+            toggle_semantic_mode: 'm',
+            toggle_detail_focus: 'd',
+            toggle_mark: ' ',
+            invert_selection: 'i',
+            toggle_bulk_status: 'b',
+            cycle_sort_field: 'o',
+            toggle_sort_order: 'O',
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Load the bindings: start from the built-in defaults, then apply any
+    /// overrides found in `path` (TOML or JSON, detected by extension).
+    pub fn load(path: Option<&std::path::Path>) -> Self {
+        let mut bindings = KeyBindings::default();
+
+        if let Some(path) = path
+            && let Some(config) = read_keybindings_config(path)
+        {
+            if let Some(c) = config.focus_search { bindings.focus_search = c; }
+            if let Some(c) = config.toggle_severity_filter { bindings.toggle_severity_filter = c; }
+            if let Some(c) = config.toggle_asset_filter { bindings.toggle_asset_filter = c; }
+            if let Some(c) = config.toggle_status_filter { bindings.toggle_status_filter = c; }
+            if let Some(c) = config.toggle_semantic_mode { bindings.toggle_semantic_mode = c; }
+            if let Some(c) = config.toggle_detail_focus { bindings.toggle_detail_focus = c; }
+            if let Some(c) = config.toggle_mark { bindings.toggle_mark = c; }
+            if let Some(c) = config.invert_selection { bindings.invert_selection = c; }
+            if let Some(c) = config.toggle_bulk_status { bindings.toggle_bulk_status = c; }
+            if let Some(c) = config.cycle_sort_field { bindings.cycle_sort_field = c; }
+            if let Some(c) = config.toggle_sort_order { bindings.toggle_sort_order = c; }
+        }
+
+        bindings
+    }
+
+    /// Default config file location: `$XDG_CONFIG_HOME/pog/keybindings.toml`,
+    /// falling back to `~/.config/pog/keybindings.toml`.
+    pub fn default_config_path() -> Option<PathBuf> {
+        if let Ok(xdg) = std::env::var("XDG_CONFIG_HOME") {
+            return Some(PathBuf::from(xdg).join("pog").join("keybindings.toml"));
+        }
+        let home = std::env::var("HOME").ok()?;
+        Some(PathBuf::from(home).join(".config").join("pog").join("keybindings.toml"))
+    }
+
+    /// The action bound to `c`, if any.
+    pub fn action_for(&self, c: char) -> Option<Action> {
+        match c {
+            c if c == self.focus_search => Some(Action::FocusSearch),
+            c if c == self.toggle_severity_filter => Some(Action::ToggleSeverityFilter),
+            c if c == self.toggle_asset_filter => Some(Action::ToggleAssetFilter),
+            c if c == self.toggle_status_filter => Some(Action::ToggleStatusFilter),
+            c if c == self.toggle_semantic_mode => Some(Action::ToggleSemanticMode),
+            c if c == self.toggle_detail_focus => Some(Action::ToggleDetailFocus),
+            c if c == self.toggle_mark => Some(Action::ToggleMark),
+            c if c == self.invert_selection => Some(Action::InvertSelection),
+            c if c == self.toggle_bulk_status => Some(Action::ToggleBulkStatus),
+            c if c == self.cycle_sort_field => Some(Action::CycleSortField),
+            c if c == self.toggle_sort_order => Some(Action::ToggleSortOrder),
+            _ => None,
+        }
+    }
+}
+
+fn read_keybindings_config(path: &std::path::Path) -> Option<KeyBindingsConfig> {
+    let raw = std::fs::read_to_string(path).ok()?;
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::from_str(&raw).ok(),
+        _ => toml::from_str(&raw).ok(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// App-level bindings (the `View` event loop itself, not a specific tab)
+// ---------------------------------------------------------------------------
+
+/// A global action handled by the `View` event loop rather than the
+/// focused tab: quitting, suspending to the shell, forcing a manual
+/// reload, moving focus, or opening/entering the selected item.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum AppAction {
+    Quit,
+    Suspend,
+    Refresh,
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Enter,
+}
+
+/// On-disk app-level key binding overrides. Every key is optional.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct AppKeyBindingsConfig {
+    pub quit: Option<char>,
+    pub suspend: Option<char>,
+    pub refresh: Option<char>,
+    pub move_up: Option<char>,
+    pub move_down: Option<char>,
+    pub move_left: Option<char>,
+    pub move_right: Option<char>,
+    pub enter: Option<char>,
+}
+
+/// The resolved app-level key bindings used by the `View` event loop.
+/// Defaults are vim-style (`hjkl`); users on other layouts, or who need
+/// different keys for accessibility reasons, can remap every one of them.
+#[derive(Clone, Copy, Debug)]
+pub struct AppKeyBindings {
+    pub quit: char,
+    pub suspend: char,
+    pub refresh: char,
+    pub move_up: char,
+    pub move_down: char,
+    pub move_left: char,
+    pub move_right: char,
+    pub enter: char,
+}
+
+impl Default for AppKeyBindings {
+    fn default() -> Self {
+        Self {
+            quit: 'q',
+            suspend: 'z',
+            refresh: 'r',
+            move_up: 'k',
+            move_down: 'j',
+            move_left: 'h',
+            move_right: 'l',
+            enter: 'u',
+        }
+    }
+}
+
+impl AppKeyBindings {
+    /// Load the bindings: start from the built-in defaults, then apply any
+    /// overrides found in `path` (TOML or JSON, detected by extension).
+    pub fn load(path: Option<&std::path::Path>) -> Self {
+        let mut bindings = AppKeyBindings::default();
+
+        if let Some(path) = path
+            && let Some(config) = read_app_keybindings_config(path)
+        {
+            if let Some(c) = config.quit { bindings.quit = c; }
+            if let Some(c) = config.suspend { bindings.suspend = c; }
+            if let Some(c) = config.refresh { bindings.refresh = c; }
+            if let Some(c) = config.move_up { bindings.move_up = c; }
+            if let Some(c) = config.move_down { bindings.move_down = c; }
+            if let Some(c) = config.move_left { bindings.move_left = c; }
+            if let Some(c) = config.move_right { bindings.move_right = c; }
+            if let Some(c) = config.enter { bindings.enter = c; }
+        }
+
+        bindings
+    }
+
+    /// Default config file location: `$XDG_CONFIG_HOME/pog/config.toml`,
+    /// falling back to `~/.config/pog/config.toml`.
+    pub fn default_config_path() -> Option<PathBuf> {
+        if let Ok(xdg) = std::env::var("XDG_CONFIG_HOME") {
+            return Some(PathBuf::from(xdg).join("pog").join("config.toml"));
+        }
+        let home = std::env::var("HOME").ok()?;
+        Some(PathBuf::from(home).join(".config").join("pog").join("config.toml"))
+    }
+
+    /// The app-level action bound to `c`, if any.
+    pub fn action_for(&self, c: char) -> Option<AppAction> {
+        match c {
+            c if c == self.quit => Some(AppAction::Quit),
+            c if c == self.suspend => Some(AppAction::Suspend),
+            c if c == self.refresh => Some(AppAction::Refresh),
+            c if c == self.move_up => Some(AppAction::MoveUp),
+            c if c == self.move_down => Some(AppAction::MoveDown),
+            c if c == self.move_left => Some(AppAction::MoveLeft),
+            c if c == self.move_right => Some(AppAction::MoveRight),
+            c if c == self.enter => Some(AppAction::Enter),
+            _ => None,
+        }
+    }
+}
+
+fn read_app_keybindings_config(path: &std::path::Path) -> Option<AppKeyBindingsConfig> {
+    let raw = std::fs::read_to_string(path).ok()?;
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::from_str(&raw).ok(),
+        _ => toml::from_str(&raw).ok(),
+    }
+}
@@ -1,7 +1,19 @@
 mod app;
+pub mod command;
+pub mod command_palette;
+pub mod fuzzy;
+pub mod highlight;
+pub mod keybindings;
+pub mod open;
 mod tabs;
+pub mod theme;
 pub mod widgets;
 
+/// Date helpers now live in `models` (shared with storage's recurrence-rule
+/// windowing); re-exported here so existing `crate::dates::...` call sites
+/// in the Graph/Calendar tabs don't need to change.
+pub use models::dates;
+
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, MouseEventKind},
     execute,
@@ -13,15 +25,30 @@ use ratatui::{
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
     text::Line,
-    widgets::{Block, Borders, Tabs},
+    widgets::{Block, Borders, Paragraph, Tabs},
     Terminal,
 };
 use std::io::{self, stdout};
+use std::sync::mpsc::{Receiver, TryRecvError};
 
 use app::App;
 
-/// Launch the TUI with the provided data.
-pub fn run_with_data(graph_data: GraphData, findings: Vec<Finding>, assets: Vec<Asset>) -> io::Result<()> {
+/// The two kinds of events `run_app`'s loop selects over: a terminal input
+/// event, or a debounced reload signal from the findings-directory watcher.
+enum AppEvent {
+    Input(Event),
+    Reload,
+}
+
+/// Launch the TUI with the provided data. `pog` is used to re-open the
+/// database and watch `pog.findings_dir()` for changes made by another
+/// `pog import` invocation while the TUI is running.
+pub fn run_with_data(
+    pog: storage::PogDir,
+    graph_data: GraphData,
+    findings: Vec<Finding>,
+    assets: Vec<Asset>,
+) -> io::Result<()> {
     // Install a panic hook that restores the terminal before printing
     // the panic message. Without this, a panic leaves the terminal in
     // raw mode, making it unusable.
@@ -38,8 +65,12 @@ pub fn run_with_data(graph_data: GraphData, findings: Vec<Finding>, assets: Vec<
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut app = App::new(graph_data, findings, assets);
-    let result = run_app(&mut terminal, &mut app);
+    // A watcher failure (e.g. inotify limits) shouldn't prevent the TUI from
+    // running, just live-reload.
+    let watch_rx = storage::watch_dir(&pog.findings_dir()).ok();
+
+    let mut app = App::new(pog.clone(), graph_data, findings, assets);
+    let result = run_app(&mut terminal, &mut app, &pog, watch_rx.as_ref());
 
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)?;
@@ -48,35 +79,33 @@ pub fn run_with_data(graph_data: GraphData, findings: Vec<Finding>, assets: Vec<
     result
 }
 
-fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App) -> io::Result<()> {
+fn run_app(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut App,
+    pog: &storage::PogDir,
+    watch_rx: Option<&Receiver<storage::ChangeEvent>>,
+) -> io::Result<()> {
     loop {
-        terminal.draw(|f| {
-            let chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([Constraint::Length(3), Constraint::Min(0)])
-                .split(f.area());
-
-            let titles: Vec<Line> = app.tab_titles().iter().map(|t| Line::from(*t)).collect();
-            let tabs = Tabs::new(titles)
-                .block(Block::default().borders(Borders::ALL).title(" pog (t: switch tab, q: quit) "))
-                .select(app.current_tab_index())
-                .style(Style::default().fg(Color::White))
-                .highlight_style(Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD));
-            f.render_widget(tabs, chunks[0]);
-
-            app.render_current_tab(f, chunks[1]);
-        })?;
+        if let Some(event) = next_event(watch_rx)? {
+            match event {
+                AppEvent::Reload => {
+                    let _ = app.replace_data(pog);
+                }
+                AppEvent::Input(Event::Key(key)) => {
+                    let handled = app.handle_key(key.code, key.modifiers, pog);
 
-        if event::poll(std::time::Duration::from_millis(100))? {
-            match event::read()? {
-                Event::Key(key) => {
-                    if !app.handle_key(key.code)
-                        && (key.code == KeyCode::Char('q') || key.code == KeyCode::Esc)
-                    {
+                    if app.should_quit {
+                        return Ok(());
+                    }
+                    if app.should_suspend {
+                        app.should_suspend = false;
+                        suspend(terminal)?;
+                    }
+                    if !handled && key.code == KeyCode::Esc {
                         return Ok(());
                     }
                 }
-                Event::Mouse(mouse) => {
+                AppEvent::Input(Event::Mouse(mouse)) => {
                     if let MouseEventKind::Down(_) = mouse.kind {
                         if mouse.row >= 1 && mouse.row <= 2 {
                             // Compute actual tab hit regions from title widths.
@@ -106,8 +135,88 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App)
                         app.handle_scroll_up();
                     }
                 }
-                _ => {}
+                AppEvent::Input(_) => {}
             }
         }
+
+        terminal.draw(|f| {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(3), Constraint::Min(0), Constraint::Length(1)])
+                .split(f.area());
+
+            let kb = &app.keybindings;
+            let hints = format!(
+                "t: switch tab, {}: quit, {}: suspend, {}: refresh, {}: open asset, : command",
+                kb.quit, kb.suspend, kb.refresh, kb.enter
+            );
+            let tab_bar_title = match &app.status_message {
+                Some(msg) => format!(" pog ({hints}) — {msg} "),
+                None => format!(" pog ({hints}) "),
+            };
+
+            let titles: Vec<Line> = app.tab_titles().iter().map(|t| Line::from(*t)).collect();
+            let tabs = Tabs::new(titles)
+                .block(Block::default().borders(Borders::ALL).title(tab_bar_title))
+                .select(app.current_tab_index())
+                .style(Style::default().fg(Color::White))
+                .highlight_style(Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD));
+            f.render_widget(tabs, chunks[0]);
+
+            app.render_current_tab(f, chunks[1]);
+
+            let command_line = match &app.command_input {
+                Some(buffer) => format!(":{buffer}▌"),
+                None => String::new(),
+            };
+            let command_bar = Paragraph::new(command_line)
+                .style(Style::default().fg(Color::Yellow));
+            f.render_widget(command_bar, chunks[2]);
+        })?;
+    }
+}
+
+/// Suspend the process to the shell (like a shell's own job control `^Z`),
+/// restoring the terminal first so the shell prompt isn't left inside the
+/// TUI's alternate screen / raw mode, then re-entering both once the
+/// process is resumed.
+///
+/// There's no `nix`/`libc` dependency in this tree to send `SIGSTOP`
+/// directly, so this shells out to `kill -STOP <pid>` instead.
+fn suspend(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)?;
+
+    #[cfg(unix)]
+    {
+        let pid = std::process::id().to_string();
+        let _ = std::process::Command::new("kill").args(["-STOP", &pid]).status();
+    }
+
+    enable_raw_mode()?;
+    execute!(terminal.backend_mut(), EnterAlternateScreen, EnableMouseCapture)?;
+    terminal.clear()?;
+    Ok(())
+}
+
+/// Select over the watcher's reload channel and terminal input, returning
+/// whichever fires first. The watcher side is polled non-blockingly (it
+/// already debounces bursts on its own thread, see [`storage::watch_dir`]),
+/// so the short `event::poll` timeout below is what actually paces the
+/// loop; a reload signal that arrives during that wait is picked up on the
+/// very next iteration.
+fn next_event(watch_rx: Option<&Receiver<storage::ChangeEvent>>) -> io::Result<Option<AppEvent>> {
+    if let Some(rx) = watch_rx {
+        match rx.try_recv() {
+            Ok(_) => return Ok(Some(AppEvent::Reload)),
+            Err(TryRecvError::Empty) => {}
+            Err(TryRecvError::Disconnected) => {}
+        }
     }
+
+    if event::poll(std::time::Duration::from_millis(100))? {
+        return Ok(Some(AppEvent::Input(event::read()?)));
+    }
+
+    Ok(None)
 }
\ No newline at end of file
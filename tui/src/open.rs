@@ -0,0 +1,139 @@
+//! Open a URL in the user's default browser.
+//!
+//! `pog` is frequently run over SSH, inside a container, or under WSL,
+//! none of which have a local desktop browser to hand a URL to directly.
+//! [`open_url`] detects those environments before shelling out and picks
+//! the right strategy instead of letting a doomed `xdg-open` call fail
+//! silently.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// What actually happened when [`open_url`] was asked to open a URL.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OpenOutcome {
+    /// Handed off to a browser (local, or the WSL host's).
+    Opened,
+    /// No usable browser was reachable; the URL was copied to the
+    /// clipboard instead.
+    CopiedToClipboard,
+    /// Neither opening nor copying worked.
+    Failed,
+}
+
+/// Open `target` in a browser, prefixing `https://` when it looks like a
+/// bare hostname or IP rather than an already-schemed URL.
+pub fn open_url(target: &str) -> OpenOutcome {
+    let url = if target.contains("://") {
+        target.to_string()
+    } else {
+        format!("https://{target}")
+    };
+
+    if is_wsl() {
+        // WSL has no browser of its own; hand off to the Windows host.
+        // `wslview` is exec'd directly (no shell re-parsing), but the
+        // `cmd.exe /C start` fallback is not: cmd.exe re-tokenizes its own
+        // command line and treats `&`, `|`, `^`, etc. as command
+        // separators regardless of how Rust quoted the argv, so `url` —
+        // built from `target`, which can come straight from a scanner
+        // import or a target's own DNS record — must be checked against a
+        // strict allowlist before it's ever handed to `cmd.exe`.
+        if run(&["wslview", &url]) || (is_safe_for_cmd_exe(&url) && run(&["cmd.exe", "/C", "start", "", &url])) {
+            return OpenOutcome::Opened;
+        }
+    } else if !is_headless() {
+        let opener = if cfg!(target_os = "macos") { "open" } else { "xdg-open" };
+        if run(&[opener, &url]) {
+            return OpenOutcome::Opened;
+        }
+    }
+
+    if copy_to_clipboard(&url) {
+        OpenOutcome::CopiedToClipboard
+    } else {
+        OpenOutcome::Failed
+    }
+}
+
+/// True when `url` is a plain `http(s)://` URL made up only of characters
+/// that cmd.exe can't reinterpret as command separators or redirections
+/// (`&`, `|`, `^`, `<`, `>`, `%`, quotes, backticks, whitespace, …). Used
+/// to gate the WSL `cmd.exe /C start` fallback, which — unlike `run`'s
+/// other callers — hands `url` to a command-line interpreter rather than
+/// exec'ing it as a single argv entry.
+fn is_safe_for_cmd_exe(url: &str) -> bool {
+    let has_safe_scheme = url.starts_with("http://") || url.starts_with("https://");
+    has_safe_scheme
+        && url
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | ':' | '/' | '_' | '~' | '?' | '=' | '#' | '@'))
+}
+
+fn run(argv: &[&str]) -> bool {
+    Command::new(argv[0])
+        .args(&argv[1..])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+/// True inside WSL: `/proc/version` names "microsoft" (WSL2) or "wsl"
+/// (WSL1) in its kernel build string on every distro we've seen.
+fn is_wsl() -> bool {
+    std::fs::read_to_string("/proc/version")
+        .map(|v| {
+            let v = v.to_lowercase();
+            v.contains("microsoft") || v.contains("wsl")
+        })
+        .unwrap_or(false)
+}
+
+/// True when there's no display to open a browser window on: no
+/// `DISPLAY`/`WAYLAND_DISPLAY`, or inside a container, which has no
+/// equivalent of WSL's host-browser handoff.
+fn is_headless() -> bool {
+    is_container()
+        || (std::env::var_os("DISPLAY").is_none() && std::env::var_os("WAYLAND_DISPLAY").is_none())
+}
+
+fn is_container() -> bool {
+    std::path::Path::new("/.dockerenv").exists()
+        || std::fs::read_to_string("/proc/1/cgroup")
+            .map(|c| c.contains("docker") || c.contains("containerd"))
+            .unwrap_or(false)
+}
+
+/// Try clipboard utilities in rough order of how likely each is to be
+/// installed, writing `text` to whichever one launches successfully.
+fn copy_to_clipboard(text: &str) -> bool {
+    const CANDIDATES: &[&[&str]] = &[
+        &["wl-copy"],
+        &["xclip", "-selection", "clipboard"],
+        &["xsel", "--clipboard", "--input"],
+        &["clip.exe"],
+        &["pbcopy"],
+    ];
+
+    for argv in CANDIDATES {
+        let child = Command::new(argv[0])
+            .args(&argv[1..])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+
+        let Ok(mut child) = child else { continue };
+        let Some(stdin) = child.stdin.as_mut() else { continue };
+        if stdin.write_all(text.as_bytes()).is_err() {
+            continue;
+        }
+        if child.wait().map(|s| s.success()).unwrap_or(false) {
+            return true;
+        }
+    }
+
+    false
+}
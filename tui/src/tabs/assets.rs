@@ -1,4 +1,6 @@
-use crossterm::event::KeyCode;
+use std::collections::HashSet;
+
+use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -6,11 +8,72 @@ use ratatui::{
     widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
+use regex::RegexBuilder;
 
 use models::Asset;
 
+use crate::command_palette::{CommandEntry, CommandPalette};
+use crate::fuzzy::{fuzzy_match_with_case, whole_word_match};
+use crate::theme::Theme;
+
 use super::Tab;
 
+// ---------------------------------------------------------------------------
+// Command palette actions
+// ---------------------------------------------------------------------------
+
+/// Actions the Assets tab's command palette can dispatch.
+#[derive(Clone, Copy)]
+enum AssetAction {
+    Filter(CriticalityFilter),
+    AgeFilter(AssetAgeFilter),
+    ClearSearch,
+    JumpToAsset,
+    /// Export the marked selection (or every visible asset, when nothing is
+    /// marked) to both CSV and JSON.
+    BatchExport,
+}
+
+fn asset_palette_entries() -> Vec<CommandEntry<AssetAction>> {
+    vec![
+        CommandEntry::new("Filter: All", AssetAction::Filter(CriticalityFilter::All)),
+        CommandEntry::new("Filter: Critical", AssetAction::Filter(CriticalityFilter::Critical)),
+        CommandEntry::new("Filter: High", AssetAction::Filter(CriticalityFilter::High)),
+        CommandEntry::new("Filter: Medium", AssetAction::Filter(CriticalityFilter::Medium)),
+        CommandEntry::new("Filter: Low", AssetAction::Filter(CriticalityFilter::Low)),
+        CommandEntry::new("Age: All", AssetAction::AgeFilter(AssetAgeFilter::All)),
+        CommandEntry::new("Age: Newly added", AssetAction::AgeFilter(AssetAgeFilter::NewlyAdded)),
+        CommandEntry::new("Age: Stale", AssetAction::AgeFilter(AssetAgeFilter::Stale)),
+        CommandEntry::new("Clear search", AssetAction::ClearSearch),
+        CommandEntry::new("Jump to asset…", AssetAction::JumpToAsset),
+        CommandEntry::new("Export selected (or visible) to CSV + JSON", AssetAction::BatchExport),
+    ]
+}
+
+// ---------------------------------------------------------------------------
+// Search modes
+// ---------------------------------------------------------------------------
+
+/// Independent search-box toggles, analogous to an editor's find feature.
+#[derive(Clone, Copy, Default)]
+struct SearchModes {
+    case_sensitive: bool,
+    whole_word: bool,
+    regex: bool,
+}
+
+impl SearchModes {
+    fn indicator_spans(&self) -> Vec<Span<'static>> {
+        let on = Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD);
+        let off = Style::default().fg(Color::DarkGray);
+        vec![
+            Span::styled(" C ", if self.case_sensitive { on } else { off }),
+            Span::styled(" W ", if self.whole_word { on } else { off }),
+            Span::styled(" .* ", if self.regex { on } else { off }),
+        ]
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Criticality filter
 // ---------------------------------------------------------------------------
@@ -50,13 +113,114 @@ impl CriticalityFilter {
         }
     }
 
-    pub fn color(&self) -> Color {
+    /// Themed color for this filter option (falls back to white for "All").
+    pub fn color(&self, theme: &Theme) -> Color {
         match self {
             CriticalityFilter::All => Color::White,
-            CriticalityFilter::Critical => Color::Red,
-            CriticalityFilter::High => Color::LightRed,
-            CriticalityFilter::Medium => Color::Yellow,
-            CriticalityFilter::Low => Color::Green,
+            other => theme.criticality_style(other.as_str()).fg.unwrap_or(Color::White),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Age filter
+// ---------------------------------------------------------------------------
+
+/// First-seen/last-seen age threshold, in days, used by [`AssetAgeFilter`].
+const NEW_WITHIN_DAYS: i32 = 7;
+const STALE_AFTER_DAYS: i32 = 30;
+
+/// "Not seen in N days" / "newly added this engagement" filter, cycled with
+/// `n`. Thresholds are fixed rather than configurable, matching the simple
+/// bucket filters elsewhere in this tab (`CriticalityFilter`).
+#[derive(Clone, Copy, PartialEq)]
+enum AssetAgeFilter {
+    All,
+    /// First imported within the last `NEW_WITHIN_DAYS` days.
+    NewlyAdded,
+    /// Not touched by an import in over `STALE_AFTER_DAYS` days.
+    Stale,
+}
+
+impl AssetAgeFilter {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AssetAgeFilter::All => "All",
+            AssetAgeFilter::NewlyAdded => "New",
+            AssetAgeFilter::Stale => "Stale",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            AssetAgeFilter::All => AssetAgeFilter::NewlyAdded,
+            AssetAgeFilter::NewlyAdded => AssetAgeFilter::Stale,
+            AssetAgeFilter::Stale => AssetAgeFilter::All,
+        }
+    }
+
+    fn matches(&self, asset: &Asset) -> bool {
+        match self {
+            AssetAgeFilter::All => true,
+            AssetAgeFilter::NewlyAdded => asset.days_since_created().is_some_and(|d| d <= NEW_WITHIN_DAYS),
+            AssetAgeFilter::Stale => asset.days_since_last_seen().is_some_and(|d| d >= STALE_AFTER_DAYS),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Sorting
+// ---------------------------------------------------------------------------
+
+/// Field the asset list is ordered by, cycled with `o`. `None` (the
+/// default) leaves `filtered_items` in whatever order filtering produced.
+#[derive(Clone, Copy, PartialEq)]
+enum AssetSortField {
+    Name,
+    Criticality,
+    CreatedAt,
+    UpdatedAt,
+    LastSeen,
+}
+
+impl AssetSortField {
+    const ALL: &[AssetSortField] = &[
+        AssetSortField::Name,
+        AssetSortField::Criticality,
+        AssetSortField::CreatedAt,
+        AssetSortField::UpdatedAt,
+        AssetSortField::LastSeen,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            AssetSortField::Name => "Name",
+            AssetSortField::Criticality => "Criticality",
+            AssetSortField::CreatedAt => "Created",
+            AssetSortField::UpdatedAt => "Updated",
+            AssetSortField::LastSeen => "Last Seen",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum AssetSortOrder {
+    Asc,
+    Desc,
+}
+
+impl AssetSortOrder {
+    fn toggled(self) -> Self {
+        match self {
+            AssetSortOrder::Asc => AssetSortOrder::Desc,
+            AssetSortOrder::Desc => AssetSortOrder::Asc,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            AssetSortOrder::Asc => "▲",
+            AssetSortOrder::Desc => "▼",
         }
     }
 }
@@ -69,15 +233,38 @@ pub struct AssetsTab {
     search_input: String,
     search_focused: bool,
     criticality_filter: CriticalityFilter,
+    age_filter: AssetAgeFilter,
     dropdown_open: bool,
     dropdown_selected: usize,
     items: Vec<Asset>,
     filtered_items: Vec<Asset>,
+    /// Matched byte indices into `filtered_items[i].name`, parallel to
+    /// `filtered_items`, used to highlight why a row matched the search.
+    name_matches: Vec<Vec<usize>>,
+    search_modes: SearchModes,
+    /// Set when `search_modes.regex` is on and `search_input` fails to
+    /// compile; the search box border turns red instead of filtering.
+    regex_error: bool,
     list_state: ListState,
     search_area: Option<Rect>,
     criticality_button_area: Option<Rect>,
     dropdown_menu_area: Option<Rect>,
     list_area: Option<Rect>,
+    theme: Theme,
+    palette: CommandPalette<AssetAction>,
+    /// Names of assets marked for batch export (`Asset::name` is unique).
+    /// Kept independent of `filtered_items`'s row order/contents so a
+    /// selection survives re-filtering.
+    selected: HashSet<String>,
+    /// Transient feedback from the last palette action (e.g. export result),
+    /// shown in the list title until the next filter change.
+    status_message: Option<String>,
+    /// Per-row criticality tint for the scrollbar track, recomputed only
+    /// when `(filtered_items.len(), track_height)` changes.
+    scrollbar_colors: Vec<Color>,
+    scrollbar_cache_key: (usize, usize),
+    sort_field: Option<AssetSortField>,
+    sort_order: AssetSortOrder,
 }
 
 impl AssetsTab {
@@ -87,38 +274,115 @@ impl AssetsTab {
         if !filtered_items.is_empty() {
             list_state.select(Some(0));
         }
+        let theme = Theme::load(Theme::default_config_path().as_deref());
+        let name_matches = vec![Vec::new(); filtered_items.len()];
         Self {
             search_input: String::new(),
             search_focused: false,
             criticality_filter: CriticalityFilter::All,
+            age_filter: AssetAgeFilter::All,
             dropdown_open: false,
             dropdown_selected: 0,
             items,
             filtered_items,
+            name_matches,
+            search_modes: SearchModes::default(),
+            regex_error: false,
             list_state,
             search_area: None,
             criticality_button_area: None,
             dropdown_menu_area: None,
             list_area: None,
+            theme,
+            palette: CommandPalette::new(asset_palette_entries()),
+            selected: HashSet::new(),
+            status_message: None,
+            scrollbar_colors: Vec::new(),
+            scrollbar_cache_key: (0, 0),
+            sort_field: None,
+            sort_order: AssetSortOrder::Asc,
         }
     }
 
+    /// Filter and rank assets against the current search query and mode
+    /// toggles, scoped to the active criticality filter. Each asset's name,
+    /// description, contact and DNS/IP are tried as match candidates; the
+    /// best-scoring field wins and, when it's the name, its matched byte
+    /// indices are kept so `render_list` can highlight them. An empty query
+    /// matches everything and preserves the original order.
+    ///
+    /// - Regex mode compiles `query` with the `regex` crate; a compile error
+    ///   sets `regex_error` (search box renders red) and every asset passes.
+    /// - Whole-word mode requires a separator- or boundary-delimited match.
+    /// - Case-sensitive mode disables lowercasing in both of the above.
     fn filter_items(&mut self) {
-        let search_lower = self.search_input.to_lowercase();
-        self.filtered_items = self.items
+        let query = self.search_input.trim();
+        self.regex_error = false;
+        self.status_message = None;
+
+        let regex = if self.search_modes.regex && !query.is_empty() {
+            match RegexBuilder::new(query).case_insensitive(!self.search_modes.case_sensitive).build() {
+                Ok(re) => Some(re),
+                Err(_) => {
+                    self.regex_error = true;
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        let skip_query_filter = self.search_modes.regex && self.regex_error;
+
+        let mut matched: Vec<(Asset, i32, Vec<usize>)> = self.items
             .iter()
-            .filter(|asset| {
-                let matches_search = search_lower.is_empty()
-                    || asset.name.to_lowercase().contains(&search_lower)
-                    || asset.description.to_lowercase().contains(&search_lower)
-                    || asset.contact.to_lowercase().contains(&search_lower)
-                    || asset.dns_or_ip.to_lowercase().contains(&search_lower);
-                let matches_crit = self.criticality_filter.matches(&asset.criticality);
-                matches_search && matches_crit
+            .filter(|asset| self.criticality_filter.matches(&asset.criticality))
+            .filter(|asset| self.age_filter.matches(asset))
+            .filter_map(|asset| {
+                if query.is_empty() || skip_query_filter {
+                    return Some((asset.clone(), 0, Vec::new()));
+                }
+
+                let fields: [&str; 4] =
+                    [&asset.name, &asset.description, &asset.contact, &asset.dns_or_ip];
+
+                if let Some(re) = &regex {
+                    return fields.iter().enumerate().find_map(|(i, field)| {
+                        let m = re.find(field)?;
+                        let indices = if i == 0 { (m.start()..m.end()).collect() } else { Vec::new() };
+                        Some((asset.clone(), 1, indices))
+                    });
+                }
+
+                if self.search_modes.whole_word {
+                    return fields.iter().enumerate().find_map(|(i, field)| {
+                        let range = whole_word_match(query, field, self.search_modes.case_sensitive)?;
+                        let indices = if i == 0 { range.collect() } else { Vec::new() };
+                        Some((asset.clone(), 1, indices))
+                    });
+                }
+
+                let mut best: Option<(i32, Vec<usize>)> = None;
+                for (i, field) in fields.iter().enumerate() {
+                    let Some(m) = fuzzy_match_with_case(query, field, self.search_modes.case_sensitive) else { continue };
+                    if best.as_ref().is_none_or(|(score, _)| m.score > *score) {
+                        let indices = if i == 0 { m.indices } else { Vec::new() };
+                        best = Some((m.score, indices));
+                    }
+                }
+
+                best.map(|(score, indices)| (asset.clone(), score, indices))
             })
-            .cloned()
             .collect();
 
+        if !query.is_empty() && !skip_query_filter {
+            matched.sort_by(|a, b| b.1.cmp(&a.1));
+        }
+
+        self.name_matches = matched.iter().map(|(_, _, indices)| indices.clone()).collect();
+        self.filtered_items = matched.into_iter().map(|(asset, _, _)| asset).collect();
+
+        self.sort_items();
+
         if self.filtered_items.is_empty() {
             self.list_state.select(None);
         } else {
@@ -126,6 +390,85 @@ impl AssetsTab {
         }
     }
 
+    fn cycle_age_filter(&mut self) {
+        self.age_filter = self.age_filter.next();
+        self.filter_items();
+    }
+
+    /// Cycle `None -> Name -> Criticality -> Created -> Updated -> Last Seen -> None`.
+    fn cycle_sort_field(&mut self) {
+        self.sort_field = match self.sort_field {
+            None => Some(AssetSortField::ALL[0]),
+            Some(field) => {
+                let pos = AssetSortField::ALL.iter().position(|f| *f == field).unwrap_or(0);
+                if pos + 1 == AssetSortField::ALL.len() { None } else { Some(AssetSortField::ALL[pos + 1]) }
+            }
+        };
+        self.filter_items();
+    }
+
+    fn toggle_sort_order(&mut self) {
+        self.sort_order = self.sort_order.toggled();
+        self.filter_items();
+    }
+
+    /// Order `filtered_items` (and the parallel `name_matches` highlight
+    /// indices) by the active `sort_field`/`sort_order`, on top of whatever
+    /// filtering and fuzzy ranking already produced. A no-op when
+    /// `sort_field` is `None`.
+    fn sort_items(&mut self) {
+        let Some(field) = self.sort_field else { return };
+
+        let mut order: Vec<usize> = (0..self.filtered_items.len()).collect();
+        order.sort_by(|&i, &j| {
+            let (a, b) = (&self.filtered_items[i], &self.filtered_items[j]);
+            let ordering = match field {
+                AssetSortField::Name => a.name.cmp(&b.name),
+                AssetSortField::Criticality => a.criticality.cmp(&b.criticality),
+                AssetSortField::CreatedAt => a.created_at.cmp(&b.created_at),
+                AssetSortField::UpdatedAt => a.updated_at.cmp(&b.updated_at),
+                AssetSortField::LastSeen => a.last_seen.cmp(&b.last_seen),
+            };
+            match self.sort_order {
+                AssetSortOrder::Asc => ordering,
+                AssetSortOrder::Desc => ordering.reverse(),
+            }
+        });
+
+        self.filtered_items = order.iter().map(|&i| self.filtered_items[i].clone()).collect();
+        self.name_matches = order.iter().map(|&i| self.name_matches[i].clone()).collect();
+    }
+
+    /// Split `text` into spans, styling the characters at `indices` (byte
+    /// offsets) to show why a fuzzy search matched this row.
+    fn highlight_spans(text: &str, indices: &[usize]) -> Vec<Span<'static>> {
+        if indices.is_empty() {
+            return vec![Span::raw(text.to_string())];
+        }
+
+        let match_style = Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+        let mut spans = Vec::new();
+        let mut current = String::new();
+        let mut current_matched = false;
+
+        for (byte_idx, ch) in text.char_indices() {
+            let is_match = indices.contains(&byte_idx);
+            if is_match != current_matched && !current.is_empty() {
+                spans.push(if current_matched {
+                    Span::styled(std::mem::take(&mut current), match_style)
+                } else {
+                    Span::raw(std::mem::take(&mut current))
+                });
+            }
+            current.push(ch);
+            current_matched = is_match;
+        }
+        if !current.is_empty() {
+            spans.push(if current_matched { Span::styled(current, match_style) } else { Span::raw(current) });
+        }
+        spans
+    }
+
     fn toggle_dropdown(&mut self) {
         if self.dropdown_open {
             self.dropdown_open = false;
@@ -179,10 +522,172 @@ impl AssetsTab {
         self.list_state.select(Some(i));
     }
 
+    /// Visible row count of the list, derived from the last rendered
+    /// `list_area` so paging matches what's actually on screen.
+    fn viewport_height(&self) -> usize {
+        self.list_area
+            .map(|area| area.height.saturating_sub(2) as usize)
+            .unwrap_or(1)
+            .max(1)
+    }
+
+    fn list_page_down(&mut self) {
+        if self.filtered_items.is_empty() { return; }
+        let step = self.viewport_height();
+        let i = self.list_state.selected().unwrap_or(0);
+        self.list_state.select(Some((i + step).min(self.filtered_items.len() - 1)));
+    }
+
+    fn list_page_up(&mut self) {
+        if self.filtered_items.is_empty() { return; }
+        let step = self.viewport_height();
+        let i = self.list_state.selected().unwrap_or(0);
+        self.list_state.select(Some(i.saturating_sub(step)));
+    }
+
+    fn list_home(&mut self) {
+        if self.filtered_items.is_empty() { return; }
+        self.list_state.select(Some(0));
+    }
+
+    fn list_end(&mut self) {
+        if self.filtered_items.is_empty() { return; }
+        self.list_state.select(Some(self.filtered_items.len() - 1));
+    }
+
     fn get_selected(&self) -> Option<&Asset> {
         self.list_state.selected().and_then(|i| self.filtered_items.get(i))
     }
 
+    /// Toggle the highlighted row's membership in `selected`.
+    fn toggle_selection(&mut self) {
+        if let Some(asset) = self.get_selected() {
+            let name = asset.name.clone();
+            if !self.selected.remove(&name) {
+                self.selected.insert(name);
+            }
+        }
+    }
+
+    /// Mark every currently visible (filtered) asset as selected.
+    fn select_all_visible(&mut self) {
+        for asset in &self.filtered_items {
+            self.selected.insert(asset.name.clone());
+        }
+    }
+
+    /// Assets targeted by a batch export: the marked selection, or every
+    /// visible asset when nothing is marked.
+    fn resolve_export_targets(&self) -> Vec<&Asset> {
+        if self.selected.is_empty() {
+            self.filtered_items.iter().collect()
+        } else {
+            self.items.iter().filter(|a| self.selected.contains(&a.name)).collect()
+        }
+    }
+
+    /// Run a selected command-palette action against this tab's state.
+    fn dispatch_action(&mut self, action: AssetAction) {
+        match action {
+            AssetAction::Filter(filter) => {
+                self.criticality_filter = filter;
+                self.filter_items();
+            }
+            AssetAction::AgeFilter(filter) => {
+                self.age_filter = filter;
+                self.filter_items();
+            }
+            AssetAction::ClearSearch => {
+                self.search_input.clear();
+                self.search_modes = SearchModes::default();
+                self.filter_items();
+            }
+            AssetAction::JumpToAsset => {
+                self.search_input.clear();
+                self.search_focused = true;
+                self.filter_items();
+            }
+            AssetAction::BatchExport => self.run_batch_export(),
+        }
+    }
+
+    /// Export `resolve_export_targets()` to both CSV and JSON, recording the
+    /// outcome in `status_message`.
+    fn run_batch_export(&mut self) {
+        let targets = self.resolve_export_targets();
+        let count = targets.len();
+        self.status_message = Some(
+            match Self::write_csv("assets_export.csv", &targets)
+                .and_then(|()| Self::write_json("assets_export.json", &targets))
+            {
+                Ok(()) => format!("exported {count} assets to assets_export.csv/.json"),
+                Err(e) => format!("export failed: {e}"),
+            },
+        );
+    }
+
+    /// Write `assets` to `path` as CSV (name, criticality, dns_or_ip,
+    /// contact, description).
+    fn write_csv(path: &str, assets: &[&Asset]) -> std::io::Result<()> {
+        let mut out = String::from("name,criticality,dns_or_ip,contact,description\n");
+        for asset in assets {
+            out.push_str(&Self::csv_field(&asset.name));
+            out.push(',');
+            out.push_str(&Self::csv_field(&asset.criticality));
+            out.push(',');
+            out.push_str(&Self::csv_field(&asset.dns_or_ip));
+            out.push(',');
+            out.push_str(&Self::csv_field(&asset.contact));
+            out.push(',');
+            out.push_str(&Self::csv_field(&asset.description));
+            out.push('\n');
+        }
+        std::fs::write(path, out)
+    }
+
+    /// Quote a CSV field if it contains a comma, quote, or newline.
+    fn csv_field(value: &str) -> String {
+        if value.contains(',') || value.contains('"') || value.contains('\n') {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+
+    /// Write `assets` to `path` as a JSON array of objects, one per asset
+    /// (name, criticality, dns_or_ip, contact, description).
+    fn write_json(path: &str, assets: &[&Asset]) -> std::io::Result<()> {
+        let mut out = String::from("[\n");
+        for (i, asset) in assets.iter().enumerate() {
+            out.push_str("  {\n");
+            out.push_str(&format!("    \"name\": \"{}\",\n", Self::json_string(&asset.name)));
+            out.push_str(&format!("    \"criticality\": \"{}\",\n", Self::json_string(&asset.criticality)));
+            out.push_str(&format!("    \"dns_or_ip\": \"{}\",\n", Self::json_string(&asset.dns_or_ip)));
+            out.push_str(&format!("    \"contact\": \"{}\",\n", Self::json_string(&asset.contact)));
+            out.push_str(&format!("    \"description\": \"{}\"\n", Self::json_string(&asset.description)));
+            out.push_str(if i + 1 < assets.len() { "  },\n" } else { "  }\n" });
+        }
+        out.push_str("]\n");
+        std::fs::write(path, out)
+    }
+
+    /// Escape a string for embedding inside a JSON double-quoted literal.
+    fn json_string(value: &str) -> String {
+        let mut out = String::with_capacity(value.len());
+        for c in value.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out
+    }
+
     fn in_area(col: u16, row: u16, area: Rect) -> bool {
         col >= area.x && col < area.x + area.width && row >= area.y && row < area.y + area.height
     }
@@ -195,12 +700,62 @@ impl AssetsTab {
 impl Tab for AssetsTab {
     fn title(&self) -> &'static str { "Assets" }
 
+    fn compute_layout(&mut self, area: Rect) {
+        let main_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(area);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // search
+                Constraint::Length(3), // criticality filter
+                Constraint::Min(0),   // list
+            ])
+            .split(main_chunks[0]);
+
+        self.search_area = Some(rows[0]);
+        self.criticality_button_area = Some(rows[1]);
+        self.list_area = Some(rows[2]);
+
+        self.dropdown_menu_area = if self.dropdown_open {
+            self.criticality_button_area.map(|button_area| Rect {
+                x: button_area.x,
+                y: button_area.y + button_area.height,
+                width: button_area.width,
+                height: CriticalityFilter::OPTIONS.len() as u16 + 2,
+            })
+        } else {
+            None
+        };
+    }
+
     fn on_blur(&mut self) {
         self.search_focused = false;
         self.dropdown_open = false;
+        self.palette.close();
     }
 
-    fn handle_key(&mut self, key: KeyCode) -> bool {
+    fn handle_key(&mut self, key: KeyCode, modifiers: KeyModifiers) -> bool {
+        // --- command palette open: takes priority over everything else ---
+        if self.palette.is_open() {
+            return match key {
+                KeyCode::Esc => { self.palette.close(); true }
+                KeyCode::Enter => {
+                    if let Some(action) = self.palette.select() {
+                        self.dispatch_action(action);
+                    }
+                    true
+                }
+                KeyCode::Down => { self.palette.next(); true }
+                KeyCode::Up => { self.palette.previous(); true }
+                KeyCode::Char(c) => { self.palette.push_char(c); true }
+                KeyCode::Backspace => { self.palette.pop_char(); true }
+                _ => false,
+            };
+        }
+
         // --- dropdown open ---
         if self.dropdown_open {
             return match key {
@@ -214,22 +769,46 @@ impl Tab for AssetsTab {
 
         // --- search focused ---
         if self.search_focused {
+            if modifiers.contains(KeyModifiers::ALT) {
+                return match key {
+                    KeyCode::Char('c') => { self.search_modes.case_sensitive = !self.search_modes.case_sensitive; self.filter_items(); true }
+                    KeyCode::Char('w') => { self.search_modes.whole_word = !self.search_modes.whole_word; self.filter_items(); true }
+                    KeyCode::Char('r') => { self.search_modes.regex = !self.search_modes.regex; self.filter_items(); true }
+                    _ => false,
+                };
+            }
             return match key {
                 KeyCode::Esc | KeyCode::Enter => { self.search_focused = false; true }
                 KeyCode::Char(c) => { self.search_input.push(c); self.filter_items(); true }
                 KeyCode::Backspace => { self.search_input.pop(); self.filter_items(); true }
                 KeyCode::Down => { self.list_next(); true }
                 KeyCode::Up => { self.list_previous(); true }
+                KeyCode::PageDown => { self.list_page_down(); true }
+                KeyCode::PageUp => { self.list_page_up(); true }
+                KeyCode::Home => { self.list_home(); true }
+                KeyCode::End => { self.list_end(); true }
                 _ => false,
             };
         }
 
         // --- normal mode ---
         match key {
+            KeyCode::Char(':') => { self.palette.open(); true }
             KeyCode::Char('s') => { self.search_focused = true; self.dropdown_open = false; true }
             KeyCode::Char('f') => { self.toggle_dropdown(); true }
+            KeyCode::Char('n') => { self.cycle_age_filter(); true }
+            KeyCode::Char('o') => { self.cycle_sort_field(); true }
+            KeyCode::Char('O') => { self.toggle_sort_order(); true }
+            KeyCode::Char(' ') => { self.toggle_selection(); true }
+            KeyCode::Char('a') => { self.select_all_visible(); true }
+            KeyCode::Esc if !self.selected.is_empty() => { self.selected.clear(); true }
+            KeyCode::Char('e') => { self.run_batch_export(); true }
             KeyCode::Down | KeyCode::Char('j') => { self.list_next(); true }
             KeyCode::Up | KeyCode::Char('k') => { self.list_previous(); true }
+            KeyCode::PageDown => { self.list_page_down(); true }
+            KeyCode::PageUp => { self.list_page_up(); true }
+            KeyCode::Home | KeyCode::Char('g') => { self.list_home(); true }
+            KeyCode::End | KeyCode::Char('G') => { self.list_end(); true }
             _ => false,
         }
     }
@@ -307,6 +886,10 @@ impl Tab for AssetsTab {
         if self.dropdown_open {
             self.render_dropdown_menu(f);
         }
+
+        if self.palette.is_open() {
+            self.palette.render(f, area);
+        }
     }
 }
 
@@ -331,15 +914,22 @@ impl AssetsTab {
     }
 
     fn render_search_box(&mut self, f: &mut Frame, area: Rect) {
-        self.search_area = Some(area);
-
-        let border_style = if self.search_focused {
-            Style::default().fg(Color::Blue)
+        let border_style = if self.regex_error {
+            Style::default().fg(Color::Red)
+        } else if self.search_focused {
+            self.theme.style(self.theme.search_border)
         } else {
             Style::default().fg(Color::White)
         };
 
-        let title = if self.search_focused { " Search (typing...) " } else { " Search (s or click) " };
+        let base_title = if self.search_focused {
+            " Search (typing... Alt+c/w/r to toggle modes) "
+        } else {
+            " Search (s or click) "
+        };
+        let mut title_spans = vec![Span::raw(base_title)];
+        title_spans.extend(self.search_modes.indicator_spans());
+
         let input_text = if self.search_focused {
             format!("{}▌", self.search_input)
         } else {
@@ -347,19 +937,17 @@ impl AssetsTab {
         };
 
         let input = Paragraph::new(input_text)
-            .block(Block::default().borders(Borders::ALL).border_style(border_style).title(title))
-            .style(Style::default().fg(Color::Blue));
+            .block(Block::default().borders(Borders::ALL).border_style(border_style).title(Line::from(title_spans)))
+            .style(self.theme.style(self.theme.search_border));
         f.render_widget(input, area);
     }
 
     fn render_criticality_button(&mut self, f: &mut Frame, area: Rect) {
-        self.criticality_button_area = Some(area);
-
         let arrow = if self.dropdown_open { "▲" } else { "▼" };
         let text = format!(" {} {}", self.criticality_filter.as_str(), arrow);
 
         let border_style = if self.dropdown_open {
-            Style::default().fg(Color::Blue)
+            self.theme.style(self.theme.search_border)
         } else {
             Style::default().fg(Color::White)
         };
@@ -371,30 +959,19 @@ impl AssetsTab {
                     .border_style(border_style)
                     .title(" Criticality Filter (f or click) ")
             )
-            .style(Style::default().fg(self.criticality_filter.color()).add_modifier(Modifier::BOLD));
+            .style(Style::default().fg(self.criticality_filter.color(&self.theme)).add_modifier(Modifier::BOLD));
         f.render_widget(button, area);
     }
 
     fn render_dropdown_menu(&mut self, f: &mut Frame) {
-        if let Some(button_area) = self.criticality_button_area {
-            let option_count = CriticalityFilter::OPTIONS.len();
-            let menu_height = option_count as u16 + 2;
-
-            let menu_area = Rect {
-                x: button_area.x,
-                y: button_area.y + button_area.height,
-                width: button_area.width,
-                height: menu_height,
-            };
-            self.dropdown_menu_area = Some(menu_area);
-
+        if let Some(menu_area) = self.dropdown_menu_area {
             f.render_widget(Clear, menu_area);
 
             let items: Vec<ListItem> = CriticalityFilter::OPTIONS.iter().enumerate().map(|(i, filter)| {
                 let style = if i == self.dropdown_selected {
-                    Style::default().bg(Color::Blue).fg(Color::White).add_modifier(Modifier::BOLD)
+                    self.theme.style(self.theme.dropdown_selected)
                 } else {
-                    Style::default().fg(filter.color())
+                    Style::default().fg(filter.color(&self.theme))
                 };
                 ListItem::new(format!(" {} ", filter.as_str())).style(style)
             }).collect();
@@ -407,37 +984,88 @@ impl AssetsTab {
     }
 
     fn render_list(&mut self, f: &mut Frame, area: Rect) {
-        self.list_area = Some(area);
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(area);
+        let (list_col, scrollbar_col) = (cols[0], cols[1]);
 
         let items: Vec<ListItem> = self.filtered_items
             .iter()
-            .map(|asset| {
-                let crit_color = match asset.criticality.to_lowercase().as_str() {
-                    "critical" => Color::Red,
-                    "high" => Color::LightRed,
-                    "medium" => Color::Yellow,
-                    "low" => Color::Green,
-                    _ => Color::Gray,
-                };
-                ListItem::new(Line::from(vec![
-                    Span::raw(&asset.name),
-                    Span::raw(" "),
-                    Span::styled(
-                        format!("[{}]", asset.criticality),
-                        Style::default().fg(crit_color),
-                    ),
-                ]))
+            .zip(self.name_matches.iter())
+            .map(|(asset, matched_indices)| {
+                let crit_style = self.theme.criticality_style(&asset.criticality);
+                let checkmark = if self.selected.contains(&asset.name) { "✓ " } else { "  " };
+                let mut spans = vec![Span::raw(checkmark)];
+                spans.extend(Self::highlight_spans(&asset.name, matched_indices));
+                spans.push(Span::raw(" "));
+                spans.push(Span::styled(format!("[{}]", asset.criticality), crit_style));
+                ListItem::new(Line::from(spans))
             })
             .collect();
 
-        let title = format!(" Assets ({}) ", self.filtered_items.len());
+        let age_suffix = match self.age_filter {
+            AssetAgeFilter::All => String::new(),
+            other => format!(" — {} (n)", other.as_str()),
+        };
+        let sort_suffix = match self.sort_field {
+            Some(field) => format!(" — sorted by {} {} (o/O)", field.as_str(), self.sort_order.as_str()),
+            None => String::new(),
+        };
+
+        let title = match (self.selected.is_empty(), &self.status_message) {
+            (true, Some(msg)) => format!(" Assets ({}){}{} — {} ", self.filtered_items.len(), age_suffix, sort_suffix, msg),
+            (true, None) => format!(" Assets ({}){}{} ", self.filtered_items.len(), age_suffix, sort_suffix),
+            (false, Some(msg)) => format!(" Assets ({}){}{} — {} selected — {} ", self.filtered_items.len(), age_suffix, sort_suffix, self.selected.len(), msg),
+            (false, None) => format!(" Assets ({}){}{} — {} selected ", self.filtered_items.len(), age_suffix, sort_suffix, self.selected.len()),
+        };
 
         let list = List::new(items)
             .block(Block::default().borders(Borders::ALL).title(title))
-            .highlight_style(Style::default().bg(Color::Blue).fg(Color::White).add_modifier(Modifier::BOLD))
+            .highlight_style(self.theme.style(self.theme.highlight))
             .highlight_symbol("▶ ");
 
-        f.render_stateful_widget(list, area, &mut self.list_state);
+        f.render_stateful_widget(list, list_col, &mut self.list_state);
+
+        self.render_scrollbar(f, scrollbar_col);
+    }
+
+    /// Render a one-column density map of criticality across the full
+    /// filtered list, tinted red/orange/yellow/green by proportional
+    /// position. The per-row colors are cached and only rebuilt when
+    /// `filtered_items.len()` or the track height changes.
+    fn render_scrollbar(&mut self, f: &mut Frame, area: Rect) {
+        let track_height = area.height.saturating_sub(2) as usize;
+        let cache_key = (self.filtered_items.len(), track_height);
+        if self.scrollbar_cache_key != cache_key {
+            self.rebuild_scrollbar_cache(track_height);
+            self.scrollbar_cache_key = cache_key;
+        }
+
+        for (row, color) in self.scrollbar_colors.iter().enumerate() {
+            let y = area.y + 1 + row as u16;
+            if y >= area.y + area.height.saturating_sub(1) {
+                break;
+            }
+            let cell = Rect { x: area.x, y, width: area.width, height: 1 };
+            f.render_widget(Paragraph::new("┃").style(Style::default().fg(*color)), cell);
+        }
+    }
+
+    fn rebuild_scrollbar_cache(&mut self, track_height: usize) {
+        self.scrollbar_colors = if self.filtered_items.is_empty() || track_height == 0 {
+            Vec::new()
+        } else {
+            (0..track_height)
+                .map(|row| {
+                    let idx = (row * self.filtered_items.len() / track_height)
+                        .min(self.filtered_items.len() - 1);
+                    self.theme.criticality_style(&self.filtered_items[idx].criticality)
+                        .fg
+                        .unwrap_or(Color::Gray)
+                })
+                .collect()
+        };
     }
 
     fn render_details(&self, f: &mut Frame, area: Rect) {
@@ -457,6 +1085,7 @@ impl AssetsTab {
                     Constraint::Length(2), // criticality
                     Constraint::Length(2), // dns/ip
                     Constraint::Length(2), // contact
+                    Constraint::Length(2), // first seen / last seen
                     Constraint::Length(1), // spacer
                     Constraint::Min(0),   // description
                 ])
@@ -468,16 +1097,10 @@ impl AssetsTab {
             ]));
             f.render_widget(name, chunks[0]);
 
-            let crit_color = match asset.criticality.to_lowercase().as_str() {
-                "critical" => Color::Red,
-                "high" => Color::LightRed,
-                "medium" => Color::Yellow,
-                "low" => Color::Green,
-                _ => Color::Gray,
-            };
+            let crit_style = self.theme.criticality_style(&asset.criticality);
             let criticality = Paragraph::new(Line::from(vec![
                 Span::styled("Criticality: ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::styled(&asset.criticality, Style::default().fg(crit_color)),
+                Span::styled(&asset.criticality, crit_style),
             ]));
             f.render_widget(criticality, chunks[1]);
 
@@ -493,12 +1116,21 @@ impl AssetsTab {
             ]));
             f.render_widget(contact, chunks[3]);
 
+            let seen = Paragraph::new(Line::from(vec![
+                Span::styled("First seen: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(&asset.created_at),
+                Span::raw("   "),
+                Span::styled("Last seen: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(&asset.last_seen),
+            ]));
+            f.render_widget(seen, chunks[4]);
+
             let description = Paragraph::new(Line::from(vec![
                 Span::styled("Description:\n", Style::default().add_modifier(Modifier::BOLD)),
                 Span::raw(&asset.description),
             ]))
             .wrap(Wrap { trim: true });
-            f.render_widget(description, chunks[5]);
+            f.render_widget(description, chunks[6]);
         } else {
             let empty = Paragraph::new("No asset selected")
                 .block(block)
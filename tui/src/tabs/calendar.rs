@@ -0,0 +1,333 @@
+//! Calendar heatmap tab: a month grid where each day cell is shaded by how
+//! many findings landed on it (and tinted by the worst severity present),
+//! similar to a contributions/habit-tracker grid. Complements the Graph
+//! tab's bar/line views with an at-a-glance density view of a pentest
+//! timeline.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use std::collections::BTreeMap;
+
+use models::{Finding, Severity};
+
+use crate::dates::{day_ordinal, days_in_month, month_abbrev, parse_ymd, today_ymd, weekday_index};
+
+use super::Tab;
+
+const WEEKDAY_HEADERS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// A single day's aggregated findings.
+#[derive(Default, Clone, Copy)]
+struct DayCell {
+    count: u32,
+    /// Lowest severity index present that day (0 = Critical), i.e. the
+    /// worst severity seen.
+    worst: Option<usize>,
+}
+
+pub struct CalendarTab {
+    findings: Vec<Finding>,
+    year: i32,
+    month: u32,
+    /// Day of month (1-based) clicked most recently; `None` until a cell is
+    /// clicked. Enter toggles the drill-down list for this day.
+    selected_day: Option<u32>,
+    drill_open: bool,
+    list_state: ListState,
+    /// Hitboxes computed in `compute_layout`, read (never written) by
+    /// `handle_click` and `render`.
+    cell_areas: Vec<(Rect, u32)>,
+    header_area: Option<Rect>,
+    weekday_area: Option<Rect>,
+    drill_area: Option<Rect>,
+}
+
+impl CalendarTab {
+    pub fn new(findings: Vec<Finding>) -> Self {
+        let (year, month) = Self::initial_month(&findings);
+        Self {
+            findings,
+            year,
+            month,
+            selected_day: None,
+            drill_open: false,
+            list_state: ListState::default(),
+            cell_areas: Vec::new(),
+            header_area: None,
+            weekday_area: None,
+            drill_area: None,
+        }
+    }
+
+    /// Start on the month of the most recent finding, falling back to the
+    /// current month when there are no dated findings.
+    fn initial_month(findings: &[Finding]) -> (i32, u32) {
+        let latest = findings
+            .iter()
+            .filter_map(|f| parse_ymd(&f.date))
+            .max_by_key(|&(y, m, d)| day_ordinal(y, m, d));
+
+        match latest {
+            Some((y, m, _)) => (y, m),
+            None => {
+                let (y, m, _) = today_ymd();
+                (y, m)
+            }
+        }
+    }
+
+    /// Replace the backing findings (e.g. after a watcher-triggered
+    /// reload). The displayed month and drill-down selection are left
+    /// untouched.
+    pub fn set_data(&mut self, findings: Vec<Finding>) {
+        self.findings = findings;
+    }
+
+    fn prev_month(&mut self) {
+        if self.month == 1 {
+            self.month = 12;
+            self.year -= 1;
+        } else {
+            self.month -= 1;
+        }
+        self.selected_day = None;
+        self.drill_open = false;
+    }
+
+    fn next_month(&mut self) {
+        if self.month == 12 {
+            self.month = 1;
+            self.year += 1;
+        } else {
+            self.month += 1;
+        }
+        self.selected_day = None;
+        self.drill_open = false;
+    }
+
+    /// Aggregate this month's findings per day of month.
+    fn day_cells(&self) -> BTreeMap<u32, DayCell> {
+        let mut cells: BTreeMap<u32, DayCell> = BTreeMap::new();
+        for f in &self.findings {
+            if let Some((y, m, d)) = parse_ymd(&f.date) {
+                if y == self.year && m == self.month {
+                    let idx = Severity::ALL.iter().position(|&s| s == f.severity).unwrap_or(0);
+                    let cell = cells.entry(d).or_default();
+                    cell.count += 1;
+                    cell.worst = Some(cell.worst.map_or(idx, |w| w.min(idx)));
+                }
+            }
+        }
+        cells
+    }
+
+    /// `(week_row, weekday_col)` grid position of each day in the month.
+    fn month_layout(&self) -> (u32, Vec<(u32, u32, u32)>) {
+        let first_weekday = weekday_index(self.year, self.month, 1);
+        let total_days = days_in_month(self.month, self.year);
+
+        let positions: Vec<(u32, u32, u32)> = (1..=total_days)
+            .map(|day| {
+                let slot = first_weekday + day - 1;
+                (slot / 7, slot % 7, day)
+            })
+            .collect();
+        let weeks = positions.last().map_or(1, |&(row, _, _)| row + 1);
+        (weeks, positions)
+    }
+
+    /// Findings that fall on `day` of the displayed month.
+    fn findings_on(&self, day: u32) -> Vec<&Finding> {
+        self.findings
+            .iter()
+            .filter(|f| parse_ymd(&f.date) == Some((self.year, self.month, day)))
+            .collect()
+    }
+
+    /// Shading for a day cell: darker/dimmer for fewer findings, full
+    /// brightness and bold once a day has several, tinted by the worst
+    /// severity seen that day.
+    fn cell_style(cell: Option<DayCell>, selected: bool) -> Style {
+        let Some(cell) = cell else {
+            return Style::default().fg(Color::DarkGray);
+        };
+        let color = cell.worst.map(|i| Severity::ALL[i].color()).unwrap_or(Color::DarkGray);
+        let mut style = Style::default().fg(color);
+        style = match cell.count {
+            0 => style.add_modifier(Modifier::DIM),
+            1..=2 => style,
+            3..=5 => style.add_modifier(Modifier::BOLD),
+            _ => style.add_modifier(Modifier::BOLD | Modifier::REVERSED),
+        };
+        if selected {
+            style = style.add_modifier(Modifier::UNDERLINED);
+        }
+        style
+    }
+}
+
+impl Tab for CalendarTab {
+    fn title(&self) -> &'static str { "Calendar" }
+
+    fn compute_layout(&mut self, area: Rect) {
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(if self.drill_open {
+                vec![Constraint::Min(30), Constraint::Length(34)]
+            } else {
+                vec![Constraint::Min(30)]
+            })
+            .split(area);
+
+        let grid_area = cols[0];
+        self.drill_area = self.drill_open.then(|| cols[1]);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // month header
+                Constraint::Length(1), // weekday header
+                Constraint::Min(6),    // week rows
+            ])
+            .split(grid_area);
+        self.header_area = Some(rows[0]);
+        self.weekday_area = Some(rows[1]);
+
+        let (weeks, positions) = self.month_layout();
+        let week_rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Ratio(1, weeks.max(1)); weeks as usize])
+            .split(rows[2]);
+
+        self.cell_areas.clear();
+        for (week, col, day) in positions {
+            let week_cols = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Ratio(1, 7); 7])
+                .split(week_rows[week as usize]);
+            self.cell_areas.push((week_cols[col as usize], day));
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> bool {
+        match key {
+            KeyCode::Left => {
+                self.prev_month();
+                true
+            }
+            KeyCode::Right => {
+                self.next_month();
+                true
+            }
+            KeyCode::Enter => {
+                if self.selected_day.is_some() {
+                    self.drill_open = !self.drill_open;
+                    self.list_state.select(self.drill_open.then_some(0));
+                    true
+                } else {
+                    false
+                }
+            }
+            KeyCode::Esc if self.drill_open => {
+                self.drill_open = false;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn handle_click(&mut self, col: u16, row: u16) {
+        for &(area, day) in &self.cell_areas {
+            if col >= area.x && col < area.x + area.width && row >= area.y && row < area.y + area.height {
+                self.selected_day = Some(day);
+                return;
+            }
+        }
+    }
+
+    fn handle_scroll_down(&mut self) {
+        if self.drill_open {
+            let i = self.list_state.selected().map_or(0, |i| i + 1);
+            self.list_state.select(Some(i));
+        }
+    }
+
+    fn handle_scroll_up(&mut self) {
+        if self.drill_open {
+            let i = self.list_state.selected().map_or(0, |i| i.saturating_sub(1));
+            self.list_state.select(Some(i));
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, _area: Rect) {
+        // All geometry is already computed in `compute_layout`.
+        if let Some(header_area) = self.header_area {
+            let header = Paragraph::new(format!("◀ {} {} ▶  (←/→ change month)", month_abbrev(self.month), self.year))
+                .alignment(Alignment::Center)
+                .style(Style::default().add_modifier(Modifier::BOLD));
+            f.render_widget(header, header_area);
+        }
+
+        if let Some(weekday_area) = self.weekday_area {
+            let weekday_cols = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Ratio(1, 7); 7])
+                .split(weekday_area);
+            for (i, name) in WEEKDAY_HEADERS.iter().enumerate() {
+                let w = Paragraph::new(*name).alignment(Alignment::Center).style(Style::default().fg(Color::DarkGray));
+                f.render_widget(w, weekday_cols[i]);
+            }
+        }
+
+        let cells = self.day_cells();
+        for &(cell_area, day) in &self.cell_areas {
+            let cell = cells.get(&day).copied();
+            let selected = self.selected_day == Some(day);
+            let style = Self::cell_style(cell, selected);
+
+            let label = match cell {
+                Some(c) if c.count > 0 => format!("{day}\n{}", c.count),
+                _ => format!("{day}"),
+            };
+
+            let block = Block::default().borders(Borders::ALL).border_style(style);
+            let widget = Paragraph::new(label)
+                .alignment(Alignment::Center)
+                .style(style)
+                .block(block);
+            f.render_widget(widget, cell_area);
+        }
+
+        if let Some(drill_area) = self.drill_area {
+            self.render_drill_down(f, drill_area);
+        }
+    }
+}
+
+impl CalendarTab {
+    fn render_drill_down(&mut self, f: &mut Frame, area: Rect) {
+        let Some(day) = self.selected_day else { return };
+        let findings = self.findings_on(day);
+
+        let title = format!(" {} {} {} ({}) ", month_abbrev(self.month), day, self.year, findings.len());
+        let items: Vec<ListItem> = findings
+            .iter()
+            .map(|f| {
+                ListItem::new(format!("[{}] {} ({})", f.severity.as_str(), f.title, f.asset))
+                    .style(Style::default().fg(f.severity.color()))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .highlight_style(Style::default().bg(Color::Blue).fg(Color::White).add_modifier(Modifier::BOLD))
+            .highlight_symbol("▶ ");
+
+        f.render_stateful_widget(list, area, &mut self.list_state);
+    }
+}
@@ -1,15 +1,20 @@
 use std::collections::BTreeMap;
 
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     symbols,
-    widgets::{Axis, Block, Borders, Chart, Dataset, Gauge, GraphType, List, ListItem, Paragraph},
+    widgets::{
+        Axis, Block, Borders, Chart, Dataset, Gauge, GraphType, LegendPosition, List, ListItem,
+        Paragraph,
+    },
     Frame,
 };
 
-use models::{Finding, GraphData, Severity};
+use models::{Finding, GraphData, Severity, SeverityBar};
+
+use crate::dates::{approx_month_day, day_ordinal, days_in_month, month_abbrev, parse_ymd};
 
 use super::Tab;
 
@@ -23,18 +28,48 @@ struct SeverityToggle {
 }
 
 // ---------------------------------------------------------------------------
-// Timeline data — weekly buckets for a line / area graph
+// Timeline data — day/week/month buckets for a line graph
 // ---------------------------------------------------------------------------
 
-/// One point on the x-axis (a week).
-struct WeekBucket {
-    /// Short label, e.g. "Sep 1" or "Jan 20".
+/// Granularity of the timeline's x-axis buckets, cycled with `g`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Bucket {
+    Day,
+    Week,
+    Month,
+}
+
+impl Bucket {
+    fn next(self) -> Bucket {
+        match self {
+            Bucket::Day => Bucket::Week,
+            Bucket::Week => Bucket::Month,
+            Bucket::Month => Bucket::Day,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Bucket::Day => "Day",
+            Bucket::Week => "Week",
+            Bucket::Month => "Month",
+        }
+    }
+}
+
+/// One point on the x-axis (a day, week, or month depending on [`Bucket`]).
+struct TimeBucket {
+    /// Short label, e.g. "Sep 1", "Jan 20", or "Jan 2024".
     label: String,
-    /// Count of findings that fall in this week, per severity.
+    /// Count of findings that fall in this bucket, per severity.
     severity_counts: [u32; 5],
+    /// Inclusive `day_ordinal` range this bucket covers, so a selected
+    /// bucket can be translated back into the findings that fall in it.
+    start_ord: i32,
+    end_ord: i32,
 }
 
-impl WeekBucket {
+impl TimeBucket {
     fn total(&self, toggles: &[SeverityToggle]) -> u32 {
         Severity::ALL
             .iter()
@@ -49,37 +84,22 @@ fn severity_index(s: Severity) -> usize {
     Severity::ALL.iter().position(|&v| v == s).unwrap_or(0)
 }
 
-/// Parse "YYYY/MM/DD" → (year, month, day).
-fn parse_ymd(date: &str) -> Option<(i32, u32, u32)> {
-    let parts: Vec<&str> = date.split('/').collect();
-    if parts.len() < 3 { return None; }
-    Some((parts[0].parse().ok()?, parts[1].parse().ok()?, parts[2].parse().ok()?))
-}
-
-/// Convert (year, month, day) → ordinal day count since an arbitrary epoch
-/// (good enough for grouping into 7-day buckets).
-fn day_ordinal(y: i32, m: u32, d: u32) -> i32 {
-    let m = m as i32;
-    let d = d as i32;
-    // Rata Die–style day number (simplified, doesn't need to be exact)
-    let a = (14 - m) / 12;
-    let yy = y + 4800 - a;
-    let mm = m + 12 * a - 3;
-    d + (153 * mm + 2) / 5 + 365 * yy + yy / 4 - yy / 100 + yy / 400 - 32045
-}
-
-fn month_abbrev(m: u32) -> &'static str {
-    match m {
-        1 => "Jan", 2 => "Feb", 3 => "Mar", 4 => "Apr",
-        5 => "May", 6 => "Jun", 7 => "Jul", 8 => "Aug",
-        9 => "Sep", 10 => "Oct", 11 => "Nov", 12 => "Dec",
-        _ => "???",
-    }
+/// Findings whose date falls inside `bucket`'s `[start_ord, end_ord]` range.
+fn findings_in_bucket<'a>(findings: &'a [Finding], bucket: &TimeBucket) -> Vec<&'a Finding> {
+    findings
+        .iter()
+        .filter(|f| {
+            parse_ymd(&f.date)
+                .map(|(y, m, d)| day_ordinal(y, m, d))
+                .is_some_and(|ord| ord >= bucket.start_ord && ord <= bucket.end_ord)
+        })
+        .collect()
 }
 
-fn build_weekly_timeline(findings: &[Finding]) -> Vec<WeekBucket> {
-    // Parse all dates into ordinal days.
-    let mut entries: Vec<(i32, u32, u32, usize)> = Vec::new(); // (y, m, d, sev_idx)
+/// Build the timeline at the requested [`Bucket`] granularity.
+fn build_timeline(findings: &[Finding], bucket: Bucket) -> Vec<TimeBucket> {
+    // Parse all dates into (year, month, day, severity index).
+    let mut entries: Vec<(i32, u32, u32, usize)> = Vec::new();
     for f in findings {
         if let Some((y, m, d)) = parse_ymd(&f.date) {
             entries.push((y, m, d, severity_index(f.severity)));
@@ -87,17 +107,26 @@ fn build_weekly_timeline(findings: &[Finding]) -> Vec<WeekBucket> {
     }
     if entries.is_empty() { return Vec::new(); }
 
+    match bucket {
+        Bucket::Month => build_monthly_timeline(&entries),
+        Bucket::Day => build_ordinal_timeline(&entries, 1),
+        Bucket::Week => build_ordinal_timeline(&entries, 7),
+    }
+}
+
+/// Day/week buckets: fixed-width groups of `width` days since the earliest
+/// finding, labelled with the date of the first day in each bucket.
+fn build_ordinal_timeline(entries: &[(i32, u32, u32, usize)], width: i32) -> Vec<TimeBucket> {
     let ords: Vec<i32> = entries.iter().map(|&(y, m, d, _)| day_ordinal(y, m, d)).collect();
     let min_ord = *ords.iter().min().unwrap();
     let max_ord = *ords.iter().max().unwrap();
 
-    // Bucket width = 7 days.
-    let bucket_count = ((max_ord - min_ord) / 7 + 1) as usize;
+    let bucket_count = ((max_ord - min_ord) / width + 1) as usize;
 
     // Group into buckets.
     let mut buckets_map: BTreeMap<usize, [u32; 5]> = BTreeMap::new();
     for (i, &ord) in ords.iter().enumerate() {
-        let idx = ((ord - min_ord) / 7) as usize;
+        let idx = ((ord - min_ord) / width) as usize;
         buckets_map.entry(idx).or_insert([0; 5])[entries[i].3] += 1;
     }
 
@@ -108,7 +137,7 @@ fn build_weekly_timeline(findings: &[Finding]) -> Vec<WeekBucket> {
     {
         let first = entries.iter().min_by_key(|e| day_ordinal(e.0, e.1, e.2)).unwrap();
         for b in 0..bucket_count {
-            let delta = (b as i32) * 7;
+            let delta = (b as i32) * width;
             let (rm, rd) = approx_month_day(first.0, first.1, first.2, delta);
             date_of_bucket.push((rm, rd));
         }
@@ -118,41 +147,44 @@ fn build_weekly_timeline(findings: &[Finding]) -> Vec<WeekBucket> {
         .map(|b| {
             let counts = buckets_map.get(&b).copied().unwrap_or([0; 5]);
             let (m, d) = date_of_bucket[b];
-            WeekBucket {
+            let start_ord = min_ord + (b as i32) * width;
+            TimeBucket {
                 label: format!("{} {}", month_abbrev(m), d),
                 severity_counts: counts,
+                start_ord,
+                end_ord: start_ord + width - 1,
             }
         })
         .collect()
 }
 
-/// Approximate month/day after adding `delta` days to (y, m, d).
-fn approx_month_day(y: i32, m: u32, d: u32, delta: i32) -> (u32, u32) {
-    let days_in = |mm: u32, yy: i32| -> u32 {
-        match mm {
-            1|3|5|7|8|10|12 => 31,
-            4|6|9|11 => 30,
-            2 => if yy % 4 == 0 && (yy % 100 != 0 || yy % 400 == 0) { 29 } else { 28 },
-            _ => 30,
-        }
-    };
-
-    let mut yy = y;
-    let mut mm = m;
-    let mut dd = d as i32 + delta;
-
-    while dd > days_in(mm, yy) as i32 {
-        dd -= days_in(mm, yy) as i32;
-        mm += 1;
-        if mm > 12 { mm = 1; yy += 1; }
-    }
-    while dd < 1 {
-        mm = if mm == 1 { 12 } else { mm - 1 };
-        if mm == 12 { yy -= 1; }
-        dd += days_in(mm, yy) as i32;
+/// Month buckets: one entry per calendar `(year, month)`, filling any empty
+/// months in between so the chart stays continuous.
+fn build_monthly_timeline(entries: &[(i32, u32, u32, usize)]) -> Vec<TimeBucket> {
+    let mut buckets_map: BTreeMap<(i32, u32), [u32; 5]> = BTreeMap::new();
+    for &(y, m, _, sev_idx) in entries {
+        buckets_map.entry((y, m)).or_insert([0; 5])[sev_idx] += 1;
     }
 
-    (mm, dd as u32)
+    let (&(min_y, min_m), _) = buckets_map.iter().next().unwrap();
+    let (&(max_y, max_m), _) = buckets_map.iter().next_back().unwrap();
+
+    let mut result = Vec::new();
+    let (mut y, mut m) = (min_y, min_m);
+    loop {
+        let counts = buckets_map.get(&(y, m)).copied().unwrap_or([0; 5]);
+        result.push(TimeBucket {
+            label: format!("{} {}", month_abbrev(m), y),
+            severity_counts: counts,
+            start_ord: day_ordinal(y, m, 1),
+            end_ord: day_ordinal(y, m, days_in_month(m, y)),
+        });
+
+        if (y, m) == (max_y, max_m) { break; }
+        m += 1;
+        if m > 12 { m = 1; y += 1; }
+    }
+    result
 }
 
 // ---------------------------------------------------------------------------
@@ -165,6 +197,23 @@ pub struct GraphTab {
     toggles: Vec<SeverityToggle>,
     toggle_cursor: usize,
     filter_area: Option<Rect>,
+    /// Live incremental filter text (bound to `/` or `f`): narrows the
+    /// findings feeding the timeline and severity bars on every keystroke,
+    /// matched case-insensitively against title, asset, severity, and status.
+    filter_input: String,
+    filter_focused: bool,
+    filter_input_area: Option<Rect>,
+    /// Timeline bucket granularity, cycled with `g`.
+    bucket: Bucket,
+    /// Index into the current timeline's buckets, selected by click or
+    /// ←/→; drives the findings drill-down panel.
+    selected_bucket: Option<usize>,
+    /// Hitbox of the plotted chart area (not the whole timeline block),
+    /// used to translate a click column into a bucket index.
+    timeline_area: Option<Rect>,
+    /// Number of buckets in the last rendered timeline, needed alongside
+    /// `timeline_area` to map a click column to a bucket index.
+    timeline_bucket_count: usize,
 }
 
 impl GraphTab {
@@ -179,19 +228,176 @@ impl GraphTab {
             toggles,
             toggle_cursor: 0,
             filter_area: None,
+            filter_input: String::new(),
+            filter_focused: false,
+            filter_input_area: None,
+            bucket: Bucket::Week,
+            selected_bucket: None,
+            timeline_area: None,
+            timeline_bucket_count: 0,
         }
     }
 
     fn toggle_current(&mut self) {
         self.toggles[self.toggle_cursor].enabled = !self.toggles[self.toggle_cursor].enabled;
     }
+
+    /// The bar chart's title, e.g. to reuse as a saved report's title.
+    pub fn title(&self) -> &str {
+        &self.data.title
+    }
+
+    /// Replace the bar chart data and backing findings in place (e.g. after
+    /// a watcher-triggered reload). Severity toggle state is left untouched.
+    pub fn set_data(&mut self, data: GraphData, findings: Vec<Finding>) {
+        self.data = data;
+        self.findings = findings;
+    }
+
+    /// Findings matching the live filter text. An empty filter matches
+    /// everything.
+    fn visible_findings(&self) -> Vec<Finding> {
+        if self.filter_input.is_empty() {
+            return self.findings.clone();
+        }
+        let query = self.filter_input.to_lowercase();
+        self.findings
+            .iter()
+            .filter(|f| {
+                f.title.to_lowercase().contains(&query)
+                    || f.asset.to_lowercase().contains(&query)
+                    || f.severity.as_str().to_lowercase().contains(&query)
+                    || f.status.as_str().to_lowercase().contains(&query)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Severity-distribution bars recomputed from `findings`, mirroring
+    /// `main::build_graph_data` but scoped to whatever subset is visible.
+    fn severity_bars(findings: &[Finding]) -> Vec<SeverityBar> {
+        Severity::ALL
+            .iter()
+            .filter_map(|&sev| {
+                let count = findings.iter().filter(|f| f.severity == sev).count() as u64;
+                (count > 0).then(|| SeverityBar::from_severity(sev, count))
+            })
+            .collect()
+    }
+
+    /// Severities with an enabled toggle, in stacking order (Critical at the
+    /// bottom).
+    fn enabled_severities(&self) -> Vec<(usize, Severity)> {
+        Severity::ALL
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| self.toggles.iter().any(|t| t.severity == **s && t.enabled))
+            .map(|(i, s)| (i, *s))
+            .collect()
+    }
+}
+
+/// Upper bound of the timeline's y-axis: the largest cumulative (stacked)
+/// total across all buckets, rounded up to the next multiple of 4 so the
+/// axis labels land on round numbers. Shared by `render_timeline` (which
+/// draws the axis) and `compute_layout` (which needs the same value to size
+/// the y-axis label gutter for click-to-bucket mapping).
+fn timeline_y_max(buckets: &[TimeBucket], enabled: &[(usize, Severity)]) -> f64 {
+    let mut running = vec![0u32; buckets.len()];
+    for &(idx, _) in enabled {
+        for (i, b) in buckets.iter().enumerate() {
+            running[i] += b.severity_counts[idx];
+        }
+    }
+    let max_val = running.iter().copied().max().unwrap_or(1).max(1) as f64;
+    let y_step = (max_val / 4.0).ceil().max(1.0);
+    (y_step * 4.0).max(max_val)
 }
 
 impl Tab for GraphTab {
     fn title(&self) -> &'static str { "Graph" }
 
-    fn handle_key(&mut self, key: KeyCode) -> bool {
+    fn compute_layout(&mut self, area: Rect) {
+        let bar_count = self.data.bars.len() as u16;
+        let severity_height = bar_count * 2 + 3;
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(severity_height),
+                Constraint::Min(10),
+            ])
+            .split(area);
+
+        self.filter_input_area = Some(rows[0]);
+
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Min(30),
+                Constraint::Length(22),
+            ])
+            .split(rows[2]);
+
+        self.filter_area = Some(cols[1]);
+
+        let timeline_rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(if self.selected_bucket.is_some() {
+                vec![Constraint::Min(10), Constraint::Length(8)]
+            } else {
+                vec![Constraint::Min(10)]
+            })
+            .split(cols[0]);
+
+        let buckets = build_timeline(&self.visible_findings(), self.bucket);
+        self.timeline_bucket_count = buckets.len();
+
+        // The chart draws inside its block's border plus a left gutter for
+        // the y-axis labels; narrow the stored hitbox by the same amount so
+        // a click column maps back to the bucket it visually lands on.
+        let inner = Block::default().borders(Borders::ALL).inner(timeline_rows[0]);
+        let enabled = self.enabled_severities();
+        let y_max = timeline_y_max(&buckets, &enabled);
+        let y_label_width = format!("{}", y_max as u32).len() as u16 + 1;
+        self.timeline_area = Some(Rect {
+            x: inner.x + y_label_width,
+            y: inner.y,
+            width: inner.width.saturating_sub(y_label_width),
+            height: inner.height,
+        });
+    }
+
+    fn handle_key(&mut self, key: KeyCode, _modifiers: KeyModifiers) -> bool {
+        if self.filter_focused {
+            return match key {
+                KeyCode::Esc => {
+                    self.filter_focused = false;
+                    self.filter_input.clear();
+                    true
+                }
+                KeyCode::Enter => {
+                    self.filter_focused = false;
+                    true
+                }
+                KeyCode::Backspace => {
+                    self.filter_input.pop();
+                    true
+                }
+                KeyCode::Char(c) => {
+                    self.filter_input.push(c);
+                    true
+                }
+                _ => true,
+            };
+        }
+
         match key {
+            KeyCode::Char('/') | KeyCode::Char('f') => {
+                self.filter_focused = true;
+                true
+            }
             KeyCode::Down | KeyCode::Char('j') => {
                 self.toggle_cursor = (self.toggle_cursor + 1) % self.toggles.len();
                 true
@@ -208,11 +414,45 @@ impl Tab for GraphTab {
                 self.toggle_current();
                 true
             }
+            KeyCode::Char('g') => {
+                self.bucket = self.bucket.next();
+                true
+            }
+            KeyCode::Left if self.timeline_bucket_count > 0 => {
+                self.selected_bucket = Some(match self.selected_bucket {
+                    Some(i) => i.saturating_sub(1),
+                    None => self.timeline_bucket_count - 1,
+                });
+                true
+            }
+            KeyCode::Right if self.timeline_bucket_count > 0 => {
+                self.selected_bucket = Some(match self.selected_bucket {
+                    Some(i) => (i + 1).min(self.timeline_bucket_count - 1),
+                    None => 0,
+                });
+                true
+            }
+            KeyCode::Esc if self.selected_bucket.is_some() => {
+                self.selected_bucket = None;
+                true
+            }
             _ => false,
         }
     }
 
     fn handle_click(&mut self, col: u16, row: u16) {
+        if let Some(area) = self.timeline_area
+            && col >= area.x && col < area.x + area.width
+            && row >= area.y && row < area.y + area.height
+            && self.timeline_bucket_count > 0
+        {
+            let span = area.width.saturating_sub(1).max(1) as f64;
+            let frac = (col - area.x) as f64 / span;
+            let idx = (frac * (self.timeline_bucket_count - 1) as f64).round() as usize;
+            self.selected_bucket = Some(idx.min(self.timeline_bucket_count - 1));
+            return;
+        }
+
         if let Some(area) = self.filter_area
             && col >= area.x && col < area.x + area.width
             && row >= area.y && row < area.y + area.height
@@ -229,30 +469,45 @@ impl Tab for GraphTab {
     }
 
     fn render(&mut self, f: &mut Frame, area: Rect) {
-        // Top: severity distribution bars.  Bottom: timeline + filter.
+        // Top: live filter prompt.  Middle: severity distribution bars.
+        // Bottom: timeline + severity toggle.
         let bar_count = self.data.bars.len() as u16;
         let severity_height = bar_count * 2 + 3;
 
         let rows = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
+                Constraint::Length(3),
                 Constraint::Length(severity_height),
                 Constraint::Min(10),
             ])
             .split(area);
 
-        self.render_severity_bars(f, rows[0]);
+        self.render_filter_input(f, rows[0]);
+        self.render_severity_bars(f, rows[1]);
 
-        // Bottom row: timeline chart (left) + severity filter (right).
+        // Bottom row: timeline chart (left) + severity toggle (right).
         let cols = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
                 Constraint::Min(30),
                 Constraint::Length(22),
             ])
-            .split(rows[1]);
+            .split(rows[2]);
 
-        self.render_timeline(f, cols[0]);
+        let timeline_rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(if self.selected_bucket.is_some() {
+                vec![Constraint::Min(10), Constraint::Length(8)]
+            } else {
+                vec![Constraint::Min(10)]
+            })
+            .split(cols[0]);
+
+        self.render_timeline(f, timeline_rows[0]);
+        if self.selected_bucket.is_some() {
+            self.render_bucket_drilldown(f, timeline_rows[1]);
+        }
         self.render_filter(f, cols[1]);
     }
 }
@@ -262,6 +517,32 @@ impl Tab for GraphTab {
 // ---------------------------------------------------------------------------
 
 impl GraphTab {
+    /// Render the live filter prompt (bound to `/` or `f`).
+    fn render_filter_input(&mut self, f: &mut Frame, area: Rect) {
+        self.filter_input_area = Some(area);
+
+        let border_style = if self.filter_focused {
+            Style::default().fg(Color::Blue)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let title = if self.filter_focused {
+            " Filter findings (typing...) "
+        } else {
+            " Filter findings (/ or f, Esc to clear) "
+        };
+        let text = if self.filter_focused {
+            format!("{}▌", self.filter_input)
+        } else {
+            self.filter_input.clone()
+        };
+
+        let widget = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).border_style(border_style).title(title))
+            .style(Style::default().fg(Color::Blue));
+        f.render_widget(widget, area);
+    }
+
     fn render_severity_bars(&self, f: &mut Frame, area: Rect) {
         let block = Block::default()
             .borders(Borders::ALL)
@@ -270,17 +551,23 @@ impl GraphTab {
         let inner = block.inner(area);
         f.render_widget(block, area);
 
-        if self.data.bars.is_empty() {
+        let bars = if self.filter_input.is_empty() {
+            self.data.bars.clone()
+        } else {
+            Self::severity_bars(&self.visible_findings())
+        };
+
+        if bars.is_empty() {
             let msg = Paragraph::new("No data to display")
                 .alignment(Alignment::Center);
             f.render_widget(msg, inner);
             return;
         }
 
-        let max_value = self.data.bars.iter().map(|b| b.value).max().unwrap_or(1);
-        let label_width = self.data.bars.iter().map(|b| b.label.len()).max().unwrap_or(0) as u16 + 2;
+        let max_value = bars.iter().map(|b| b.value).max().unwrap_or(1);
+        let label_width = bars.iter().map(|b| b.label.len()).max().unwrap_or(0) as u16 + 2;
 
-        let bar_constraints: Vec<Constraint> = self.data.bars
+        let bar_constraints: Vec<Constraint> = bars
             .iter()
             .map(|_| Constraint::Length(2))
             .collect();
@@ -291,7 +578,7 @@ impl GraphTab {
             .constraints(bar_constraints)
             .split(inner);
 
-        for (i, bar) in self.data.bars.iter().enumerate() {
+        for (i, bar) in bars.iter().enumerate() {
             let row_chunks = Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints([
@@ -323,12 +610,11 @@ impl GraphTab {
     // ── Line graph ───────────────────────────────────────────────────
 
     fn render_timeline(&self, f: &mut Frame, area: Rect) {
-        let buckets = build_weekly_timeline(&self.findings);
+        let buckets = build_timeline(&self.visible_findings(), self.bucket);
+        let title = format!(" Findings over time ({}) ", self.bucket.label());
 
         if buckets.is_empty() {
-            let block = Block::default()
-                .borders(Borders::ALL)
-                .title(" Findings over time ");
+            let block = Block::default().borders(Borders::ALL).title(title);
             let inner = block.inner(area);
             f.render_widget(block, area);
             let msg = Paragraph::new("No findings with dates")
@@ -337,16 +623,26 @@ impl GraphTab {
             return;
         }
 
-        let values: Vec<u32> = buckets.iter().map(|b| b.total(&self.toggles)).collect();
-        let max_val = values.iter().copied().max().unwrap_or(1).max(1) as f64;
+        // Severities with an enabled toggle, in the order they're stacked
+        // (Critical at the bottom).
+        let enabled = self.enabled_severities();
 
-        // Build data points for the chart: (x, y) where x = bucket index, y = count.
-        let data_points: Vec<(f64, f64)> = values
+        // Cumulatively sum each enabled severity's counts on top of the
+        // previous one, so each series is the running total through that
+        // severity rather than just its own count.
+        let mut running = vec![0u32; buckets.len()];
+        let series: Vec<Vec<(f64, f64)>> = enabled
             .iter()
-            .enumerate()
-            .map(|(i, &v)| (i as f64, v as f64))
+            .map(|&(idx, _)| {
+                for (i, b) in buckets.iter().enumerate() {
+                    running[i] += b.severity_counts[idx];
+                }
+                running.iter().enumerate().map(|(i, &v)| (i as f64, v as f64)).collect()
+            })
             .collect();
 
+        let y_max = timeline_y_max(&buckets, &enabled);
+
         // Build x-axis labels: show month name at each month boundary.
         let n = buckets.len();
         // We'll build month boundary indices for smart label placement.
@@ -374,8 +670,7 @@ impl GraphTab {
             .collect();
 
         // Build y-axis labels.
-        let y_step = (max_val / 4.0).ceil().max(1.0);
-        let y_max = (y_step * 4.0).max(max_val);
+        let y_step = y_max / 4.0;
         let y_labels: Vec<ratatui::text::Span> = (0..=4)
             .map(|i| {
                 let v = (y_step * i as f64) as u32;
@@ -386,12 +681,34 @@ impl GraphTab {
             })
             .collect();
 
-        let dataset = Dataset::default()
-            .name("Findings")
-            .marker(symbols::Marker::Braille)
-            .graph_type(GraphType::Line)
-            .style(Style::default().fg(Color::Blue))
-            .data(&data_points);
+        let mut datasets: Vec<Dataset> = enabled
+            .iter()
+            .zip(series.iter())
+            .map(|(&(_, sev), points)| {
+                Dataset::default()
+                    .name(sev.as_str())
+                    .marker(symbols::Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(sev.color()))
+                    .data(points)
+            })
+            .collect();
+
+        // A vertical marker line at the selected bucket's x-position, drawn
+        // on top of the severity series.
+        let marker_points: Vec<(f64, f64)> = match self.selected_bucket.filter(|&i| i < buckets.len()) {
+            Some(idx) => vec![(idx as f64, 0.0), (idx as f64, y_max)],
+            None => Vec::new(),
+        };
+        if !marker_points.is_empty() {
+            datasets.push(
+                Dataset::default()
+                    .marker(symbols::Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(Color::White))
+                    .data(&marker_points),
+            );
+        }
 
         let x_axis = Axis::default()
             .style(Style::default().fg(Color::DarkGray))
@@ -403,24 +720,41 @@ impl GraphTab {
             .bounds([0.0, y_max])
             .labels(y_labels);
 
-        let chart = Chart::new(vec![dataset])
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(" Findings over time "),
-            )
+        let chart = Chart::new(datasets)
+            .block(Block::default().borders(Borders::ALL).title(title))
             .x_axis(x_axis)
             .y_axis(y_axis)
-            .legend_position(None);
+            .legend_position(Some(LegendPosition::TopRight));
 
         f.render_widget(chart, area);
     }
 
+    /// Findings that fall in the selected bucket (←/→ or a chart click),
+    /// listed with their hex id, severity, and title.
+    fn render_bucket_drilldown(&self, f: &mut Frame, area: Rect) {
+        let visible = self.visible_findings();
+        let buckets = build_timeline(&visible, self.bucket);
+        let Some(bucket) = self.selected_bucket.and_then(|i| buckets.get(i)) else {
+            return;
+        };
+        let matches = findings_in_bucket(&visible, bucket);
+
+        let title = format!(" {} — {} finding(s) (Esc to clear) ", bucket.label, matches.len());
+        let items: Vec<ListItem> = matches
+            .iter()
+            .map(|f| {
+                ListItem::new(format!("{} [{}] {}", f.hex_id, f.severity.as_str(), f.title))
+                    .style(Style::default().fg(f.severity.color()))
+            })
+            .collect();
+
+        let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+        f.render_widget(list, area);
+    }
+
     // ── Severity filter panel ───────────────────────────────────────────
 
     fn render_filter(&mut self, f: &mut Frame, area: Rect) {
-        self.filter_area = Some(area);
-
         let block = Block::default()
             .borders(Borders::ALL)
             .title(" Filter (↑↓ Space) ");
@@ -1,8 +1,10 @@
 pub mod assets;
+pub mod calendar;
 pub mod graph;
+pub mod placeholder;
 pub mod search;
 
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{layout::Rect, Frame};
 
 /// Common interface for all TUI tabs.
@@ -10,11 +12,20 @@ pub trait Tab {
     /// Human-readable title shown in the tab bar.
     fn title(&self) -> &'static str;
 
+    /// Compute and cache this tab's interactive hitboxes (search boxes,
+    /// buttons, dropdown menus, lists, ...) for `area`. Called once per
+    /// frame, before the frame is drawn and before any click is resolved
+    /// against it, so `handle_click` always sees geometry that matches the
+    /// current state rather than whatever the previous frame happened to
+    /// draw. `render` must treat these as already computed and only read
+    /// them — it must not assign them as a side effect of drawing.
+    fn compute_layout(&mut self, _area: Rect) {}
+
     /// Render this tab into the given area.
     fn render(&mut self, f: &mut Frame, area: Rect);
 
     /// Handle a key press. Return `true` if the key was consumed.
-    fn handle_key(&mut self, _key: KeyCode) -> bool { false }
+    fn handle_key(&mut self, _key: KeyCode, _modifiers: KeyModifiers) -> bool { false }
 
     /// Handle a mouse click at (`col`, `row`). Default is no-op.
     fn handle_click(&mut self, _col: u16, _row: u16) {}
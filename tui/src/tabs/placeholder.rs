@@ -33,6 +33,10 @@ impl PlaceholderTab {
 impl Tab for PlaceholderTab {
     fn title(&self) -> &'static str { "Placeholder" }
 
+    fn compute_layout(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
     fn handle_click(&mut self, col: u16, row: u16) {
         if self.in_area(col, row) {
             self.click_count += 1;
@@ -41,8 +45,6 @@ impl Tab for PlaceholderTab {
     }
 
     fn render(&mut self, f: &mut Frame, area: Rect) {
-        self.area = Some(area);
-
         let click_info = if let Some((x, y)) = self.last_click {
             format!("Last click: ({}, {}) | Total clicks: {}", x, y, self.click_count)
         } else {
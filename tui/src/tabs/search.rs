@@ -1,4 +1,6 @@
-use crossterm::event::KeyCode;
+use std::collections::HashSet;
+
+use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -7,10 +9,54 @@ use ratatui::{
     Frame,
 };
 
-use models::{Finding, Severity};
+use regex::RegexBuilder;
+
+use models::{Finding, Severity, Status};
+use storage::{cosine_similarity, Embedder, HashingEmbedder};
+
+use crate::fuzzy::{fuzzy_match_with_case, whole_word_match};
+use crate::highlight::{HighlightCache, Language};
+use crate::keybindings::{Action, KeyBindings};
+use crate::theme::Theme;
 
 use super::Tab;
 
+/// Minimum cosine similarity for a finding to be considered a semantic match.
+const SEMANTIC_THRESHOLD: f32 = 0.15;
+
+// ---------------------------------------------------------------------------
+// Search modes
+// ---------------------------------------------------------------------------
+
+/// Whether the search box's query is matched as a fuzzy subsequence or
+/// compiled as a regular expression.
+#[derive(Clone, Copy, Default, PartialEq)]
+enum SearchMode {
+    #[default]
+    Plain,
+    Regex,
+}
+
+/// Independent search-box toggles, analogous to an editor's find feature.
+#[derive(Clone, Copy, Default)]
+struct SearchModes {
+    mode: SearchMode,
+    case_sensitive: bool,
+    whole_word: bool,
+}
+
+impl SearchModes {
+    fn indicator_spans(&self) -> Vec<Span<'static>> {
+        let on = Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD);
+        let off = Style::default().fg(Color::DarkGray);
+        vec![
+            Span::styled(" C ", if self.case_sensitive { on } else { off }),
+            Span::styled(" W ", if self.whole_word { on } else { off }),
+            Span::styled(" .* ", if self.mode == SearchMode::Regex { on } else { off }),
+        ]
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Severity filter (wraps the domain `Severity` with an extra "All" option)
 // ---------------------------------------------------------------------------
@@ -45,10 +91,11 @@ impl SeverityFilter {
         }
     }
 
-    pub fn color(&self) -> Color {
+    /// Themed color for this filter option (falls back to white for "All").
+    pub fn color(&self, theme: &Theme) -> Color {
         match self {
             SeverityFilter::All => Color::White,
-            SeverityFilter::Only(s) => s.color(),
+            SeverityFilter::Only(s) => theme.severity_style(*s).fg.unwrap_or(Color::White),
         }
     }
 }
@@ -86,23 +133,157 @@ impl AssetFilter {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Status filter (wraps the domain `Status` with an extra "All" option)
+// ---------------------------------------------------------------------------
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum StatusFilter {
+    All,
+    Only(Status),
+}
+
+impl StatusFilter {
+    pub const OPTIONS: &[StatusFilter] = &[
+        StatusFilter::All,
+        StatusFilter::Only(Status::Open),
+        StatusFilter::Only(Status::InProgress),
+        StatusFilter::Only(Status::Resolved),
+        StatusFilter::Only(Status::FalsePositive),
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatusFilter::All => "All",
+            StatusFilter::Only(s) => s.as_str(),
+        }
+    }
+
+    pub fn matches(&self, status: Status) -> bool {
+        match self {
+            StatusFilter::All => true,
+            StatusFilter::Only(s) => *s == status,
+        }
+    }
+
+    /// Themed color for this filter option (falls back to white for "All").
+    pub fn color(&self, theme: &Theme) -> Color {
+        match self {
+            StatusFilter::All => Color::White,
+            StatusFilter::Only(s) => theme.status_style(*s).fg.unwrap_or(Color::White),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Sorting
+// ---------------------------------------------------------------------------
+
+/// Field the findings list is ordered by, cycled with `o`. `None` (the
+/// default) leaves `filtered_items` in whatever order filtering produced
+/// (fuzzy-match rank when searching, insertion order otherwise).
+#[derive(Clone, Copy, PartialEq)]
+enum SortField {
+    Severity,
+    Date,
+    Asset,
+    Status,
+    Title,
+}
+
+impl SortField {
+    const ALL: &[SortField] = &[
+        SortField::Severity,
+        SortField::Date,
+        SortField::Asset,
+        SortField::Status,
+        SortField::Title,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            SortField::Severity => "Severity",
+            SortField::Date => "Date",
+            SortField::Asset => "Asset",
+            SortField::Status => "Status",
+            SortField::Title => "Title",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    fn toggled(self) -> Self {
+        match self {
+            SortOrder::Asc => SortOrder::Desc,
+            SortOrder::Desc => SortOrder::Asc,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "▲",
+            SortOrder::Desc => "▼",
+        }
+    }
+}
 
 pub struct SearchTab {
     search_input: String,
     search_focused: bool,
     severity_filter: SeverityFilter,
     asset_filter: AssetFilter,
+    status_filter: StatusFilter,
     asset_options: Vec<AssetFilter>,
     active_dropdown: ActiveDropdown,
     dropdown_selected: usize,
+    semantic_mode: bool,
+    embedder: HashingEmbedder,
     items: Vec<Finding>,
     filtered_items: Vec<Finding>,
+    /// Matched byte indices into `filtered_items[i].title`, parallel to
+    /// `filtered_items`, used to highlight why a fuzzy search matched this
+    /// row. Empty when the best-scoring field wasn't the title, or when not
+    /// in literal search mode.
+    title_matches: Vec<Vec<usize>>,
+    search_modes: SearchModes,
+    /// Set when `search_modes.mode` is `Regex` and `search_input` fails to
+    /// compile; the search box border turns red instead of filtering.
+    regex_error: bool,
     list_state: ListState,
     search_area: Option<Rect>,
     severity_button_area: Option<Rect>,
     asset_button_area: Option<Rect>,
+    status_button_area: Option<Rect>,
     dropdown_menu_area: Option<Rect>,
     list_area: Option<Rect>,
+    pog: storage::PogDir,
+    highlight_cache: HighlightCache,
+    /// Whether scroll/arrow input drives the detail pane's body instead of
+    /// the findings list (toggled with `d`).
+    detail_focused: bool,
+    detail_scroll: u16,
+    /// Extra severity allow-list set by a `:filter` command line, applied on
+    /// top of `severity_filter`. `None` means no command filter is active.
+    command_severities: Option<Vec<Severity>>,
+    /// Slugs of findings marked for a bulk status change (`Finding::slug` is
+    /// unique, unlike `hex_id` which only disambiguates within one asset).
+    /// Kept independent of `filtered_items`'s row order/contents so a
+    /// selection survives re-filtering.
+    selected: HashSet<String>,
+    /// Per-row severity tint for the scrollbar track, recomputed only when
+    /// `(filtered_items.len(), track_height)` changes.
+    scrollbar_colors: Vec<Color>,
+    scrollbar_cache_key: (usize, usize),
+    sort_field: Option<SortField>,
+    sort_order: SortOrder,
+    theme: Theme,
+    keybindings: KeyBindings,
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -110,15 +291,18 @@ enum ActiveDropdown {
     None,
     Severity,
     Asset,
+    Status,
+    BulkStatus,
 }
 
 impl SearchTab {
-    pub fn new(items: Vec<Finding>) -> Self {
+    pub fn new(items: Vec<Finding>, pog: storage::PogDir) -> Self {
         let filtered_items = items.clone();
         let mut list_state = ListState::default();
         if !filtered_items.is_empty() {
             list_state.select(Some(0));
         }
+        let title_matches = vec![Vec::new(); filtered_items.len()];
 
         // Build unique sorted asset list
         let mut assets: Vec<String> = items.iter().map(|f| f.asset.clone()).collect();
@@ -132,20 +316,96 @@ impl SearchTab {
             search_focused: false,
             severity_filter: SeverityFilter::All,
             asset_filter: AssetFilter::All,
+            status_filter: StatusFilter::All,
             asset_options,
             active_dropdown: ActiveDropdown::None,
             dropdown_selected: 0,
+            semantic_mode: false,
+            embedder: HashingEmbedder::default(),
             items,
             filtered_items,
+            title_matches,
+            search_modes: SearchModes::default(),
+            regex_error: false,
             list_state,
             search_area: None,
             severity_button_area: None,
             asset_button_area: None,
+            status_button_area: None,
             dropdown_menu_area: None,
             list_area: None,
+            pog,
+            highlight_cache: HighlightCache::new(),
+            detail_focused: false,
+            detail_scroll: 0,
+            command_severities: None,
+            selected: HashSet::new(),
+            scrollbar_colors: Vec::new(),
+            scrollbar_cache_key: (0, 0),
+            sort_field: None,
+            sort_order: SortOrder::Asc,
+            theme: Theme::load(Theme::default_config_path().as_deref()),
+            keybindings: KeyBindings::load(KeyBindings::default_config_path().as_deref()),
         }
     }
 
+    /// Cycle `None -> Severity -> Date -> Asset -> Status -> Title -> None`.
+    fn cycle_sort_field(&mut self) {
+        self.sort_field = match self.sort_field {
+            None => Some(SortField::ALL[0]),
+            Some(field) => {
+                let pos = SortField::ALL.iter().position(|f| *f == field).unwrap_or(0);
+                if pos + 1 == SortField::ALL.len() { None } else { Some(SortField::ALL[pos + 1]) }
+            }
+        };
+        self.filter_items();
+    }
+
+    fn toggle_sort_order(&mut self) {
+        self.sort_order = self.sort_order.toggled();
+        self.filter_items();
+    }
+
+    /// Order `filtered_items` (and the parallel `title_matches` highlight
+    /// indices) by the active `sort_field`/`sort_order`, on top of whatever
+    /// filtering and fuzzy ranking already produced. A no-op when
+    /// `sort_field` is `None`.
+    fn sort_items(&mut self) {
+        let Some(field) = self.sort_field else { return };
+
+        let mut order: Vec<usize> = (0..self.filtered_items.len()).collect();
+        order.sort_by(|&i, &j| {
+            let (a, b) = (&self.filtered_items[i], &self.filtered_items[j]);
+
+            // Empty dates always sort last, regardless of `sort_order`, so
+            // toggling direction never buries dated findings under the ones
+            // missing a date.
+            if field == SortField::Date && (a.date.is_empty() || b.date.is_empty()) {
+                return match (a.date.is_empty(), b.date.is_empty()) {
+                    (true, true) => std::cmp::Ordering::Equal,
+                    (true, false) => std::cmp::Ordering::Greater,
+                    (false, true) => std::cmp::Ordering::Less,
+                    (false, false) => unreachable!(),
+                };
+            }
+
+            let ordering = match field {
+                SortField::Severity => a.severity.cmp(&b.severity),
+                SortField::Date => a.date.cmp(&b.date),
+                SortField::Asset => a.asset.cmp(&b.asset),
+                SortField::Status => a.status.cmp(&b.status),
+                SortField::Title => a.title.cmp(&b.title),
+            };
+            match self.sort_order {
+                SortOrder::Asc => ordering,
+                SortOrder::Desc => ordering.reverse(),
+            }
+        });
+
+        self.filtered_items = order.iter().map(|&i| self.filtered_items[i].clone()).collect();
+        self.title_matches = order.iter().map(|&i| self.title_matches[i].clone()).collect();
+    }
+
     fn toggle_severity_dropdown(&mut self) {
         if self.active_dropdown == ActiveDropdown::Severity {
             self.active_dropdown = ActiveDropdown::None;
@@ -172,10 +432,81 @@ impl SearchTab {
         }
     }
 
+    fn toggle_status_dropdown(&mut self) {
+        if self.active_dropdown == ActiveDropdown::Status {
+            self.active_dropdown = ActiveDropdown::None;
+        } else {
+            self.active_dropdown = ActiveDropdown::Status;
+            self.search_focused = false;
+            self.dropdown_selected = StatusFilter::OPTIONS
+                .iter()
+                .position(|f| *f == self.status_filter)
+                .unwrap_or(0);
+        }
+    }
+
+    /// Open the bulk-status dropdown. There's no single "current" status to
+    /// preselect (it applies to a whole marked set), so the cursor always
+    /// starts on the first option.
+    fn toggle_bulk_status_dropdown(&mut self) {
+        if self.active_dropdown == ActiveDropdown::BulkStatus {
+            self.active_dropdown = ActiveDropdown::None;
+        } else {
+            self.active_dropdown = ActiveDropdown::BulkStatus;
+            self.search_focused = false;
+            self.dropdown_selected = 0;
+        }
+    }
+
+    /// Toggle the currently highlighted row's mark.
+    fn toggle_selection(&mut self) {
+        if let Some(finding) = self.get_selected() {
+            let slug = finding.slug.clone();
+            if !self.selected.remove(&slug) {
+                self.selected.insert(slug);
+            }
+        }
+    }
+
+    /// Flip the mark on every row currently visible under the active filters.
+    fn invert_selection(&mut self) {
+        for item in &self.filtered_items {
+            if !self.selected.remove(&item.slug) {
+                self.selected.insert(item.slug.clone());
+            }
+        }
+    }
+
+    fn clear_selection(&mut self) {
+        self.selected.clear();
+    }
+
+    /// Apply `status` to every marked finding, or just the highlighted row
+    /// when nothing is marked, updating both `items` and `filtered_items` so
+    /// the change survives the next re-filter. Clears the selection
+    /// afterwards.
+    fn apply_bulk_status(&mut self, status: Status) {
+        let targets: HashSet<String> = if self.selected.is_empty() {
+            self.get_selected().map(|f| f.slug.clone()).into_iter().collect()
+        } else {
+            self.selected.clone()
+        };
+
+        for item in self.items.iter_mut().chain(self.filtered_items.iter_mut()) {
+            if targets.contains(&item.slug) {
+                item.status = status;
+            }
+        }
+
+        self.selected.clear();
+    }
+
     fn dropdown_option_count(&self) -> usize {
         match self.active_dropdown {
             ActiveDropdown::Severity => SeverityFilter::OPTIONS.len(),
             ActiveDropdown::Asset => self.asset_options.len(),
+            ActiveDropdown::Status => StatusFilter::OPTIONS.len(),
+            ActiveDropdown::BulkStatus => Status::ALL.len(),
             ActiveDropdown::None => 0,
         }
     }
@@ -210,6 +541,16 @@ impl SearchTab {
                     self.asset_filter = filter.clone();
                 }
             }
+            ActiveDropdown::Status => {
+                if let Some(&filter) = StatusFilter::OPTIONS.get(self.dropdown_selected) {
+                    self.status_filter = filter;
+                }
+            }
+            ActiveDropdown::BulkStatus => {
+                if let Some(&status) = Status::ALL.get(self.dropdown_selected) {
+                    self.apply_bulk_status(status);
+                }
+            }
             ActiveDropdown::None => {}
         }
         self.active_dropdown = ActiveDropdown::None;
@@ -217,26 +558,209 @@ impl SearchTab {
     }
 
     fn filter_items(&mut self) {
-        let search_lower = self.search_input.to_lowercase();
-        self.filtered_items = self.items
-            .iter()
-            .filter(|item| {
-                let matches_search = search_lower.is_empty()
-                    || item.title.to_lowercase().contains(&search_lower)
-                    || item.description.to_lowercase().contains(&search_lower)
-                    || item.location.to_lowercase().contains(&search_lower);
-                let matches_severity = self.severity_filter.matches(item.severity);
-                let matches_asset = self.asset_filter.matches(&item.asset);
-                matches_search && matches_severity && matches_asset
-            })
-            .cloned()
-            .collect();
+        if self.semantic_mode && !self.search_input.is_empty() {
+            self.filtered_items = self.semantic_matches();
+            self.title_matches = vec![Vec::new(); self.filtered_items.len()];
+        } else {
+            self.literal_matches();
+        }
+
+        self.sort_items();
 
         if self.filtered_items.is_empty() {
             self.list_state.select(None);
         } else {
             self.list_state.select(Some(0));
         }
+        self.detail_scroll = 0;
+    }
+
+    /// Fuzzy-rank findings against the current query, scoped to the active
+    /// severity/asset filters. Each finding's title, location and
+    /// description are tried as match candidates (an fzf-style subsequence
+    /// match scored by [`fuzzy_match_with_case`] — consecutive-run bonus,
+    /// word-boundary bonus, per-gap penalty); the best-scoring field wins
+    /// and, when it's the title, its matched byte indices are kept so
+    /// `render_list` can highlight them. Non-matches are dropped, and the
+    /// rest are sorted by descending score, ties broken by descending
+    /// severity. An empty query bypasses scoring and keeps the original
+    /// (insertion) order.
+    ///
+    /// - Regex mode compiles `query` with the `regex` crate; a compile error
+    ///   sets `regex_error` (search box renders red) and every finding passes
+    ///   unscored rather than being silently filtered to zero results.
+    /// - Whole-word mode requires a separator- or boundary-delimited match.
+    /// - Case-sensitive mode disables lowercasing in both of the above.
+    fn literal_matches(&mut self) {
+        let query = self.search_input.trim();
+        self.regex_error = false;
+
+        let regex = if self.search_modes.mode == SearchMode::Regex && !query.is_empty() {
+            let pattern = if self.search_modes.whole_word { format!(r"\b(?:{query})\b") } else { query.to_string() };
+            match RegexBuilder::new(&pattern).case_insensitive(!self.search_modes.case_sensitive).build() {
+                Ok(re) => Some(re),
+                Err(_) => {
+                    self.regex_error = true;
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        let skip_query_filter = self.search_modes.mode == SearchMode::Regex && self.regex_error;
+
+        let mut matched: Vec<(Finding, i32, Vec<usize>)> = self.items
+            .iter()
+            .filter(|item| {
+                self.severity_filter.matches(item.severity)
+                    && self.matches_command_severities(item.severity)
+                    && self.asset_filter.matches(&item.asset)
+                    && self.status_filter.matches(item.status)
+            })
+            .filter_map(|item| {
+                if query.is_empty() || skip_query_filter {
+                    return Some((item.clone(), 0, Vec::new()));
+                }
+
+                let fields: [&str; 3] = [&item.title, &item.location, &item.description];
+
+                if let Some(re) = &regex {
+                    return fields.iter().enumerate().find_map(|(i, field)| {
+                        let m = re.find(field)?;
+                        let indices = if i == 0 { (m.start()..m.end()).collect() } else { Vec::new() };
+                        Some((item.clone(), 1, indices))
+                    });
+                }
+
+                if self.search_modes.whole_word {
+                    return fields.iter().enumerate().find_map(|(i, field)| {
+                        let range = whole_word_match(query, field, self.search_modes.case_sensitive)?;
+                        let indices = if i == 0 { range.collect() } else { Vec::new() };
+                        Some((item.clone(), 1, indices))
+                    });
+                }
+
+                let mut best: Option<(i32, Vec<usize>)> = None;
+                for (i, field) in fields.iter().enumerate() {
+                    let Some(m) = fuzzy_match_with_case(query, field, self.search_modes.case_sensitive) else { continue };
+                    if best.as_ref().is_none_or(|(score, _)| m.score > *score) {
+                        let indices = if i == 0 { m.indices } else { Vec::new() };
+                        best = Some((m.score, indices));
+                    }
+                }
+
+                best.map(|(score, indices)| (item.clone(), score, indices))
+            })
+            .collect();
+
+        if !query.is_empty() && !skip_query_filter {
+            matched.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| b.0.severity.cmp(&a.0.severity)));
+        }
+
+        self.title_matches = matched.iter().map(|(_, _, indices)| indices.clone()).collect();
+        self.filtered_items = matched.into_iter().map(|(item, _, _)| item).collect();
+    }
+
+    /// Rank items by cosine similarity between the query's embedding and an
+    /// embedding of each finding's title + description, so a phrase like
+    /// "weak TLS configuration" surfaces related findings even when no
+    /// keyword overlaps.
+    fn semantic_matches(&self) -> Vec<Finding> {
+        let query_vec = self.embedder.embed(&self.search_input);
+
+        let mut scored: Vec<(f32, &Finding)> = self.items
+            .iter()
+            .filter(|item| {
+                self.severity_filter.matches(item.severity)
+                    && self.matches_command_severities(item.severity)
+                    && self.asset_filter.matches(&item.asset)
+                    && self.status_filter.matches(item.status)
+            })
+            .map(|item| {
+                let item_vec = self.embedder.embed(&format!("{} {}", item.title, item.description));
+                (cosine_similarity(&query_vec, &item_vec), item)
+            })
+            .filter(|(score, _)| *score >= SEMANTIC_THRESHOLD)
+            .collect();
+
+        scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        scored.into_iter().map(|(_, item)| item.clone()).collect()
+    }
+
+    fn toggle_semantic_mode(&mut self) {
+        self.semantic_mode = !self.semantic_mode;
+        self.filter_items();
+    }
+
+    fn matches_command_severities(&self, severity: Severity) -> bool {
+        match &self.command_severities {
+            Some(allowed) => allowed.contains(&severity),
+            None => true,
+        }
+    }
+
+    /// Apply a `:filter <sev,sev,...>` command, OR-ing across every listed
+    /// severity on top of the dropdown's own `severity_filter`.
+    pub fn set_command_severities(&mut self, severities: Vec<Severity>) {
+        self.command_severities = if severities.is_empty() { None } else { Some(severities) };
+        self.filter_items();
+    }
+
+    /// Set the asset filter to a single asset by name (used by `:goto asset
+    /// <name>`). Returns `false` if no finding belongs to that asset.
+    pub fn set_asset_filter_by_name(&mut self, name: &str) -> bool {
+        if !self.asset_options.iter().any(|f| matches!(f, AssetFilter::Only(a) if a == name)) {
+            return false;
+        }
+        self.asset_filter = AssetFilter::Only(name.to_string());
+        self.filter_items();
+        true
+    }
+
+    /// The asset of the currently-selected finding, if any (used by the
+    /// "open asset" action to resolve a `dns_or_ip` to launch).
+    pub fn selected_asset_name(&self) -> Option<&str> {
+        self.get_selected().map(|f| f.asset.as_str())
+    }
+
+    /// The unfiltered findings, including any local edits (e.g. bulk status
+    /// changes) not yet re-queried from the database. Used to save a JSON
+    /// report reflecting what's currently on screen.
+    pub fn items(&self) -> &[Finding] {
+        &self.items
+    }
+
+    /// Reset every filter (dropdown and command-line) back to "show all".
+    pub fn clear_filters(&mut self) {
+        self.severity_filter = SeverityFilter::All;
+        self.asset_filter = AssetFilter::All;
+        self.status_filter = StatusFilter::All;
+        self.command_severities = None;
+        self.filter_items();
+    }
+
+    /// Replace the backing findings (e.g. after a watcher-triggered reload),
+    /// rebuilding the asset filter options and re-applying the current
+    /// search/filter state. Restores the previous selection by hex ID when
+    /// that finding is still present.
+    pub fn set_items(&mut self, items: Vec<Finding>) {
+        let prev_selected_hex_id = self.get_selected().map(|f| f.hex_id.clone());
+
+        let mut assets: Vec<String> = items.iter().map(|f| f.asset.clone()).collect();
+        assets.sort();
+        assets.dedup();
+        let mut asset_options: Vec<AssetFilter> = vec![AssetFilter::All];
+        asset_options.extend(assets.into_iter().map(AssetFilter::Only));
+        self.asset_options = asset_options;
+
+        self.items = items;
+        self.filter_items();
+
+        if let Some(hex_id) = prev_selected_hex_id {
+            if let Some(pos) = self.filtered_items.iter().position(|f| f.hex_id == hex_id) {
+                self.list_state.select(Some(pos));
+            }
+        }
     }
 
     fn list_next(&mut self) {
@@ -246,6 +770,7 @@ impl SearchTab {
             None => 0,
         };
         self.list_state.select(Some(i));
+        self.detail_scroll = 0;
     }
 
     fn list_previous(&mut self) {
@@ -255,6 +780,19 @@ impl SearchTab {
             None => 0,
         };
         self.list_state.select(Some(i));
+        self.detail_scroll = 0;
+    }
+
+    fn detail_scroll_down(&mut self) {
+        self.detail_scroll = self.detail_scroll.saturating_add(1);
+    }
+
+    fn detail_scroll_up(&mut self) {
+        self.detail_scroll = self.detail_scroll.saturating_sub(1);
+    }
+
+    fn toggle_detail_focus(&mut self) {
+        self.detail_focused = !self.detail_focused;
     }
 
     fn get_selected(&self) -> Option<&Finding> {
@@ -264,6 +802,36 @@ impl SearchTab {
     fn in_area(col: u16, row: u16, area: Rect) -> bool {
         col >= area.x && col < area.x + area.width && row >= area.y && row < area.y + area.height
     }
+
+    /// Split `text` into spans, styling the characters at `indices` (byte
+    /// offsets) to show why a fuzzy search matched this row.
+    fn highlight_spans(text: &str, indices: &[usize]) -> Vec<Span<'static>> {
+        if indices.is_empty() {
+            return vec![Span::raw(text.to_string())];
+        }
+
+        let match_style = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+        let mut spans = Vec::new();
+        let mut current = String::new();
+        let mut current_matched = false;
+
+        for (byte_idx, ch) in text.char_indices() {
+            let is_match = indices.contains(&byte_idx);
+            if is_match != current_matched && !current.is_empty() {
+                spans.push(if current_matched {
+                    Span::styled(std::mem::take(&mut current), match_style)
+                } else {
+                    Span::raw(std::mem::take(&mut current))
+                });
+            }
+            current.push(ch);
+            current_matched = is_match;
+        }
+        if !current.is_empty() {
+            spans.push(if current_matched { Span::styled(current, match_style) } else { Span::raw(current) });
+        }
+        spans
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -273,12 +841,66 @@ impl SearchTab {
 impl Tab for SearchTab {
     fn title(&self) -> &'static str { "Search" }
 
+    fn compute_layout(&mut self, area: Rect) {
+        let main_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+            ])
+            .split(main_chunks[0]);
+
+        self.search_area = Some(rows[0]);
+
+        let filter_cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Ratio(1, 3); 3])
+            .split(rows[1]);
+        self.severity_button_area = Some(filter_cols[0]);
+        self.asset_button_area = Some(filter_cols[1]);
+        self.status_button_area = Some(filter_cols[2]);
+
+        self.list_area = Some(rows[2]);
+
+        self.dropdown_menu_area = match self.active_dropdown {
+            ActiveDropdown::None => None,
+            ActiveDropdown::Severity | ActiveDropdown::Asset | ActiveDropdown::Status => {
+                let button_area = match self.active_dropdown {
+                    ActiveDropdown::Severity => self.severity_button_area,
+                    ActiveDropdown::Asset => self.asset_button_area,
+                    ActiveDropdown::Status => self.status_button_area,
+                    ActiveDropdown::BulkStatus | ActiveDropdown::None => None,
+                };
+                button_area.map(|button_area| Rect {
+                    x: button_area.x,
+                    y: button_area.y + button_area.height,
+                    width: button_area.width,
+                    height: self.dropdown_option_count() as u16 + 2,
+                })
+            }
+            // No dedicated button for the bulk-status menu (opened with `b`,
+            // not a click target), so float it under the search box instead.
+            ActiveDropdown::BulkStatus => self.search_area.map(|search_area| Rect {
+                x: search_area.x,
+                y: search_area.y + search_area.height,
+                width: search_area.width,
+                height: self.dropdown_option_count() as u16 + 2,
+            }),
+        };
+    }
+
     fn on_blur(&mut self) {
         self.search_focused = false;
         self.active_dropdown = ActiveDropdown::None;
     }
 
-    fn handle_key(&mut self, key: KeyCode) -> bool {
+    fn handle_key(&mut self, key: KeyCode, modifiers: KeyModifiers) -> bool {
         // --- dropdown is open: route keys to dropdown ---
         if self.active_dropdown != ActiveDropdown::None {
             return match key {
@@ -292,6 +914,21 @@ impl Tab for SearchTab {
 
         // --- search box focused: route keys to text input ---
         if self.search_focused {
+            if modifiers.contains(KeyModifiers::ALT) {
+                return match key {
+                    KeyCode::Char('c') => { self.search_modes.case_sensitive = !self.search_modes.case_sensitive; self.filter_items(); true }
+                    KeyCode::Char('w') => { self.search_modes.whole_word = !self.search_modes.whole_word; self.filter_items(); true }
+                    KeyCode::Char('r') => {
+                        self.search_modes.mode = match self.search_modes.mode {
+                            SearchMode::Plain => SearchMode::Regex,
+                            SearchMode::Regex => SearchMode::Plain,
+                        };
+                        self.filter_items();
+                        true
+                    }
+                    _ => false,
+                };
+            }
             return match key {
                 KeyCode::Esc | KeyCode::Enter => { self.search_focused = false; true }
                 KeyCode::Char(c) => { self.search_input.push(c); self.filter_items(); true }
@@ -302,11 +939,41 @@ impl Tab for SearchTab {
             };
         }
 
+        // --- detail pane focused: arrows scroll the body instead of the list ---
+        if self.detail_focused {
+            return match key {
+                KeyCode::Esc => { self.detail_focused = false; true }
+                KeyCode::Down => { self.detail_scroll_down(); true }
+                KeyCode::Up => { self.detail_scroll_up(); true }
+                KeyCode::Char(c) if self.keybindings.action_for(c) == Some(Action::ToggleDetailFocus) => {
+                    self.toggle_detail_focus();
+                    true
+                }
+                _ => false,
+            };
+        }
+
         // --- normal mode: tab-level shortcuts ---
+        if let KeyCode::Char(c) = key
+            && let Some(action) = self.keybindings.action_for(c)
+        {
+            return match action {
+                Action::FocusSearch => { self.search_focused = true; self.active_dropdown = ActiveDropdown::None; true }
+                Action::ToggleSeverityFilter => { self.toggle_severity_dropdown(); true }
+                Action::ToggleAssetFilter => { self.toggle_asset_dropdown(); true }
+                Action::ToggleStatusFilter => { self.toggle_status_dropdown(); true }
+                Action::ToggleSemanticMode => { self.toggle_semantic_mode(); true }
+                Action::ToggleDetailFocus => { self.toggle_detail_focus(); true }
+                Action::ToggleMark => { self.toggle_selection(); true }
+                Action::InvertSelection => { self.invert_selection(); true }
+                Action::ToggleBulkStatus => { self.toggle_bulk_status_dropdown(); true }
+                Action::CycleSortField => { self.cycle_sort_field(); true }
+                Action::ToggleSortOrder => { self.toggle_sort_order(); true }
+            };
+        }
+
         match key {
-            KeyCode::Char('s') => { self.search_focused = true; self.active_dropdown = ActiveDropdown::None; true }
-            KeyCode::Char('f') => { self.toggle_severity_dropdown(); true }
-            KeyCode::Char('a') => { self.toggle_asset_dropdown(); true }
+            KeyCode::Esc if !self.selected.is_empty() => { self.clear_selection(); true }
             KeyCode::Down => { self.list_next(); true }
             KeyCode::Up => { self.list_previous(); true }
             _ => false,
@@ -345,6 +1012,13 @@ impl Tab for SearchTab {
             return;
         }
 
+        if let Some(area) = self.status_button_area
+            && Self::in_area(col, row, area)
+        {
+            self.toggle_status_dropdown();
+            return;
+        }
+
         if let Some(area) = self.search_area
             && Self::in_area(col, row, area)
         {
@@ -361,6 +1035,7 @@ impl Tab for SearchTab {
                 let clicked_index = (row - list_start_y) as usize;
                 if clicked_index < self.filtered_items.len() {
                     self.list_state.select(Some(clicked_index));
+                    self.detail_scroll = 0;
                 }
             }
             return;
@@ -370,11 +1045,19 @@ impl Tab for SearchTab {
     }
 
     fn handle_scroll_down(&mut self) {
-        self.list_next();
+        if self.detail_focused {
+            self.detail_scroll_down();
+        } else {
+            self.list_next();
+        }
     }
 
     fn handle_scroll_up(&mut self) {
-        self.list_previous();
+        if self.detail_focused {
+            self.detail_scroll_up();
+        } else {
+            self.list_previous();
+        }
     }
 
     fn render(&mut self, f: &mut Frame, area: Rect) {
@@ -409,27 +1092,41 @@ impl SearchTab {
 
         self.render_search_box(f, rows[0]);
 
-        // Severity and asset filters side by side
+        // Severity, asset, and status filters side by side
         let filter_cols = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .constraints([Constraint::Ratio(1, 3); 3])
             .split(rows[1]);
 
         self.render_severity_button(f, filter_cols[0]);
         self.render_asset_button(f, filter_cols[1]);
+        self.render_status_button(f, filter_cols[2]);
         self.render_list(f, rows[2]);
     }
 
     fn render_search_box(&mut self, f: &mut Frame, area: Rect) {
-        self.search_area = Some(area);
-
-        let border_style = if self.search_focused {
-            Style::default().fg(Color::Blue)
+        let border_style = if self.regex_error {
+            Style::default().fg(Color::Red)
+        } else if self.search_focused {
+            self.theme.style(self.theme.search_border)
         } else {
             Style::default().fg(Color::White)
         };
 
-        let title = if self.search_focused { " Search (typing...) " } else { " Search (s or click) " };
+        let mode = if self.semantic_mode { "semantic" } else { "literal" };
+        let base_title = if self.regex_error {
+            format!(" Search [{mode}] — invalid regex ")
+        } else if self.search_focused {
+            format!(" Search [{mode}] (typing... Alt+c/w/r to toggle modes) ")
+        } else {
+            format!(
+                " Search [{mode}] ({} or click, {} to toggle mode) ",
+                self.keybindings.focus_search, self.keybindings.toggle_semantic_mode,
+            )
+        };
+        let mut title_spans = vec![Span::raw(base_title)];
+        title_spans.extend(self.search_modes.indicator_spans());
+
         let input_text = if self.search_focused {
             format!("{}▌", self.search_input)
         } else {
@@ -437,19 +1134,17 @@ impl SearchTab {
         };
 
         let input = Paragraph::new(input_text)
-            .block(Block::default().borders(Borders::ALL).border_style(border_style).title(title))
+            .block(Block::default().borders(Borders::ALL).border_style(border_style).title(Line::from(title_spans)))
             .style(Style::default().fg(Color::Blue));
         f.render_widget(input, area);
     }
 
     fn render_severity_button(&mut self, f: &mut Frame, area: Rect) {
-        self.severity_button_area = Some(area);
-
         let arrow = if self.active_dropdown == ActiveDropdown::Severity { "▲" } else { "▼" };
         let text = format!(" {} {}", self.severity_filter.as_str(), arrow);
 
         let border_style = if self.active_dropdown == ActiveDropdown::Severity {
-            Style::default().fg(Color::Blue)
+            self.theme.style(self.theme.search_border)
         } else {
             Style::default().fg(Color::White)
         };
@@ -459,20 +1154,18 @@ impl SearchTab {
                 Block::default()
                     .borders(Borders::ALL)
                     .border_style(border_style)
-                    .title(" Severity Filter (f or click) ")
+                    .title(format!(" Severity Filter ({} or click) ", self.keybindings.toggle_severity_filter))
             )
-            .style(Style::default().fg(self.severity_filter.color()).add_modifier(Modifier::BOLD));
+            .style(Style::default().fg(self.severity_filter.color(&self.theme)).add_modifier(Modifier::BOLD));
         f.render_widget(button, area);
     }
 
     fn render_asset_button(&mut self, f: &mut Frame, area: Rect) {
-        self.asset_button_area = Some(area);
-
         let arrow = if self.active_dropdown == ActiveDropdown::Asset { "▲" } else { "▼" };
         let text = format!(" {} {}", self.asset_filter.as_str(), arrow);
 
         let border_style = if self.active_dropdown == ActiveDropdown::Asset {
-            Style::default().fg(Color::Blue)
+            self.theme.style(self.theme.search_border)
         } else {
             Style::default().fg(Color::White)
         };
@@ -482,40 +1175,44 @@ impl SearchTab {
                 Block::default()
                     .borders(Borders::ALL)
                     .border_style(border_style)
-                    .title(" Asset Filter (a or click) ")
+                    .title(format!(" Asset Filter ({} or click) ", self.keybindings.toggle_asset_filter))
             )
             .style(Style::default().fg(self.asset_filter.color()).add_modifier(Modifier::BOLD));
         f.render_widget(button, area);
     }
 
-    fn render_dropdown_menu(&mut self, f: &mut Frame) {
-        let button_area = match self.active_dropdown {
-            ActiveDropdown::Severity => self.severity_button_area,
-            ActiveDropdown::Asset => self.asset_button_area,
-            ActiveDropdown::None => None,
-        };
+    fn render_status_button(&mut self, f: &mut Frame, area: Rect) {
+        let arrow = if self.active_dropdown == ActiveDropdown::Status { "▲" } else { "▼" };
+        let text = format!(" {} {}", self.status_filter.as_str(), arrow);
 
-        if let Some(button_area) = button_area {
-            let option_count = self.dropdown_option_count();
-            let menu_height = option_count as u16 + 2;
+        let border_style = if self.active_dropdown == ActiveDropdown::Status {
+            self.theme.style(self.theme.search_border)
+        } else {
+            Style::default().fg(Color::White)
+        };
 
-            let menu_area = Rect {
-                x: button_area.x,
-                y: button_area.y + button_area.height,
-                width: button_area.width,
-                height: menu_height,
-            };
-            self.dropdown_menu_area = Some(menu_area);
+        let button = Paragraph::new(text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+                    .title(format!(" Status Filter ({} or click) ", self.keybindings.toggle_status_filter))
+            )
+            .style(Style::default().fg(self.status_filter.color(&self.theme)).add_modifier(Modifier::BOLD));
+        f.render_widget(button, area);
+    }
 
+    fn render_dropdown_menu(&mut self, f: &mut Frame) {
+        if let Some(menu_area) = self.dropdown_menu_area {
             f.render_widget(Clear, menu_area);
 
             let items: Vec<ListItem> = match self.active_dropdown {
                 ActiveDropdown::Severity => {
                     SeverityFilter::OPTIONS.iter().enumerate().map(|(i, filter)| {
                         let style = if i == self.dropdown_selected {
-                            Style::default().bg(Color::Blue).fg(Color::White).add_modifier(Modifier::BOLD)
+                            self.theme.style(self.theme.dropdown_selected)
                         } else {
-                            Style::default().fg(filter.color())
+                            Style::default().fg(filter.color(&self.theme))
                         };
                         ListItem::new(format!(" {} ", filter.as_str())).style(style)
                     }).collect()
@@ -523,47 +1220,165 @@ impl SearchTab {
                 ActiveDropdown::Asset => {
                     self.asset_options.iter().enumerate().map(|(i, filter)| {
                         let style = if i == self.dropdown_selected {
-                            Style::default().bg(Color::Blue).fg(Color::White).add_modifier(Modifier::BOLD)
+                            self.theme.style(self.theme.dropdown_selected)
                         } else {
                             Style::default().fg(filter.color())
                         };
                         ListItem::new(format!(" {} ", filter.as_str())).style(style)
                     }).collect()
                 }
+                ActiveDropdown::Status => {
+                    StatusFilter::OPTIONS.iter().enumerate().map(|(i, filter)| {
+                        let style = if i == self.dropdown_selected {
+                            self.theme.style(self.theme.dropdown_selected)
+                        } else {
+                            Style::default().fg(filter.color(&self.theme))
+                        };
+                        ListItem::new(format!(" {} ", filter.as_str())).style(style)
+                    }).collect()
+                }
+                ActiveDropdown::BulkStatus => {
+                    Status::ALL.iter().enumerate().map(|(i, status)| {
+                        let style = if i == self.dropdown_selected {
+                            self.theme.style(self.theme.dropdown_selected)
+                        } else {
+                            self.theme.status_style(*status)
+                        };
+                        ListItem::new(format!(" {} ", status.as_str())).style(style)
+                    }).collect()
+                }
                 ActiveDropdown::None => vec![],
             };
 
+            let title = if self.active_dropdown == ActiveDropdown::BulkStatus {
+                let count = if self.selected.is_empty() { 1 } else { self.selected.len() };
+                format!(" Set status for {count} finding(s) ")
+            } else {
+                String::new()
+            };
+
             let list = List::new(items)
-                .block(Block::default().borders(Borders::ALL));
+                .block(Block::default().borders(Borders::ALL).title(title));
 
             f.render_widget(list, menu_area);
         }
     }
 
     fn render_list(&mut self, f: &mut Frame, area: Rect) {
-        self.list_area = Some(area);
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(area);
+        let (list_col, scrollbar_col) = (cols[0], cols[1]);
 
         let items: Vec<ListItem> = self.filtered_items
             .iter()
-            .map(|item| ListItem::new(item.title.as_str()))
+            .zip(self.title_matches.iter())
+            .map(|(item, matched_indices)| {
+                let marked = self.selected.contains(&item.slug);
+                let checkmark = if marked { "✓ " } else { "  " };
+                let mut spans = vec![Span::raw(checkmark)];
+                spans.extend(Self::highlight_spans(&item.title, matched_indices));
+                let line = Line::from(spans);
+                let style = if marked { Style::default().bg(Color::DarkGray) } else { Style::default() };
+                ListItem::new(line).style(style)
+            })
             .collect();
 
-        let title = format!(" Findings ({}) ", self.filtered_items.len());
+        let sort_suffix = match self.sort_field {
+            Some(field) => format!(" — sorted by {} {} (o/O)", field.as_str(), self.sort_order.as_str()),
+            None => String::new(),
+        };
+
+        let title = if self.selected.is_empty() {
+            format!(" Findings ({}){} ", self.filtered_items.len(), sort_suffix)
+        } else {
+            format!(" Findings ({}) — {} marked{} ", self.filtered_items.len(), self.selected.len(), sort_suffix)
+        };
 
         let list = List::new(items)
             .block(Block::default().borders(Borders::ALL).title(title))
-            .highlight_style(Style::default().bg(Color::Blue).fg(Color::White).add_modifier(Modifier::BOLD))
+            .highlight_style(self.theme.style(self.theme.highlight))
             .highlight_symbol("▶ ");
 
-        f.render_stateful_widget(list, area, &mut self.list_state);
+        f.render_stateful_widget(list, list_col, &mut self.list_state);
+
+        self.render_scrollbar(f, scrollbar_col);
+    }
+
+    /// Render a one-column density map of severity across the full filtered
+    /// list, tinted by each row's severity color at its proportional
+    /// position. The per-row colors are cached and only rebuilt when
+    /// `filtered_items.len()` or the track height changes.
+    fn render_scrollbar(&mut self, f: &mut Frame, area: Rect) {
+        let track_height = area.height.saturating_sub(2) as usize;
+        let cache_key = (self.filtered_items.len(), track_height);
+        if self.scrollbar_cache_key != cache_key {
+            self.rebuild_scrollbar_cache(track_height);
+            self.scrollbar_cache_key = cache_key;
+        }
+
+        for (row, color) in self.scrollbar_colors.iter().enumerate() {
+            let y = area.y + 1 + row as u16;
+            if y >= area.y + area.height.saturating_sub(1) {
+                break;
+            }
+            let cell = Rect { x: area.x, y, width: area.width, height: 1 };
+            f.render_widget(Paragraph::new("┃").style(Style::default().fg(*color)), cell);
+        }
+    }
+
+    fn rebuild_scrollbar_cache(&mut self, track_height: usize) {
+        self.scrollbar_colors = if self.filtered_items.is_empty() || track_height == 0 {
+            Vec::new()
+        } else {
+            (0..track_height)
+                .map(|row| {
+                    let idx = (row * self.filtered_items.len() / track_height)
+                        .min(self.filtered_items.len() - 1);
+                    self.theme.severity_style(self.filtered_items[idx].severity).fg.unwrap_or(Color::Gray)
+                })
+                .collect()
+        };
+    }
+
+    /// Load the finding's primary content file from disk and return its
+    /// highlighted body, using [`HighlightCache`] so repeated frames while
+    /// scrolling don't re-tokenize the same text. Falls back to
+    /// [`Finding::description_lines`] when no content file is found, e.g.
+    /// for findings added without an import.
+    fn highlighted_body(&mut self, finding: &Finding) -> Vec<Line<'static>> {
+        let dir = self.pog.finding_dir(&finding.asset, &finding.hex_id, &finding.slug);
+        let Some(path) = storage::find_primary_file(&dir) else {
+            return finding.description_lines();
+        };
+
+        let Ok(text) = std::fs::read_to_string(&path) else {
+            return finding.description_lines();
+        };
+
+        let cache_key = format!("{}/{}", finding.asset, finding.hex_id);
+        let lang = Language::detect(&path);
+        self.highlight_cache.get_or_highlight(&cache_key, &text, lang).to_vec()
     }
 
-    fn render_details(&self, f: &mut Frame, area: Rect) {
+    fn render_details(&mut self, f: &mut Frame, area: Rect) {
+        let detail_border_style = if self.detail_focused {
+            Style::default().fg(Color::Blue)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let detail_title = if self.detail_focused {
+            " Details (Esc to leave, ↑/↓ to scroll) ".to_string()
+        } else {
+            format!(" Details ({} to scroll body) ", self.keybindings.toggle_detail_focus)
+        };
         let block = Block::default()
             .borders(Borders::ALL)
-            .title(" Details ");
+            .border_style(detail_border_style)
+            .title(detail_title);
 
-        if let Some(finding) = self.get_selected() {
+        if let Some(finding) = self.get_selected().cloned() {
             let inner = block.inner(area);
             f.render_widget(block, area);
 
@@ -579,7 +1394,7 @@ impl SearchTab {
                     Constraint::Length(2), // status
                     Constraint::Length(2), // location
                     Constraint::Length(1), // spacer
-                    Constraint::Min(0),   // description
+                    Constraint::Min(0),   // highlighted body
                 ])
                 .split(inner);
 
@@ -595,10 +1410,14 @@ impl SearchTab {
             ]));
             f.render_widget(id_line, chunks[1]);
 
-            let severity = Paragraph::new(Line::from(vec![
+            let mut severity_spans = vec![
                 Span::styled("Severity: ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::styled(finding.severity.as_str(), Style::default().fg(finding.severity.color())),
-            ]));
+                Span::styled(finding.severity.as_str(), self.theme.severity_style(finding.severity)),
+            ];
+            if let Some(score) = finding.base_score() {
+                severity_spans.push(Span::raw(format!(" (CVSS {score:.1})")));
+            }
+            let severity = Paragraph::new(Line::from(severity_spans));
             f.render_widget(severity, chunks[2]);
 
             let asset = Paragraph::new(Line::from(vec![
@@ -615,7 +1434,7 @@ impl SearchTab {
 
             let status = Paragraph::new(Line::from(vec![
                 Span::styled("Status: ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::styled(finding.status.as_str(), Style::default().fg(finding.status.color())),
+                Span::styled(finding.status.as_str(), self.theme.status_style(finding.status)),
             ]));
             f.render_widget(status, chunks[5]);
 
@@ -625,12 +1444,19 @@ impl SearchTab {
             ]));
             f.render_widget(location, chunks[6]);
 
-            let description = Paragraph::new(Line::from(vec![
-                Span::styled("Description:\n", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(&finding.description),
-            ]))
-            .wrap(Wrap { trim: true });
-            f.render_widget(description, chunks[8]);
+            let mut body_lines = self.highlighted_body(&finding);
+            if let Some(snippet) = &finding.snippet {
+                body_lines.push(Line::raw(""));
+                body_lines.push(Line::from(Span::styled(
+                    format!("Snippet ({}):", snippet.lang),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )));
+                body_lines.extend(snippet.highlight());
+            }
+            let body = Paragraph::new(body_lines)
+                .scroll((self.detail_scroll, 0))
+                .wrap(Wrap { trim: false });
+            f.render_widget(body, chunks[8]);
         } else {
             let empty = Paragraph::new("No finding selected")
                 .block(block)
@@ -0,0 +1,217 @@
+//! Configurable color theme for the TUI.
+//!
+//! Colors are no longer hardcoded in each tab: a [`Theme`] is loaded once
+//! (from a user config file, if present) and threaded into the rendering
+//! path. Every field is optional in the on-disk representation so a user
+//! can override just the colors they care about — [`Style::extend`] lets a
+//! partial override fall back to the built-in default field by field.
+//!
+//! `NO_COLOR` (see <https://no-color.org>) is honored: when set, every
+//! [`Style`] resolves to the terminal default, regardless of config.
+
+use std::path::{Path, PathBuf};
+
+use ratatui::style::{Color, Modifier};
+use serde::Deserialize;
+
+// ---------------------------------------------------------------------------
+// Style
+// ---------------------------------------------------------------------------
+
+/// A partial style override. Every field is optional so a deserialized user
+/// config can specify only the bits it wants to change.
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub add_modifier: Option<Modifier>,
+    pub sub_modifier: Option<Modifier>,
+}
+
+impl Style {
+    pub const fn new() -> Self {
+        Self { fg: None, bg: None, add_modifier: None, sub_modifier: None }
+    }
+
+    pub const fn fg(fg: Color) -> Self {
+        Self { fg: Some(fg), bg: None, add_modifier: None, sub_modifier: None }
+    }
+
+    /// Merge `other` on top of `self`, field by field: any field `other`
+    /// sets wins, everything else keeps `self`'s value.
+    pub fn extend(mut self, other: Style) -> Self {
+        if other.fg.is_some() { self.fg = other.fg; }
+        if other.bg.is_some() { self.bg = other.bg; }
+        if other.add_modifier.is_some() { self.add_modifier = other.add_modifier; }
+        if other.sub_modifier.is_some() { self.sub_modifier = other.sub_modifier; }
+        self
+    }
+
+    /// Resolve to a ratatui [`ratatui::style::Style`]. When `NO_COLOR` is
+    /// set, `fg`/`bg` are dropped so the terminal's default colors show
+    /// through; modifiers (bold, etc.) are kept since they carry meaning
+    /// beyond color.
+    pub fn resolve(&self, no_color: bool) -> ratatui::style::Style {
+        let mut style = ratatui::style::Style::default();
+        if !no_color {
+            if let Some(fg) = self.fg { style = style.fg(fg); }
+            if let Some(bg) = self.bg { style = style.bg(bg); }
+        }
+        if let Some(m) = self.add_modifier { style = style.add_modifier(m); }
+        if let Some(m) = self.sub_modifier { style = style.remove_modifier(m); }
+        style
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Theme
+// ---------------------------------------------------------------------------
+
+/// On-disk theme overrides. Every key is optional.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ThemeConfig {
+    pub critical: Option<Style>,
+    pub high: Option<Style>,
+    pub medium: Option<Style>,
+    pub low: Option<Style>,
+    pub info: Option<Style>,
+    pub unknown: Option<Style>,
+    pub search_border: Option<Style>,
+    pub highlight: Option<Style>,
+    pub dropdown_selected: Option<Style>,
+    pub status_open: Option<Style>,
+    pub status_in_progress: Option<Style>,
+    pub status_resolved: Option<Style>,
+    pub status_false_positive: Option<Style>,
+}
+
+/// The resolved theme used throughout the TUI rendering path.
+#[derive(Clone, Copy, Debug)]
+pub struct Theme {
+    pub critical: Style,
+    pub high: Style,
+    pub medium: Style,
+    pub low: Style,
+    pub info: Style,
+    pub unknown: Style,
+    pub search_border: Style,
+    pub highlight: Style,
+    pub dropdown_selected: Style,
+    pub status_open: Style,
+    pub status_in_progress: Style,
+    pub status_resolved: Style,
+    pub status_false_positive: Style,
+    /// Whether `NO_COLOR` was set when this theme was resolved.
+    pub no_color: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            critical: Style::fg(Color::Red),
+            high: Style::fg(Color::LightRed),
+            medium: Style::fg(Color::Yellow),
+            low: Style::fg(Color::Green),
+            info: Style::fg(Color::Blue),
+            unknown: Style::fg(Color::Gray),
+            search_border: Style::fg(Color::Blue),
+            highlight: Style { fg: Some(Color::White), bg: Some(Color::Blue), add_modifier: Some(Modifier::BOLD), sub_modifier: None },
+            dropdown_selected: Style { fg: Some(Color::White), bg: Some(Color::Blue), add_modifier: Some(Modifier::BOLD), sub_modifier: None },
+            status_open: Style::fg(Color::Red),
+            status_in_progress: Style::fg(Color::Yellow),
+            status_resolved: Style::fg(Color::Green),
+            status_false_positive: Style::fg(Color::Gray),
+            no_color: false,
+        }
+    }
+}
+
+impl Theme {
+    /// Load the theme: start from the built-in defaults, apply any
+    /// overrides found in `path` (TOML or JSON, detected by extension),
+    /// then apply the `NO_COLOR` override last.
+    pub fn load(path: Option<&Path>) -> Self {
+        let mut theme = Theme::default();
+
+        if let Some(path) = path
+            && let Some(config) = read_theme_config(path)
+        {
+            theme.critical = theme.critical.extend(config.critical.unwrap_or_default());
+            theme.high = theme.high.extend(config.high.unwrap_or_default());
+            theme.medium = theme.medium.extend(config.medium.unwrap_or_default());
+            theme.low = theme.low.extend(config.low.unwrap_or_default());
+            theme.info = theme.info.extend(config.info.unwrap_or_default());
+            theme.unknown = theme.unknown.extend(config.unknown.unwrap_or_default());
+            theme.search_border = theme.search_border.extend(config.search_border.unwrap_or_default());
+            theme.highlight = theme.highlight.extend(config.highlight.unwrap_or_default());
+            theme.dropdown_selected = theme.dropdown_selected.extend(config.dropdown_selected.unwrap_or_default());
+            theme.status_open = theme.status_open.extend(config.status_open.unwrap_or_default());
+            theme.status_in_progress = theme.status_in_progress.extend(config.status_in_progress.unwrap_or_default());
+            theme.status_resolved = theme.status_resolved.extend(config.status_resolved.unwrap_or_default());
+            theme.status_false_positive = theme.status_false_positive.extend(config.status_false_positive.unwrap_or_default());
+        }
+
+        theme.no_color = std::env::var_os("NO_COLOR").is_some();
+        theme
+    }
+
+    /// Default config file location: `$XDG_CONFIG_HOME/pog/theme.toml`,
+    /// falling back to `~/.config/pog/theme.toml`.
+    pub fn default_config_path() -> Option<PathBuf> {
+        if let Ok(xdg) = std::env::var("XDG_CONFIG_HOME") {
+            return Some(PathBuf::from(xdg).join("pog").join("theme.toml"));
+        }
+        let home = std::env::var("HOME").ok()?;
+        Some(PathBuf::from(home).join(".config").join("pog").join("theme.toml"))
+    }
+
+    /// Resolve a [`Style`] from this theme down to a ratatui style,
+    /// honoring `NO_COLOR`.
+    pub fn style(&self, style: Style) -> ratatui::style::Style {
+        style.resolve(self.no_color)
+    }
+
+    /// Map a criticality/severity string ("Critical", "High", ...) to its
+    /// themed [`Style`].
+    pub fn criticality_style(&self, criticality: &str) -> ratatui::style::Style {
+        let style = match criticality.to_lowercase().as_str() {
+            "critical" => self.critical,
+            "high" => self.high,
+            "medium" => self.medium,
+            "low" => self.low,
+            _ => self.unknown,
+        };
+        self.style(style)
+    }
+
+    /// Map a [`models::Severity`] to its themed [`Style`].
+    pub fn severity_style(&self, severity: models::Severity) -> ratatui::style::Style {
+        let style = match severity {
+            models::Severity::Critical => self.critical,
+            models::Severity::High => self.high,
+            models::Severity::Medium => self.medium,
+            models::Severity::Low => self.low,
+            models::Severity::Info => self.info,
+        };
+        self.style(style)
+    }
+
+    /// Map a [`models::Status`] to its themed [`Style`].
+    pub fn status_style(&self, status: models::Status) -> ratatui::style::Style {
+        let style = match status {
+            models::Status::Open => self.status_open,
+            models::Status::InProgress => self.status_in_progress,
+            models::Status::Resolved => self.status_resolved,
+            models::Status::FalsePositive => self.status_false_positive,
+        };
+        self.style(style)
+    }
+}
+
+fn read_theme_config(path: &Path) -> Option<ThemeConfig> {
+    let raw = std::fs::read_to_string(path).ok()?;
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::from_str(&raw).ok(),
+        _ => toml::from_str(&raw).ok(),
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -87,6 +89,31 @@ impl SearchBox {
     pub fn query(&self) -> String {
         self.input.to_lowercase()
     }
+
+    /// Fuzzy-rank `candidates` against the current query (see
+    /// [`crate::fuzzy`] for the scoring rules). Returns indices into
+    /// `candidates` sorted by descending score; ties are broken by shorter
+    /// candidate, then by earlier position. An empty query returns every
+    /// index in its original order.
+    pub fn rank(&self, candidates: &[&str]) -> Vec<usize> {
+        if self.input.is_empty() {
+            return (0..candidates.len()).collect();
+        }
+
+        let mut scored: Vec<(usize, i32)> = candidates
+            .iter()
+            .enumerate()
+            .filter_map(|(i, c)| crate::fuzzy::fuzzy_score(&self.input, c).map(|score| (i, score)))
+            .collect();
+
+        scored.sort_by(|&(ia, sa), &(ib, sb)| {
+            sb.cmp(&sa)
+                .then_with(|| candidates[ia].len().cmp(&candidates[ib].len()))
+                .then_with(|| ia.cmp(&ib))
+        });
+
+        scored.into_iter().map(|(i, _)| i).collect()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -103,24 +130,47 @@ pub struct DropdownOption {
 /// Generic dropdown state.  It does not own the option list — callers pass the
 /// options slice into each method so the same state can be used with different
 /// backing stores.
+///
+/// Single-select (the default, via [`Dropdown::new`]) keeps its original
+/// behavior: `selected` is the cursor, and closing the menu commits it.
+/// Multi-select (via [`Dropdown::new_multi`]) additionally tracks a set of
+/// *checked* options that toggle on click/Enter without closing the menu —
+/// callers read the active filter via [`Dropdown::selected_indices`].
 pub struct Dropdown {
     pub open: bool,
     pub selected: usize,
+    pub checked: HashSet<usize>,
+    pub multi: bool,
     pub button_area: Option<Rect>,
     pub menu_area: Option<Rect>,
 }
 
 impl Default for Dropdown {
     fn default() -> Self {
-        Self { open: false, selected: 0, button_area: None, menu_area: None }
+        Self {
+            open: false,
+            selected: 0,
+            checked: HashSet::new(),
+            multi: false,
+            button_area: None,
+            menu_area: None,
+        }
     }
 }
 
 impl Dropdown {
+    /// Single-select dropdown (existing call sites are unaffected).
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Multi-select dropdown: menu items toggle a checkbox instead of
+    /// closing the menu, and a trailing "Clear all" row resets the
+    /// selection.
+    pub fn new_multi() -> Self {
+        Self { multi: true, ..Self::default() }
+    }
+
     pub fn toggle(&mut self, current_index: usize) {
         if self.open {
             self.open = false;
@@ -142,6 +192,47 @@ impl Dropdown {
         }
     }
 
+    /// Toggle whether `index` is checked (multi-select only).
+    pub fn toggle_checked(&mut self, index: usize) {
+        if !self.checked.insert(index) {
+            self.checked.remove(&index);
+        }
+    }
+
+    /// Clear every checked option — the "clear all" affordance.
+    pub fn clear_checked(&mut self) {
+        self.checked.clear();
+    }
+
+    /// Checked option indices, ascending. Empty means "no filter applied".
+    pub fn selected_indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = self.checked.iter().copied().collect();
+        indices.sort_unstable();
+        indices
+    }
+
+    /// Summarize the active selection for the button label: the single
+    /// cursor option in single-select mode; otherwise "All" when nothing is
+    /// checked, the checked labels joined by ", " for up to three, or
+    /// "N selected" beyond that.
+    pub fn summary_label(&self, options: &[DropdownOption]) -> String {
+        if !self.multi {
+            return options.get(self.selected).map(|o| o.label.clone()).unwrap_or_default();
+        }
+
+        let indices = self.selected_indices();
+        match indices.len() {
+            0 => "All".to_string(),
+            1..=3 => indices
+                .iter()
+                .filter_map(|&i| options.get(i))
+                .map(|o| o.label.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            n => format!("{n} selected"),
+        }
+    }
+
     /// Render the filter button (the clickable bar that opens/closes the
     /// dropdown).
     pub fn render_button(
@@ -174,10 +265,13 @@ impl Dropdown {
         f.render_widget(button, area);
     }
 
-    /// Render the floating menu below the button.
+    /// Render the floating menu below the button. In multi-select mode each
+    /// item is prefixed with a checkbox glyph and a trailing "Clear all" row
+    /// is appended.
     pub fn render_menu(&mut self, f: &mut Frame, options: &[DropdownOption]) {
         if let Some(button_area) = self.button_area {
-            let menu_height = options.len() as u16 + 2;
+            let row_count = options.len() + if self.multi { 1 } else { 0 };
+            let menu_height = row_count as u16 + 2;
 
             let menu_area = Rect {
                 x: button_area.x,
@@ -189,15 +283,30 @@ impl Dropdown {
 
             f.render_widget(Clear, menu_area);
 
-            let items: Vec<ListItem> = options.iter().enumerate().map(|(i, opt)| {
+            let mut items: Vec<ListItem> = options.iter().enumerate().map(|(i, opt)| {
                 let style = if i == self.selected {
                     Style::default().bg(Color::Blue).fg(Color::White).add_modifier(Modifier::BOLD)
                 } else {
                     Style::default().fg(opt.color)
                 };
-                ListItem::new(format!(" {} ", opt.label)).style(style)
+                let text = if self.multi {
+                    let glyph = if self.checked.contains(&i) { "[x]" } else { "[ ]" };
+                    format!(" {glyph} {} ", opt.label)
+                } else {
+                    format!(" {} ", opt.label)
+                };
+                ListItem::new(text).style(style)
             }).collect();
 
+            if self.multi {
+                let style = if self.selected == options.len() {
+                    Style::default().bg(Color::Blue).fg(Color::White).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Gray)
+                };
+                items.push(ListItem::new(" Clear all ").style(style));
+            }
+
             let list = List::new(items)
                 .block(Block::default().borders(Borders::ALL));
             f.render_widget(list, menu_area);
@@ -205,14 +314,16 @@ impl Dropdown {
     }
 
     /// Handle a click that lands inside the open menu.  Returns `Some(index)`
-    /// if a menu item was clicked, `None` otherwise.
+    /// if a menu item was clicked, `None` otherwise. In multi-select mode,
+    /// `Some(option_count)` means the trailing "Clear all" row was clicked.
     pub fn click_menu(&self, col: u16, row: u16, option_count: usize) -> Option<usize> {
         if let Some(area) = self.menu_area {
             if in_area(col, row, area) {
                 let start_y = area.y + 1;
                 if row >= start_y {
                     let idx = (row - start_y) as usize;
-                    if idx < option_count {
+                    let row_count = option_count + if self.multi { 1 } else { 0 };
+                    if idx < row_count {
                         return Some(idx);
                     }
                 }